@@ -1,10 +1,16 @@
-use crate::lldpdu::Lldpdu;
+use crate::lldpdu::{Lldpdu, MAX_LLDPDU_SIZE};
 use crate::tlv::chassisid_tlv::*;
 use crate::tlv::eolldpdu_tlv::EndOfLLDPDUTLV;
 use crate::tlv::portid_tlv::*;
 use crate::tlv::ttl_tlv::TtlTLV;
+use crate::tlv::xpdurequest_tlv::XpduRequestTLV;
 use crate::tlv::Tlv;
-use std::time::Instant;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+pub mod dcbx;
+
+use dcbx::{DcbConfig, Dcbx, OperationalDcbConfig};
 
 extern crate pnet;
 use pnet::datalink::Channel::Ethernet;
@@ -27,6 +33,23 @@ impl Logger for StdoutLogger {
     }
 }
 
+/// How the agent logs received LLDPDUs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// A single line, as produced by the `Display` implementation of [Lldpdu]
+    Compact,
+    /// One labelled line per TLV, as produced by [Lldpdu::neighbor_view]
+    Detailed,
+}
+
+/// XPDUs received from a remote agent
+struct RemoteXpdus {
+    /// The last regular LLDPDU, carrying the manifest
+    lldpdu: Lldpdu,
+    /// XPDUs matching the manifest, by XPDU number
+    xpdus: HashMap<u8, Lldpdu>,
+}
+
 /// LLDP agent
 ///
 /// This is the top-level component. It provides two functions.
@@ -41,6 +64,14 @@ pub struct LLDPAgent {
     interval: f32,
     channel: (Box<dyn DataLinkSender>, Box<dyn DataLinkReceiver>),
     logger: Box<dyn Logger>,
+    log_format: LogFormat,
+    optional_tlvs: Vec<(Tlv, u8)>,
+    tx_omitted_tlvs: u64,
+    multiframe: bool,
+    local_xpdus: Vec<Lldpdu>,
+    remote_xpdus: HashMap<MacAddr, RemoteXpdus>,
+    neighbor_expiry: HashMap<MacAddr, Instant>,
+    dcbx: Option<Dcbx>,
 }
 
 impl LLDPAgent {
@@ -89,9 +120,64 @@ impl LLDPAgent {
             interval,
             channel: (tx, rx),
             logger,
+            log_format: LogFormat::Compact,
+            optional_tlvs: vec![],
+            tx_omitted_tlvs: 0,
+            multiframe: false,
+            local_xpdus: vec![],
+            remote_xpdus: HashMap::new(),
+            neighbor_expiry: HashMap::new(),
+            dcbx: None,
         }
     }
 
+    /// Set the format used to log received LLDPDUs (`LogFormat::Compact` by default).
+    pub fn set_log_format(&mut self, format: LogFormat) {
+        self.log_format = format;
+    }
+
+    /// Enable or disable the IEEE 802.1AB-2016 multiframe extension.
+    ///
+    /// With the extension enabled, optional TLVs which do not fit into the announced LLDPDU are offered as XPDUs
+    /// instead of being omitted (see [Lldpdu::new_multiframe]).
+    ///
+    /// The extension is disabled by default: the announced check values are computed with an algorithm of this crate
+    /// (see [check_value](crate::lldpdu::xpdu::check_value)) rather than the one of IEEE 802.1AB-2016, so peers which
+    /// recompute them may not accept the XPDUs.
+    ///
+    /// Receiving XPDUs from remote agents is always supported.
+    pub fn set_multiframe(&mut self, enabled: bool) {
+        self.multiframe = enabled;
+    }
+
+    /// Add an optional TLV to the announced LLDPDU.
+    ///
+    /// If the optional TLVs do not fit into a single LLDPDU, the ones with the lowest `priority` are omitted from
+    /// transmission (see [Lldpdu::new_prioritized]).
+    pub fn add_optional_tlv(&mut self, tlv: Tlv, priority: u8) {
+        self.optional_tlvs.push((tlv, priority));
+    }
+
+    /// Enable DCBX (IEEE 802.1Qaz) with the given administrative configuration.
+    ///
+    /// The agent advertises the DCBX TLVs with the highest priority and negotiates the operational configuration
+    /// with the peer, see [Dcbx]. Changes of the operational configuration are logged and advertised right away.
+    pub fn enable_dcbx(&mut self, config: DcbConfig) {
+        self.dcbx = Some(Dcbx::new(self.mac_address, config));
+    }
+
+    /// Get the operational DCB configuration, `None` if DCBX is not enabled.
+    ///
+    /// This is the configuration to apply to the NIC.
+    pub fn dcb_operational(&self) -> Option<&OperationalDcbConfig> {
+        self.dcbx.as_ref().map(Dcbx::operational)
+    }
+
+    /// Get the number of optional TLVs omitted from transmission because the LLDPDU was full.
+    pub fn tx_omitted_tlvs(&self) -> u64 {
+        self.tx_omitted_tlvs
+    }
+
     /// Runs the agent
     ///
     /// This is the main loop of the LLDP agent. It takes care of sending as well as receiving LLDP frames.
@@ -102,52 +188,22 @@ impl LLDPAgent {
     ///
     /// Valid LLDP frames have an ethertype of 0x88CC, are directed to one of the LLDP multicast addresses
     /// (01:80:c2:00:00:00, 01:80:c2:00:00:03 and 01:80:c2:00:00:0e) and have not been sent by the local agent.
+    /// Multiframe extension frames are handled as described for `LLDPAgent.receive()`.
     ///
-    /// After processing received frames, the agent announces itself by calling `LLDPAgent.announce()` if a sufficient
-    /// amount of time has passed.
+    /// After processing received frames, the agent forgets neighbors whose information has expired and announces
+    /// itself by calling `LLDPAgent.announce()` if a sufficient amount of time has passed.
     ///
     /// If `run_once` is set to `true`, stop after the first LLDPDU has been received.
     pub fn run(&mut self, run_once: bool) {
         let mut t_previous = Instant::now();
 
-        let valid_destination = vec![
-            MacAddr(0x01, 0x80, 0xc2, 0x00, 0x00, 0x00),
-            MacAddr(0x01, 0x80, 0xc2, 0x00, 0x00, 0x03),
-            MacAddr(0x01, 0x80, 0xc2, 0x00, 0x00, 0x0e),
-        ];
-
         loop {
             // Get the next frame
             match self.channel.1.next() {
                 Ok(frame) => {
                     // Frame has been received
-                    let ether_frame = match EthernetPacket::new(frame) {
-                        Some(frame) => frame,
-                        None => continue,
-                    };
-
-                    let source_mac = ether_frame.get_source();
-                    if source_mac == self.mac_address {
-                        continue;
-                    }
-
-                    let destination_mac = ether_frame.get_destination();
-                    if !valid_destination.iter().any(|mac| mac == &destination_mac) {
-                        continue;
-                    }
-
-                    let ether_type = ether_frame.get_ethertype();
-                    if ether_type != EtherTypes::Lldp {
-                        continue;
-                    }
-
-                    // Instantiate Lldpdu struct from raw bytes
-                    let lldpdu: Lldpdu = Lldpdu::from_bytes(ether_frame.payload());
-
-                    // Log contents
-                    self.logger.log(&format!("{}", lldpdu));
-
-                    if run_once {
+                    let frame = frame.to_vec();
+                    if self.receive(&frame) && run_once {
                         break;
                     }
                 }
@@ -156,8 +212,11 @@ impl LLDPAgent {
                     panic!("An error occurred while reading: {}", e);
                 }
             }
-            // Announce if the time is right
+            // Forget neighbors whose TTL has run out
             let t_now = Instant::now();
+            self.expire_neighbors(t_now);
+
+            // Announce if the time is right
             if (t_now - t_previous).as_secs_f32() > self.interval {
                 self.announce();
                 t_previous = t_now;
@@ -165,17 +224,239 @@ impl LLDPAgent {
         }
     }
 
-    /// Announces the agent.
+    /// Process a received Ethernet frame.
     ///
-    /// Send an LLDP frame using the channel
+    /// Frames which are not valid LLDP frames are ignored. Frames directed to the agent's own MAC address are
+    /// accepted as well, as XPDU requests and XPDUs are exchanged between two agents directly.
     ///
-    /// Sends an LLDP frame with an LLDPDU containing:
-    /// * the agent's MAC address as its chassis id
-    /// * the agent's interface name as port id
-    /// * a TTL of 60 seconds
-    pub fn announce(&mut self) {
-        // Construct LLDPDU
-        let init_tlvs: Vec<Tlv> = vec![
+    /// * XPDU requests are answered with the requested XPDUs.
+    /// * LLDPDUs carrying a Manifest TLV are only logged once all XPDUs listed in the manifest have been received.
+    ///   Missing XPDUs are requested from the sender. The logged LLDPDU includes the TLVs from the XPDUs.
+    /// * All other LLDPDUs are logged as they are.
+    ///
+    /// The information received from a neighbor is kept for the time to live of its last LLDPDU, see
+    /// `LLDPAgent.expire_neighbors()`. A shutdown LLDPDU (TTL 0) discards it right away.
+    ///
+    /// With DCBX enabled, logged LLDPDUs are passed to the DCBX state machines. The agent announces itself right away
+    /// if the operational DCB configuration changed or a CEE peer expects an acknowledgement.
+    ///
+    /// Returns `true` if an LLDPDU has been logged.
+    fn receive(&mut self, frame: &[u8]) -> bool {
+        let valid_destination = [
+            MacAddr(0x01, 0x80, 0xc2, 0x00, 0x00, 0x00),
+            MacAddr(0x01, 0x80, 0xc2, 0x00, 0x00, 0x03),
+            MacAddr(0x01, 0x80, 0xc2, 0x00, 0x00, 0x0e),
+        ];
+
+        let ether_frame = match EthernetPacket::new(frame) {
+            Some(frame) => frame,
+            None => return false,
+        };
+
+        let source_mac = ether_frame.get_source();
+        if source_mac == self.mac_address {
+            return false;
+        }
+
+        let destination_mac = ether_frame.get_destination();
+        let unicast = destination_mac == self.mac_address;
+        if !unicast && !valid_destination.iter().any(|mac| mac == &destination_mac) {
+            return false;
+        }
+
+        let ether_type = ether_frame.get_ethertype();
+        if ether_type != EtherTypes::Lldp {
+            return false;
+        }
+
+        // Instantiate Lldpdu struct from raw bytes
+        let lldpdu: Lldpdu = Lldpdu::from_bytes(ether_frame.payload());
+
+        if let Some(request) = lldpdu.xpdu_request() {
+            let numbers = request.value.clone();
+            self.send_xpdus(source_mac, &numbers);
+            return false;
+        }
+
+        let xpdu =
+            matches!(lldpdu.manifest(), Some(manifest) if unicast && manifest.value.len() == 1);
+        let ttl = lldpdu.ttl();
+        if !xpdu {
+            match ttl {
+                Some(0) => self.forget_neighbor(source_mac),
+                Some(ttl) => {
+                    let expiry = Instant::now() + Duration::from_secs(ttl.into());
+                    self.neighbor_expiry.insert(source_mac, expiry);
+                }
+                None => (),
+            }
+        }
+
+        let lldpdu = match lldpdu.manifest() {
+            Some(_) if xpdu => match self.receive_xpdu(source_mac, lldpdu) {
+                Some(lldpdu) => lldpdu,
+                None => return false,
+            },
+            Some(_) if ttl != Some(0) => match self.receive_manifest(source_mac, lldpdu) {
+                Some(lldpdu) => lldpdu,
+                None => return false,
+            },
+            _ => lldpdu,
+        };
+
+        // Log contents
+        let msg = match self.log_format {
+            LogFormat::Compact => format!("{}", lldpdu),
+            LogFormat::Detailed => format!("{}", lldpdu.neighbor_view()),
+        };
+        self.logger.log(&msg);
+
+        let (changed, ack_pending) = match &mut self.dcbx {
+            Some(dcbx) => (dcbx.receive(source_mac, &lldpdu), dcbx.take_ack_pending()),
+            None => (false, false),
+        };
+        if changed {
+            self.dcbx_changed();
+        } else if ack_pending {
+            self.announce();
+        }
+        true
+    }
+
+    /// Log and advertise a change of the operational DCB configuration.
+    fn dcbx_changed(&mut self) {
+        let operational = self.dcb_operational().unwrap().to_string();
+        self.logger.log(&format!(
+            "DCBX operational configuration changed: {}",
+            operational
+        ));
+        self.announce();
+    }
+
+    /// Forget all neighbors whose information has expired at `now`.
+    ///
+    /// With DCBX enabled, expired peers are removed from the DCBX state machines as well.
+    fn expire_neighbors(&mut self, now: Instant) {
+        let expired: Vec<MacAddr> = self
+            .neighbor_expiry
+            .iter()
+            .filter(|(_, expiry)| **expiry <= now)
+            .map(|(source, _)| *source)
+            .collect();
+        let mut changed = false;
+        for source in expired {
+            self.forget_neighbor(source);
+            if let Some(dcbx) = &mut self.dcbx {
+                changed |= dcbx.expire(source);
+            }
+        }
+        if changed {
+            self.dcbx_changed();
+        }
+    }
+
+    /// Discard the information received from `source`.
+    fn forget_neighbor(&mut self, source: MacAddr) {
+        self.neighbor_expiry.remove(&source);
+        self.remote_xpdus.remove(&source);
+    }
+
+    /// Process an LLDPDU carrying a Manifest TLV.
+    ///
+    /// Returns the reassembled LLDPDU if all XPDUs are available, otherwise requests the missing ones.
+    fn receive_manifest(&mut self, source: MacAddr, lldpdu: Lldpdu) -> Option<Lldpdu> {
+        let descriptors = lldpdu.manifest().unwrap().value.clone();
+
+        let remote = self
+            .remote_xpdus
+            .entry(source)
+            .or_insert_with(|| RemoteXpdus {
+                lldpdu: lldpdu.clone(),
+                xpdus: HashMap::new(),
+            });
+        remote.lldpdu = lldpdu;
+
+        // Forget XPDUs which are no longer offered or whose contents changed
+        remote.xpdus.retain(|number, xpdu| {
+            let check = xpdu.manifest().unwrap().value[0].check;
+            descriptors
+                .iter()
+                .any(|descriptor| descriptor.number == *number && descriptor.check == check)
+        });
+
+        let missing: Vec<u8> = descriptors
+            .iter()
+            .map(|descriptor| descriptor.number)
+            .filter(|number| !remote.xpdus.contains_key(number))
+            .collect();
+
+        if missing.is_empty() {
+            return Some(self.reassemble(source));
+        }
+
+        let mut tlvs = self.mandatory_tlvs();
+        tlvs.push(Tlv::XpduRequest(XpduRequestTLV::new(missing)));
+        self.send(source, &Lldpdu::new(tlvs));
+
+        None
+    }
+
+    /// Process an XPDU.
+    ///
+    /// Returns the reassembled LLDPDU if this was the last missing XPDU.
+    fn receive_xpdu(&mut self, source: MacAddr, xpdu: Lldpdu) -> Option<Lldpdu> {
+        let remote = self.remote_xpdus.get_mut(&source)?;
+        let descriptors = &remote.lldpdu.manifest().unwrap().value;
+
+        let descriptor = xpdu.manifest().unwrap().value[0];
+        if !descriptors.contains(&descriptor) {
+            return None;
+        }
+
+        let was_complete = descriptors.len() == remote.xpdus.len();
+        remote.xpdus.insert(descriptor.number, xpdu);
+
+        if was_complete || descriptors.len() != remote.xpdus.len() {
+            return None;
+        }
+
+        Some(self.reassemble(source))
+    }
+
+    /// Combine the last LLDPDU received from `source` with its XPDUs.
+    fn reassemble(&self, source: MacAddr) -> Lldpdu {
+        let remote = &self.remote_xpdus[&source];
+        let xpdus: Vec<Lldpdu> = remote
+            .lldpdu
+            .manifest()
+            .unwrap()
+            .value
+            .iter()
+            .map(|descriptor| remote.xpdus[&descriptor.number].clone())
+            .collect();
+
+        remote.lldpdu.reassemble(&xpdus)
+    }
+
+    /// Send the XPDUs with the given numbers to `destination`.
+    ///
+    /// Requests for unknown XPDUs are ignored.
+    fn send_xpdus(&mut self, destination: MacAddr, numbers: &[u8]) {
+        for number in numbers {
+            let xpdu = match (*number as usize)
+                .checked_sub(1)
+                .and_then(|index| self.local_xpdus.get(index))
+            {
+                Some(xpdu) => xpdu.clone(),
+                None => continue,
+            };
+            self.send(destination, &xpdu);
+        }
+    }
+
+    /// Get the mandatory TLVs identifying the agent.
+    fn mandatory_tlvs(&self) -> Vec<Tlv> {
+        vec![
             Tlv::ChassisId(ChassisIdTLV::new(
                 ChassisIdSubType::MacAddress,
                 ChassisIdValue::Mac(self.mac_address.octets().to_vec()),
@@ -186,40 +467,89 @@ impl LLDPAgent {
             )),
             Tlv::Ttl(TtlTLV::new(60)),
             // Tlv::EndOfLldpdu(EndOfLLDPDUTLV::new()),
-        ];
+        ]
+    }
 
-        let lldpdu: Lldpdu = Lldpdu::new(init_tlvs);
+    /// Send `lldpdu` to `destination` using the channel.
+    fn send(&mut self, destination: MacAddr, lldpdu: &Lldpdu) {
+        let frame = build_frame(self.mac_address, destination, lldpdu);
 
-        // Construct Ethernet Frame
-        let mut header = [0u8; 14];
-        let mut ethernet_header = MutableEthernetPacket::new(&mut header[..]).unwrap();
+        match self.channel.0.send_to(&frame, None) {
+            Some(Ok(_)) => (),
+            Some(Err(err)) => panic!("ERROR: Announce failed: {:?}", err),
+            None => (),
+        };
+    }
 
-        let source = self.mac_address;
-        ethernet_header.set_source(source);
+    /// Announces the agent.
+    ///
+    /// Send an LLDP frame using the channel
+    ///
+    /// Sends an LLDP frame with an LLDPDU containing:
+    /// * the agent's MAC address as its chassis id
+    /// * the agent's interface name as port id
+    /// * a TTL of 60 seconds
+    /// * the optional TLVs added with `LLDPAgent.add_optional_tlv()`, as far as they fit
+    /// * the DCBX TLVs, if enabled with `LLDPAgent.enable_dcbx()`
+    ///
+    /// With the multiframe extension enabled, the remaining optional TLVs are offered as XPDUs.
+    ///
+    /// Omitted optional TLVs are counted and logged.
+    pub fn announce(&mut self) {
+        // Construct LLDPDU
+        let init_tlvs = self.mandatory_tlvs();
+        let mut optional_tlvs = self.optional_tlvs.clone();
+        if let Some(dcbx) = &self.dcbx {
+            optional_tlvs.extend(dcbx.tlvs().into_iter().map(|tlv| (tlv, u8::MAX)));
+        }
 
-        let dest = MacAddr(0x01, 0x80, 0xc2, 0x00, 0x00, 0x0e);
-        ethernet_header.set_destination(dest);
+        let (lldpdu, omitted) = if self.multiframe {
+            let frames = Lldpdu::new_multiframe(init_tlvs, optional_tlvs);
+            self.local_xpdus = frames.xpdus;
+            (frames.lldpdu, frames.omitted)
+        } else {
+            Lldpdu::new_prioritized(init_tlvs, optional_tlvs)
+        };
 
-        ethernet_header.set_ethertype(EtherTypes::Lldp);
+        if !omitted.is_empty() {
+            self.tx_omitted_tlvs += omitted.len() as u64;
 
-        let mut frame = header.to_vec();
-        frame.extend_from_slice(&lldpdu.bytes());
+            let omitted: Vec<String> = omitted.iter().map(|tlv| format!("{}", tlv)).collect();
+            self.logger.log(&format!(
+                "LLDPDU exceeds {} bytes, omitted {} optional TLV(s): {}",
+                MAX_LLDPDU_SIZE,
+                omitted.len(),
+                omitted.join(", ")
+            ));
+        }
 
         // Send frame
-        match self.channel.0.send_to(&frame, None) {
-            Some(Ok(_)) => (),
-            Some(Err(err)) => panic!("ERROR: Announce failed: {:?}", err),
-            None => (),
-        };
+        self.send(MacAddr(0x01, 0x80, 0xc2, 0x00, 0x00, 0x0e), &lldpdu);
     }
 }
 
+/// Construct an Ethernet frame carrying `lldpdu`.
+fn build_frame(source: MacAddr, destination: MacAddr, lldpdu: &Lldpdu) -> Vec<u8> {
+    let mut header = [0u8; 14];
+    let mut ethernet_header = MutableEthernetPacket::new(&mut header[..]).unwrap();
+
+    ethernet_header.set_source(source);
+    ethernet_header.set_destination(destination);
+    ethernet_header.set_ethertype(EtherTypes::Lldp);
+
+    let mut frame = header.to_vec();
+    frame.extend_from_slice(&lldpdu.bytes());
+    frame
+}
+
 #[cfg(test)]
 mod tests {
     use pnet::datalink::dummy::{self, dummy_interface, Config};
 
     use super::*;
     use crate::tlv::eolldpdu_tlv::EndOfLLDPDUTLV;
+    use crate::tlv::systemdescription_tlv::SystemDescriptionTLV;
+    use crate::tlv::systemname_tlv::SystemNameTLV;
     use std::cell::RefCell;
     use std::rc::Rc;
     use std::sync::mpsc;
@@ -292,6 +622,424 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_announce_omits_lowest_priority() {
+        let (tx_sender, tx_receiver) = mpsc::channel();
+        let (_, rx_receiver) = mpsc::channel();
+        let dummy_loopback = dummy_interface(42);
+        let dummy_config = Config::new(rx_receiver, tx_sender);
+
+        let (tx, rx) = {
+            match dummy::channel(&dummy_loopback, dummy_config) {
+                Ok(Ethernet(tx, rx)) => (tx, rx),
+                _ => unreachable!("pnet is broken"),
+            }
+        };
+
+        let full_log = Rc::new(RefCell::new(String::new()));
+        let logger = Box::new(MockLogger {
+            inner: full_log.clone(),
+        });
+
+        let mut a = LLDPAgent::new(
+            MacAddr::new(102, 111, 111, 98, 97, 114),
+            String::from("lo"),
+            1.0,
+            Some((tx, rx)),
+            Some(logger),
+        );
+        for _ in 0..5 {
+            a.add_optional_tlv(
+                Tlv::SystemDescription(SystemDescriptionTLV::new("x".repeat(250))),
+                1,
+            );
+        }
+        a.add_optional_tlv(
+            Tlv::SystemDescription(SystemDescriptionTLV::new("y".repeat(250))),
+            0,
+        );
+        a.add_optional_tlv(Tlv::SystemName(SystemNameTLV::new("foobar".into())), 2);
+        a.announce();
+
+        let received = tx_receiver
+            .try_recv()
+            .expect("No packet received from agent");
+
+        assert_eq!(received.len(), 14 + 18 + 5 * 252 + 8);
+        assert_eq!(a.tx_omitted_tlvs(), 1);
+        assert!(full_log.borrow().starts_with(
+            "LLDPDU exceeds 1500 bytes, omitted 1 optional TLV(s): SystemDescriptionTLV(\"yyy"
+        ));
+
+        a.announce();
+        assert_eq!(a.tx_omitted_tlvs(), 2);
+    }
+
+    fn dummy_agent(
+        mac_address: MacAddr,
+        logger: Box<dyn Logger>,
+    ) -> (LLDPAgent, mpsc::Receiver<Box<[u8]>>) {
+        let (tx_sender, tx_receiver) = mpsc::channel();
+        let (_, rx_receiver) = mpsc::channel();
+        let dummy_loopback = dummy_interface(42);
+        let dummy_config = Config::new(rx_receiver, tx_sender);
+
+        let (tx, rx) = {
+            match dummy::channel(&dummy_loopback, dummy_config) {
+                Ok(Ethernet(tx, rx)) => (tx, rx),
+                _ => unreachable!("pnet is broken"),
+            }
+        };
+
+        let agent = LLDPAgent::new(
+            mac_address,
+            String::from("lo"),
+            1.0,
+            Some((tx, rx)),
+            Some(logger),
+        );
+        (agent, tx_receiver)
+    }
+
+    #[test]
+    fn test_multiframe_exchange() {
+        let sender_log = Rc::new(RefCell::new(String::new()));
+        let (mut sender, sender_tx) = dummy_agent(
+            MacAddr::new(102, 111, 111, 98, 97, 114),
+            Box::new(MockLogger {
+                inner: sender_log.clone(),
+            }),
+        );
+        let receiver_log = Rc::new(RefCell::new(String::new()));
+        let (mut receiver, receiver_tx) = dummy_agent(
+            MacAddr::new(170, 187, 204, 221, 238, 255),
+            Box::new(MockLogger {
+                inner: receiver_log.clone(),
+            }),
+        );
+
+        sender.set_multiframe(true);
+        for index in 0..12 {
+            sender.add_optional_tlv(
+                Tlv::SystemDescription(SystemDescriptionTLV::new(format!("{:0>250}", index))),
+                0,
+            );
+        }
+        sender.announce();
+        assert_eq!(sender.tx_omitted_tlvs(), 0);
+        assert!(sender_log.borrow().is_empty());
+
+        // The receiver requests both XPDUs from the sender
+        let announcement = sender_tx.try_recv().unwrap();
+        assert!(announcement.len() <= 14 + MAX_LLDPDU_SIZE);
+        assert!(!receiver.receive(&announcement));
+        assert!(receiver_log.borrow().is_empty());
+
+        let request = receiver_tx.try_recv().unwrap();
+        assert_eq!(&request[0..6], &[102, 111, 111, 98, 97, 114]);
+        assert_eq!(
+            Lldpdu::from_bytes(&request[14..])
+                .xpdu_request()
+                .unwrap()
+                .value,
+            vec![1, 2]
+        );
+
+        // The sender answers with the XPDUs
+        assert!(!sender.receive(&request));
+        let xpdus = [sender_tx.try_recv().unwrap(), sender_tx.try_recv().unwrap()];
+        assert!(sender_tx.try_recv().is_err());
+
+        // The receiver logs the reassembled LLDPDU
+        assert!(!receiver.receive(&xpdus[0]));
+        assert!(receiver.receive(&xpdus[1]));
+        for index in 0..12 {
+            assert!(receiver_log
+                .borrow()
+                .contains(&format!("SystemDescriptionTLV(\"{:0>250}\")", index)));
+        }
+        assert!(!receiver_log.borrow().contains("ManifestTLV"));
+
+        // Known XPDUs are not requested again
+        receiver_log.borrow_mut().clear();
+        assert!(receiver.receive(&announcement));
+        assert!(receiver_tx.try_recv().is_err());
+        assert!(receiver_log.borrow().contains(&format!("{:0>250}", 11)));
+
+        // The XPDUs are forgotten once the TTL has run out
+        let sender_mac = MacAddr::new(102, 111, 111, 98, 97, 114);
+        receiver.expire_neighbors(Instant::now() + Duration::from_secs(59));
+        assert!(receiver.remote_xpdus.contains_key(&sender_mac));
+        receiver.expire_neighbors(Instant::now() + Duration::from_secs(61));
+        assert!(receiver.remote_xpdus.is_empty());
+        assert!(receiver.neighbor_expiry.is_empty());
+
+        assert!(!receiver.receive(&announcement));
+        assert_eq!(
+            Lldpdu::from_bytes(&receiver_tx.try_recv().unwrap()[14..])
+                .xpdu_request()
+                .unwrap()
+                .value,
+            vec![1, 2]
+        );
+        assert!(receiver.remote_xpdus.contains_key(&sender_mac));
+
+        // A shutdown LLDPDU discards them right away
+        let shutdown = Lldpdu::new(vec![
+            Tlv::ChassisId(ChassisIdTLV::new(
+                ChassisIdSubType::MacAddress,
+                ChassisIdValue::Mac(sender_mac.octets().to_vec()),
+            )),
+            Tlv::PortId(PortIdTLV::new(
+                PortIdSubtype::InterfaceName,
+                PortIdValue::Other(String::from("lo")),
+            )),
+            Tlv::Ttl(TtlTLV::new(0)),
+        ]);
+        assert!(receiver.receive(&build_frame(
+            sender_mac,
+            MacAddr::new(0x01, 0x80, 0xc2, 0x00, 0x00, 0x0e),
+            &shutdown
+        )));
+        assert!(receiver.remote_xpdus.is_empty());
+        assert!(receiver.neighbor_expiry.is_empty());
+    }
+
+    #[test]
+    fn test_receive_detailed() {
+        let full_log = Rc::new(RefCell::new(String::new()));
+        let (mut a, _) = dummy_agent(
+            MacAddr::new(170, 187, 204, 221, 238, 255),
+            Box::new(MockLogger {
+                inner: full_log.clone(),
+            }),
+        );
+        a.set_log_format(LogFormat::Detailed);
+
+        let frame = b"\x01\x80\xc2\x00\x00\x0e\xff\xee\xdd\xcc\xbb\xaa\x88\xcc\x02\x07\x04\xff\xee\xdd\xcc\xbb\xaa\x04\x07\x03\xff\xee\xdd\xcc\xbb\xaa\x06\x02\x00x\x00\x00";
+        assert!(a.receive(frame));
+        assert_eq!(
+            full_log.borrow().as_str(),
+            "Chassis: mac ff:ee:dd:cc:bb:aa\nPort: mac ff:ee:dd:cc:bb:aa\nTTL: 120s"
+        );
+    }
+
+    #[test]
+    fn test_dcbx() {
+        use crate::tlv::organizationallyspecific_tlv::ieee8021::dcbx::*;
+
+        let tables = |bandwidth| {
+            EtsTables::new(
+                [0; PRIORITIES],
+                bandwidth,
+                [TransmissionSelectionAlgorithm::Ets; PRIORITIES],
+            )
+        };
+        let config = |willing, pfc_enabled| {
+            let mut config = DcbConfig::new(
+                EtsConfiguration::new(willing, false, 8, tables([100, 0, 0, 0, 0, 0, 0, 0])),
+                PriorityFlowControl::new(willing, false, 8, pfc_enabled),
+                ApplicationPriority::new(vec![]),
+            );
+            config.ets_recommendation =
+                Some(EtsRecommendation::new(tables([50, 50, 0, 0, 0, 0, 0, 0])));
+            config
+        };
+
+        let switch_log = Rc::new(RefCell::new(String::new()));
+        let (mut switch, switch_tx) = dummy_agent(
+            MacAddr::new(102, 111, 111, 98, 97, 114),
+            Box::new(MockLogger {
+                inner: switch_log.clone(),
+            }),
+        );
+        switch.enable_dcbx(config(false, 0x08));
+
+        let host_log = Rc::new(RefCell::new(String::new()));
+        let (mut host, host_tx) = dummy_agent(
+            MacAddr::new(170, 187, 204, 221, 238, 255),
+            Box::new(MockLogger {
+                inner: host_log.clone(),
+            }),
+        );
+        host.enable_dcbx(config(true, 0));
+        assert_eq!(host.dcb_operational().unwrap().pfc_enabled, 0);
+
+        // The willing host adopts the configuration of the switch and re-advertises it
+        switch.announce();
+        assert!(host.receive(&switch_tx.try_recv().unwrap()));
+        let operational = host.dcb_operational().unwrap();
+        assert_eq!(operational.pfc_enabled, 0x08);
+        assert_eq!(operational.ets.bandwidth, [50, 50, 0, 0, 0, 0, 0, 0]);
+        assert!(host_log
+            .borrow()
+            .contains("DCBX operational configuration changed: ETS (remote)"));
+
+        let advertised = Lldpdu::from_bytes(&host_tx.try_recv().unwrap()[14..]);
+        assert_eq!(
+            advertised.org_tlvs::<PriorityFlowControl>()[0].enabled,
+            0x08
+        );
+
+        // The switch keeps its configuration, there is no mismatch
+        assert!(switch.receive(&build_frame(
+            MacAddr::new(170, 187, 204, 221, 238, 255),
+            MacAddr::new(0x01, 0x80, 0xc2, 0x00, 0x00, 0x0e),
+            &advertised
+        )));
+        assert!(!switch.dcb_operational().unwrap().pfc_mismatch);
+        assert!(switch_tx.try_recv().is_err());
+
+        // The host falls back to its local configuration once the switch's information expires
+        host_log.borrow_mut().clear();
+        host.expire_neighbors(Instant::now() + Duration::from_secs(59));
+        assert_eq!(host.dcb_operational().unwrap().pfc_enabled, 0x08);
+        host.expire_neighbors(Instant::now() + Duration::from_secs(61));
+        let operational = host.dcb_operational().unwrap();
+        assert_eq!(operational.pfc_enabled, 0);
+        assert_eq!(operational.ets.bandwidth, [100, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(host_log
+            .borrow()
+            .contains("DCBX operational configuration changed: ETS (local)"));
+
+        let advertised = Lldpdu::from_bytes(&host_tx.try_recv().unwrap()[14..]);
+        assert_eq!(advertised.org_tlvs::<PriorityFlowControl>()[0].enabled, 0);
+    }
+
+    #[test]
+    fn test_dcbx_cee_peer() {
+        use crate::tlv::organizationallyspecific_tlv::cee::*;
+        use crate::tlv::organizationallyspecific_tlv::ieee8021::dcbx::*;
+        use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+
+        let host_log = Rc::new(RefCell::new(String::new()));
+        let (mut host, host_tx) = dummy_agent(
+            MacAddr::new(170, 187, 204, 221, 238, 255),
+            Box::new(MockLogger {
+                inner: host_log.clone(),
+            }),
+        );
+        let tables = EtsTables::new(
+            [0; PRIORITIES],
+            [100, 0, 0, 0, 0, 0, 0, 0],
+            [TransmissionSelectionAlgorithm::Ets; PRIORITIES],
+        );
+        host.enable_dcbx(DcbConfig::new(
+            EtsConfiguration::new(true, false, 8, tables),
+            PriorityFlowControl::new(true, false, 8, 0),
+            ApplicationPriority::new(vec![]),
+        ));
+
+        let switch = MacAddr::new(102, 111, 111, 98, 97, 114);
+        let announcement = |pfc_enabled, seq_no, ack_no| {
+            let cee = CeeDcbx::new(vec![
+                CeeSubTlv::Control(CeeControl::new(0, 0, seq_no, ack_no)),
+                CeeSubTlv::Pfc(
+                    CeeFeatureHeader::new(true, false, false),
+                    CeePfc {
+                        enabled: pfc_enabled,
+                        num_tcs: 8,
+                    },
+                ),
+            ]);
+            let lldpdu = Lldpdu::new(vec![
+                Tlv::ChassisId(ChassisIdTLV::new(
+                    ChassisIdSubType::MacAddress,
+                    ChassisIdValue::Mac(switch.octets().to_vec()),
+                )),
+                Tlv::PortId(PortIdTLV::new(
+                    PortIdSubtype::InterfaceName,
+                    PortIdValue::Other(String::from("Ethernet1/1")),
+                )),
+                Tlv::Ttl(TtlTLV::new(120)),
+                Tlv::OrganizationallySpecific(OrganizationallySpecificTLV::from_payload(&cee)),
+            ]);
+            build_frame(
+                switch,
+                MacAddr::new(0x01, 0x80, 0xc2, 0x00, 0x00, 0x0e),
+                &lldpdu,
+            )
+        };
+        let advertised_control = |frame: Box<[u8]>| {
+            let lldpdu = Lldpdu::from_bytes(&frame[14..]);
+            *lldpdu.org_tlvs::<CeeDcbx>()[0].control().unwrap()
+        };
+
+        // The host adopts the configuration of the switch and answers with a CEE DCBX TLV
+        assert!(host.receive(&announcement(0x08, 7, 0)));
+        assert_eq!(host.dcb_operational().unwrap().pfc_enabled, 0x08);
+        assert_eq!(
+            advertised_control(host_tx.try_recv().unwrap()),
+            CeeControl::new(0, 0, 1, 7)
+        );
+        assert!(host_tx.try_recv().is_err());
+
+        // An acknowledgement of the host's configuration needs no answer
+        assert!(host.receive(&announcement(0x08, 7, 1)));
+        assert!(host_tx.try_recv().is_err());
+        assert!(host.dcbx.as_ref().unwrap().cee_acknowledged());
+
+        // A new sequence number of the switch is acknowledged right away, even without changes
+        host_log.borrow_mut().clear();
+        assert!(host.receive(&announcement(0x08, 8, 1)));
+        assert!(!host_log
+            .borrow()
+            .contains("DCBX operational configuration changed"));
+        assert_eq!(
+            advertised_control(host_tx.try_recv().unwrap()),
+            CeeControl::new(0, 0, 1, 8)
+        );
+    }
+
+    #[test]
+    fn test_fabric_attach_client() {
+        use crate::tlv::organizationallyspecific_tlv::fabric_attach::*;
+        use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+
+        let server_log = Rc::new(RefCell::new(String::new()));
+        let (mut server, _) = dummy_agent(
+            MacAddr::new(102, 111, 111, 98, 97, 114),
+            Box::new(MockLogger {
+                inner: server_log.clone(),
+            }),
+        );
+        server.set_log_format(LogFormat::Detailed);
+
+        let (mut client, client_tx) = dummy_agent(
+            MacAddr::new(170, 187, 204, 221, 238, 255),
+            Box::new(MockLogger {
+                inner: Rc::new(RefCell::new(String::new())),
+            }),
+        );
+        let element =
+            FaElement::try_new(15, 0, 0, [170, 187, 204, 221, 238, 255, 0, 0, 0, 0]).unwrap();
+        let assignments = FaAssignments::try_new(vec!["10100:100".parse().unwrap()]).unwrap();
+        client.add_optional_tlv(
+            Tlv::OrganizationallySpecific(OrganizationallySpecificTLV::from_payload(&element)),
+            0,
+        );
+        client.add_optional_tlv(
+            Tlv::OrganizationallySpecific(OrganizationallySpecificTLV::from_payload(&assignments)),
+            0,
+        );
+        client.announce();
+
+        let announcement = client_tx.try_recv().unwrap();
+        let requested = Lldpdu::from_bytes(&announcement[14..]).org_tlvs::<FaAssignments>();
+        assert_eq!(
+            requested[0].assignments[0].status,
+            FaAssignmentStatus::Pending
+        );
+
+        assert!(server.receive(&announcement));
+        assert!(server_log
+            .borrow()
+            .contains("FA Element: FA Client - Server/Endpoint"));
+        assert!(server_log
+            .borrow()
+            .contains("FA I-SID/VLAN Assignments: I-SID 10100 -> VLAN 100 (pending)"));
+    }
+
     #[test]
     fn test_socket_bind() {
         let _ = LLDPAgent::new(
@@ -363,4 +1111,4 @@ mod tests {
 
         assert_eq!(full_log.borrow().as_str(), "LLDPDU(ChassisIdTLV(4, \"FF:EE:DD:CC:BB:AA\"), PortIdTLV(3, \"FF:EE:DD:CC:BB:AA\"), TtlTLV(120), EndOfLLDPDUTLV)");
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,659 @@
+//! IEEE 802.1Qaz DCBX attribute state machines
+//!
+//! A port running DCBX advertises its ETS, PFC and Application Priority configuration and, if it is willing,
+//! adopts the configuration of its peer. The result is the operational configuration which is to be applied to the
+//! NIC.
+//!
+//! The agent runs the state machines for its port, see [Dcbx]. Peers speaking the pre-standard CEE DCBX are
+//! supported as well.
+
+mod cee;
+
+use crate::lldpdu::Lldpdu;
+use crate::tlv::organizationallyspecific_tlv::cee::CeeDcbx;
+use crate::tlv::organizationallyspecific_tlv::ieee8021::dcbx::{
+    ApplicationPriority, ApplicationPriorityEntry, EtsConfiguration, EtsRecommendation, EtsTables,
+    PriorityFlowControl, PRIORITIES,
+};
+use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+use crate::tlv::Tlv;
+
+use pnet::datalink::MacAddr;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use cee::{CeeState, RemoteCee};
+
+/// Administrative DCB configuration of the local port
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DcbConfig {
+    /// ETS configuration, including the willing bit for ETS and Application Priority
+    pub ets: EtsConfiguration,
+    /// ETS configuration recommended to the peer, if any
+    pub ets_recommendation: Option<EtsRecommendation>,
+    /// PFC configuration, including the willing bit for PFC
+    pub pfc: PriorityFlowControl,
+    /// Application priority table
+    pub app: ApplicationPriority,
+}
+
+impl DcbConfig {
+    /// Constructor
+    pub fn new(
+        ets: EtsConfiguration,
+        pfc: PriorityFlowControl,
+        app: ApplicationPriority,
+    ) -> DcbConfig {
+        DcbConfig {
+            ets,
+            ets_recommendation: None,
+            pfc,
+            app,
+        }
+    }
+}
+
+/// Origin of an operational DCB attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// The administrative configuration of the local port
+    Local,
+    /// The configuration advertised by the peer
+    Remote,
+}
+
+impl Display for ConfigSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigSource::Local => write!(f, "local"),
+            ConfigSource::Remote => write!(f, "remote"),
+        }
+    }
+}
+
+/// Operational DCB configuration of the local port, as negotiated with the peer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperationalDcbConfig {
+    /// ETS tables in effect
+    pub ets: EtsTables,
+    /// Origin of the ETS tables
+    pub ets_source: ConfigSource,
+    /// Priorities PFC is enabled on, by bit
+    pub pfc_enabled: u8,
+    /// Origin of the PFC configuration
+    pub pfc_source: ConfigSource,
+    /// Whether the PFC configuration of the peer differs from the local one and neither side adopts the other's
+    pub pfc_mismatch: bool,
+    /// Application priority table in effect
+    pub app: Vec<ApplicationPriorityEntry>,
+    /// Origin of the application priority table
+    pub app_source: ConfigSource,
+    /// Whether more than one DCBX peer is present, in which case the local configuration is used
+    pub multiple_peers: bool,
+}
+
+impl OperationalDcbConfig {
+    /// Create the operational configuration of a port without peer.
+    fn local(config: &DcbConfig) -> OperationalDcbConfig {
+        OperationalDcbConfig {
+            ets: config.ets.tables,
+            ets_source: ConfigSource::Local,
+            pfc_enabled: config.pfc.enabled,
+            pfc_source: ConfigSource::Local,
+            pfc_mismatch: false,
+            app: config.app.entries.clone(),
+            app_source: ConfigSource::Local,
+            multiple_peers: false,
+        }
+    }
+}
+
+impl Display for OperationalDcbConfig {
+    /// Write the configuration, e.g. `ETS (remote): TC map [...], ..., PFC (local, mismatch): enabled on [3], ...`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pfc: Vec<String> = (0..PRIORITIES)
+            .filter(|priority| self.pfc_enabled & (1 << priority) != 0)
+            .map(|priority| priority.to_string())
+            .collect();
+
+        write!(
+            f,
+            "ETS ({}): {}, PFC ({}{}): enabled on [{}], App ({}): {}{}",
+            self.ets_source,
+            self.ets,
+            self.pfc_source,
+            if self.pfc_mismatch { ", mismatch" } else { "" },
+            pfc.join(" "),
+            self.app_source,
+            ApplicationPriority::new(self.app.clone()),
+            if self.multiple_peers {
+                ", multiple peers"
+            } else {
+                ""
+            }
+        )
+    }
+}
+
+/// DCB configuration advertised by a peer
+#[derive(Debug, Clone)]
+struct RemoteDcbConfig {
+    ets_recommendation: Option<EtsRecommendation>,
+    pfc: Option<PriorityFlowControl>,
+    app: Option<ApplicationPriority>,
+    /// Set if the peer speaks CEE DCBX
+    cee: Option<RemoteCee>,
+}
+
+impl RemoteDcbConfig {
+    /// Get the DCB configuration advertised in `lldpdu`, `None` if it carries no DCBX TLVs.
+    ///
+    /// The IEEE 802.1Qaz TLVs take precedence over a CEE DCBX TLV.
+    fn from_lldpdu(lldpdu: &Lldpdu) -> Option<RemoteDcbConfig> {
+        let remote = RemoteDcbConfig {
+            ets_recommendation: lldpdu.org_tlvs::<EtsRecommendation>().into_iter().next(),
+            pfc: lldpdu.org_tlvs::<PriorityFlowControl>().into_iter().next(),
+            app: lldpdu.org_tlvs::<ApplicationPriority>().into_iter().next(),
+            cee: None,
+        };
+        if remote.ets_recommendation.is_none() && remote.pfc.is_none() && remote.app.is_none() {
+            return lldpdu.org_tlvs::<CeeDcbx>().first().map(cee::remote_config);
+        }
+        Some(remote)
+    }
+}
+
+/// DCBX state of a port
+///
+/// Keeps the configuration advertised by each DCBX peer on the port and runs the attribute state machines of
+/// IEEE 802.1Qaz against the peer:
+///
+/// * ETS is an asymmetric attribute. A willing port adopts the ETS Recommendation of its peer, a port which is not
+///   willing or whose peer does not send a recommendation uses its local configuration.
+/// * PFC is a symmetric attribute. A willing port adopts the PFC configuration of a peer which is not willing. If
+///   both ports are willing, the port with the numerically higher MAC address adopts the configuration of its peer.
+///   If neither port adopts the other's configuration and they differ, a mismatch is reported.
+/// * Application Priority has no willing bit of its own. The application priority table of the peer is adopted if
+///   the local port is willing to accept the ETS configuration.
+///
+/// Attributes the peer does not advertise fall back to the local configuration, as do all attributes once the peer
+/// has shut down (a TTL of 0) or its information has expired, see [Dcbx::expire].
+///
+/// A peer which only sends the CEE DCBX TLV is answered with a CEE DCBX TLV instead of the IEEE 802.1Qaz TLVs. Its
+/// Priority Group, PFC and Application features are negotiated like the IEEE attributes, except that the Priority
+/// Group and Application features are symmetric: they have willing bits of their own, which are compared like the
+/// ones of PFC. Configuration changes are acknowledged through the sequence numbers of the control sub-TLV, see
+/// [Dcbx::cee_acknowledged] and [Dcbx::take_ack_pending].
+///
+/// DCBX is defined for point-to-point links. While more than one peer advertises DCBX TLVs, the port uses its local
+/// configuration and reports `multiple_peers` until only one of them is left.
+#[derive(Debug, Clone)]
+pub struct Dcbx {
+    mac_address: MacAddr,
+    local: DcbConfig,
+    remotes: HashMap<MacAddr, RemoteDcbConfig>,
+    operational: OperationalDcbConfig,
+    cee: CeeState,
+}
+
+impl Dcbx {
+    /// Create the DCBX state of the port with the given `mac_address` and administrative configuration.
+    pub fn new(mac_address: MacAddr, local: DcbConfig) -> Dcbx {
+        let operational = OperationalDcbConfig::local(&local);
+        Dcbx {
+            mac_address,
+            local,
+            remotes: HashMap::new(),
+            operational,
+            cee: CeeState::default(),
+        }
+    }
+
+    /// Get the administrative configuration.
+    pub fn local(&self) -> &DcbConfig {
+        &self.local
+    }
+
+    /// Get the operational configuration.
+    pub fn operational(&self) -> &OperationalDcbConfig {
+        &self.operational
+    }
+
+    /// Process an LLDPDU received from the peer `source`.
+    ///
+    /// Returns `true` if the operational configuration changed.
+    pub fn receive(&mut self, source: MacAddr, lldpdu: &Lldpdu) -> bool {
+        let remote = match lldpdu.ttl() {
+            Some(0) => None,
+            _ => RemoteDcbConfig::from_lldpdu(lldpdu),
+        };
+        let was_cee = self
+            .remotes
+            .get(&source)
+            .is_some_and(|remote| remote.cee.is_some());
+        match remote.as_ref().and_then(|remote| remote.cee) {
+            Some(cee) => self.cee.receive(&cee.control),
+            None if was_cee => self.cee.reset_peer(),
+            None => (),
+        }
+        match remote {
+            Some(remote) => self.remotes.insert(source, remote),
+            None => self.remotes.remove(&source),
+        };
+
+        self.update()
+    }
+
+    /// Forget the configuration of the peer `source`, e.g. because its information has expired.
+    ///
+    /// Returns `true` if the operational configuration changed.
+    pub fn expire(&mut self, source: MacAddr) -> bool {
+        let remote = match self.remotes.remove(&source) {
+            Some(remote) => remote,
+            None => return false,
+        };
+        if remote.cee.is_some() {
+            self.cee.reset_peer();
+        }
+        self.update()
+    }
+
+    /// Whether the port negotiates with a single peer speaking CEE DCBX.
+    pub fn cee_peer(&self) -> bool {
+        self.remotes.len() == 1 && self.remotes.values().all(|remote| remote.cee.is_some())
+    }
+
+    /// Whether the CEE peer acknowledged the configuration advertised last.
+    pub fn cee_acknowledged(&self) -> bool {
+        self.cee_peer() && self.cee.acknowledged()
+    }
+
+    /// Return `true` once after the CEE peer advertised a new sequence number.
+    ///
+    /// The peer expects an LLDPDU acknowledging it right away, even if the operational configuration did not change.
+    pub fn take_ack_pending(&mut self) -> bool {
+        self.cee.take_ack_pending()
+    }
+
+    /// Run the attribute state machines.
+    ///
+    /// Returns `true` if the operational configuration changed.
+    fn update(&mut self) -> bool {
+        let operational = self.negotiate();
+        if self.cee_peer() {
+            self.cee.update(&self.local, &operational);
+        }
+
+        let changed = operational != self.operational;
+        self.operational = operational;
+        changed
+    }
+
+    /// Get the operational configuration resulting from the local and remote configuration.
+    fn negotiate(&self) -> OperationalDcbConfig {
+        let mut operational = OperationalDcbConfig::local(&self.local);
+        operational.multiple_peers = self.remotes.len() > 1;
+
+        let (source, remote) = match self.remotes.iter().next() {
+            Some((source, remote)) if self.remotes.len() == 1 => (*source, remote),
+            _ => return operational,
+        };
+
+        // The ETS Recommendation is offered to willing ports, CEE features are adopted from peers which are not
+        // willing themselves
+        let adopt_ets = |remote_willing: bool| {
+            self.local.ets.willing && (!remote_willing || self.mac_address > source)
+        };
+        let cee = remote.cee.as_ref();
+        if adopt_ets(cee.is_some_and(|cee| cee.pg_willing)) {
+            if let Some(recommendation) = &remote.ets_recommendation {
+                operational.ets = recommendation.tables;
+                operational.ets_source = ConfigSource::Remote;
+            }
+        }
+        if adopt_ets(cee.is_some_and(|cee| cee.app_willing)) {
+            if let Some(app) = &remote.app {
+                operational.app = app.entries.clone();
+                operational.app_source = ConfigSource::Remote;
+            }
+        }
+
+        if let Some(pfc) = &remote.pfc {
+            let adopt = self.local.pfc.willing && (!pfc.willing || self.mac_address > source);
+            let adopted_by_peer =
+                pfc.willing && (!self.local.pfc.willing || source > self.mac_address);
+
+            if adopt {
+                operational.pfc_enabled = pfc.enabled;
+                operational.pfc_source = ConfigSource::Remote;
+            } else if !adopted_by_peer {
+                operational.pfc_mismatch = pfc.enabled != self.local.pfc.enabled;
+            }
+        }
+
+        operational
+    }
+
+    /// Get the DCBX TLVs to advertise.
+    ///
+    /// The ETS Configuration, PFC and Application Priority TLVs carry the operational configuration along with the
+    /// local willing bits and capabilities. A CEE peer is sent a CEE DCBX TLV carrying the same information instead.
+    pub fn tlvs(&self) -> Vec<Tlv> {
+        if self.cee_peer() {
+            let cee = OrganizationallySpecificTLV::from_payload(&self.cee.tlv());
+            return vec![Tlv::OrganizationallySpecific(cee)];
+        }
+
+        let ets = EtsConfiguration {
+            tables: self.operational.ets,
+            ..self.local.ets
+        };
+        let pfc = PriorityFlowControl {
+            enabled: self.operational.pfc_enabled,
+            ..self.local.pfc
+        };
+        let app = ApplicationPriority::new(self.operational.app.clone());
+
+        let mut tlvs = vec![OrganizationallySpecificTLV::from_payload(&ets)];
+        if let Some(recommendation) = &self.local.ets_recommendation {
+            tlvs.push(OrganizationallySpecificTLV::from_payload(recommendation));
+        }
+        tlvs.push(OrganizationallySpecificTLV::from_payload(&pfc));
+        tlvs.push(OrganizationallySpecificTLV::from_payload(&app));
+
+        tlvs.into_iter()
+            .map(Tlv::OrganizationallySpecific)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::chassisid_tlv::{ChassisIdSubType, ChassisIdTLV, ChassisIdValue};
+    use crate::tlv::organizationallyspecific_tlv::cee::{CeeAppEntry, CeeControl, CEE_OUI};
+    use crate::tlv::organizationallyspecific_tlv::ieee8021::dcbx::{
+        ApplicationSelector, TransmissionSelectionAlgorithm,
+    };
+    use crate::tlv::portid_tlv::{PortIdSubtype, PortIdTLV, PortIdValue};
+    use crate::tlv::ttl_tlv::TtlTLV;
+
+    const LOCAL: MacAddr = MacAddr(0x02, 0x00, 0x00, 0x00, 0x00, 0x02);
+    const LOWER: MacAddr = MacAddr(0x02, 0x00, 0x00, 0x00, 0x00, 0x01);
+    const HIGHER: MacAddr = MacAddr(0x02, 0x00, 0x00, 0x00, 0x00, 0x03);
+
+    fn tables(bandwidth: [u8; PRIORITIES]) -> EtsTables {
+        EtsTables::new(
+            [0; PRIORITIES],
+            bandwidth,
+            [TransmissionSelectionAlgorithm::Ets; PRIORITIES],
+        )
+    }
+
+    fn config(willing: bool, pfc_enabled: u8) -> DcbConfig {
+        DcbConfig::new(
+            EtsConfiguration::new(willing, false, 8, tables([100, 0, 0, 0, 0, 0, 0, 0])),
+            PriorityFlowControl::new(willing, false, 8, pfc_enabled),
+            ApplicationPriority::new(vec![]),
+        )
+    }
+
+    fn lldpdu(ttl: u16, payloads: Vec<Tlv>) -> Lldpdu {
+        let mut tlvs = vec![
+            Tlv::ChassisId(ChassisIdTLV::new(
+                ChassisIdSubType::MacAddress,
+                ChassisIdValue::Mac(vec![0x02, 0, 0, 0, 0, 0x01]),
+            )),
+            Tlv::PortId(PortIdTLV::new(
+                PortIdSubtype::InterfaceName,
+                PortIdValue::Other(String::from("eth0")),
+            )),
+            Tlv::Ttl(TtlTLV::new(ttl)),
+        ];
+        tlvs.extend(payloads);
+        Lldpdu::new(tlvs)
+    }
+
+    fn remote(willing: bool, pfc_enabled: u8) -> Lldpdu {
+        let mut config = config(willing, pfc_enabled);
+        config.ets_recommendation =
+            Some(EtsRecommendation::new(tables([50, 50, 0, 0, 0, 0, 0, 0])));
+        config.app = ApplicationPriority::new(vec![ApplicationPriorityEntry::new(
+            3,
+            ApplicationSelector::UdpDccp,
+            4791,
+        )]);
+        lldpdu(120, Dcbx::new(HIGHER, config).tlvs())
+    }
+
+    #[test]
+    fn test_willing_adopts_remote() {
+        let mut dcbx = Dcbx::new(LOCAL, config(true, 0));
+        assert!(dcbx.receive(LOWER, &remote(false, 0x08)));
+
+        let operational = dcbx.operational();
+        assert_eq!(operational.ets_source, ConfigSource::Remote);
+        assert_eq!(operational.ets.bandwidth, [50, 50, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(operational.pfc_source, ConfigSource::Remote);
+        assert_eq!(operational.pfc_enabled, 0x08);
+        assert_eq!(operational.app_source, ConfigSource::Remote);
+        assert_eq!(operational.app.len(), 1);
+
+        // Receiving the same configuration again changes nothing
+        assert!(!dcbx.receive(LOWER, &remote(false, 0x08)));
+    }
+
+    #[test]
+    fn test_not_willing_keeps_local() {
+        let mut dcbx = Dcbx::new(LOCAL, config(false, 0x08));
+        assert!(!dcbx.receive(LOWER, &remote(true, 0x10)));
+        assert_eq!(
+            *dcbx.operational(),
+            OperationalDcbConfig::local(dcbx.local())
+        );
+    }
+
+    #[test]
+    fn test_pfc_mismatch() {
+        let mut dcbx = Dcbx::new(LOCAL, config(false, 0x08));
+        assert!(dcbx.receive(LOWER, &remote(false, 0x10)));
+        assert!(dcbx.operational().pfc_mismatch);
+        assert_eq!(dcbx.operational().pfc_enabled, 0x08);
+
+        assert!(dcbx.receive(LOWER, &remote(false, 0x08)));
+        assert!(!dcbx.operational().pfc_mismatch);
+    }
+
+    #[test]
+    fn test_pfc_both_willing() {
+        // The port with the higher MAC address adopts the configuration of its peer
+        let mut dcbx = Dcbx::new(LOCAL, config(true, 0x08));
+        assert!(dcbx.receive(LOWER, &remote(true, 0x10)));
+        assert_eq!(dcbx.operational().pfc_source, ConfigSource::Remote);
+
+        let mut dcbx = Dcbx::new(LOCAL, config(true, 0x08));
+        dcbx.receive(HIGHER, &remote(true, 0x10));
+        assert_eq!(dcbx.operational().pfc_source, ConfigSource::Local);
+        assert_eq!(dcbx.operational().pfc_enabled, 0x08);
+        assert!(!dcbx.operational().pfc_mismatch);
+    }
+
+    #[test]
+    fn test_peer_shutdown() {
+        let mut dcbx = Dcbx::new(LOCAL, config(true, 0));
+        dcbx.receive(LOWER, &remote(false, 0x08));
+        assert!(dcbx.receive(LOWER, &lldpdu(0, vec![])));
+        assert_eq!(
+            *dcbx.operational(),
+            OperationalDcbConfig::local(dcbx.local())
+        );
+    }
+
+    #[test]
+    fn test_peer_expired() {
+        let mut dcbx = Dcbx::new(LOCAL, config(true, 0));
+        dcbx.receive(LOWER, &remote(false, 0x08));
+        assert!(!dcbx.expire(HIGHER));
+        assert!(dcbx.expire(LOWER));
+        assert_eq!(
+            *dcbx.operational(),
+            OperationalDcbConfig::local(dcbx.local())
+        );
+        assert!(!dcbx.expire(LOWER));
+    }
+
+    #[test]
+    fn test_multiple_peers() {
+        let mut dcbx = Dcbx::new(LOCAL, config(true, 0));
+        assert!(dcbx.receive(LOWER, &remote(false, 0x08)));
+
+        // A second peer puts the port back to its local configuration
+        assert!(dcbx.receive(HIGHER, &remote(false, 0x10)));
+        let operational = dcbx.operational();
+        assert!(operational.multiple_peers);
+        assert_eq!(operational.pfc_source, ConfigSource::Local);
+        assert_eq!(operational.pfc_enabled, 0);
+        assert!(!operational.pfc_mismatch);
+        assert!(format!("{}", operational).ends_with(", multiple peers"));
+
+        // Neighbors without DCBX TLVs are not peers
+        assert!(!dcbx.receive(HIGHER, &remote(false, 0x10)));
+        assert!(dcbx.receive(HIGHER, &lldpdu(120, vec![])));
+        assert!(!dcbx.operational().multiple_peers);
+        assert_eq!(dcbx.operational().pfc_enabled, 0x08);
+
+        assert!(!dcbx.expire(HIGHER));
+    }
+
+    fn cee_remote_apps() -> Vec<CeeAppEntry> {
+        vec![CeeAppEntry {
+            protocol: 0x8906,
+            selector: 0,
+            oui: CEE_OUI,
+            priority_map: 0x08,
+        }]
+    }
+
+    fn cee_remote(willing: bool, pfc_enabled: u8, seq_no: u32, ack_no: u32) -> Lldpdu {
+        use crate::tlv::organizationallyspecific_tlv::cee::*;
+
+        let cee = CeeDcbx::new(vec![
+            CeeSubTlv::Control(CeeControl::new(0, 0, seq_no, ack_no)),
+            CeeSubTlv::PriorityGroups(
+                CeeFeatureHeader::new(true, willing, false),
+                CeePriorityGroups {
+                    pgid: [0, 0, 0, 1, 0, 0, 0, 0],
+                    percentage: [50, 50, 0, 0, 0, 0, 0, 0],
+                    num_tcs: 8,
+                },
+            ),
+            CeeSubTlv::Pfc(
+                CeeFeatureHeader::new(true, willing, false),
+                CeePfc {
+                    enabled: pfc_enabled,
+                    num_tcs: 8,
+                },
+            ),
+            CeeSubTlv::Application(
+                CeeFeatureHeader::new(true, willing, false),
+                cee_remote_apps(),
+            ),
+        ]);
+        lldpdu(
+            120,
+            vec![Tlv::OrganizationallySpecific(
+                OrganizationallySpecificTLV::from_payload(&cee),
+            )],
+        )
+    }
+
+    #[test]
+    fn test_cee_peer() {
+        let mut dcbx = Dcbx::new(LOCAL, config(true, 0));
+        assert!(dcbx.receive(LOWER, &cee_remote(false, 0x08, 5, 0)));
+        assert!(dcbx.cee_peer());
+
+        let operational = dcbx.operational();
+        assert_eq!(operational.ets_source, ConfigSource::Remote);
+        assert_eq!(operational.ets.priority_tc, [0, 0, 0, 1, 0, 0, 0, 0]);
+        assert_eq!(operational.ets.bandwidth, [50, 50, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(operational.pfc_enabled, 0x08);
+        assert_eq!(
+            operational.app,
+            vec![ApplicationPriorityEntry::new(
+                3,
+                ApplicationSelector::Ethertype,
+                0x8906
+            )]
+        );
+
+        // The peer is answered with a CEE DCBX TLV acknowledging its sequence number
+        assert!(dcbx.take_ack_pending());
+        assert!(!dcbx.take_ack_pending());
+        let advertised = lldpdu(120, dcbx.tlvs());
+        assert!(advertised.org_tlvs::<PriorityFlowControl>().is_empty());
+        let cee = &advertised.org_tlvs::<CeeDcbx>()[0];
+        assert_eq!(cee.control().unwrap().seq_no, 1);
+        assert_eq!(cee.control().unwrap().ack_no, 5);
+        assert_eq!(cee.pfc().unwrap().1.enabled, 0x08);
+        assert!(cee.pfc().unwrap().0.willing);
+        assert_eq!(cee.applications().unwrap().1, cee_remote_apps().as_slice());
+        assert!(!dcbx.cee_acknowledged());
+
+        // The peer acknowledges the configuration
+        assert!(!dcbx.receive(LOWER, &cee_remote(false, 0x08, 5, 1)));
+        assert!(dcbx.cee_acknowledged());
+        assert!(!dcbx.take_ack_pending());
+
+        // A new configuration of the peer is adopted and acknowledged under a new sequence number
+        assert!(dcbx.receive(LOWER, &cee_remote(false, 0x10, 6, 1)));
+        assert!(dcbx.take_ack_pending());
+        assert!(!dcbx.cee_acknowledged());
+        let cee = lldpdu(120, dcbx.tlvs()).org_tlvs::<CeeDcbx>()[0].clone();
+        assert_eq!(*cee.control().unwrap(), CeeControl::new(0, 0, 2, 6));
+
+        // Without the CEE peer, the IEEE TLVs are advertised again
+        assert!(dcbx.expire(LOWER));
+        assert!(!dcbx.cee_peer());
+        let advertised = lldpdu(120, dcbx.tlvs());
+        assert!(advertised.org_tlvs::<CeeDcbx>().is_empty());
+        assert_eq!(advertised.org_tlvs::<PriorityFlowControl>()[0].enabled, 0);
+    }
+
+    #[test]
+    fn test_cee_willing_peer() {
+        // Both sides are willing, the port with the higher MAC address adopts the configuration of its peer
+        let mut dcbx = Dcbx::new(LOCAL, config(true, 0));
+        dcbx.receive(HIGHER, &cee_remote(true, 0x08, 1, 0));
+        assert_eq!(dcbx.operational().ets_source, ConfigSource::Local);
+        assert_eq!(dcbx.operational().pfc_source, ConfigSource::Local);
+        assert_eq!(dcbx.operational().app_source, ConfigSource::Local);
+
+        let mut dcbx = Dcbx::new(LOCAL, config(true, 0));
+        dcbx.receive(LOWER, &cee_remote(true, 0x08, 1, 0));
+        assert_eq!(dcbx.operational().ets_source, ConfigSource::Remote);
+        assert_eq!(dcbx.operational().pfc_source, ConfigSource::Remote);
+        assert_eq!(dcbx.operational().app_source, ConfigSource::Remote);
+    }
+
+    #[test]
+    fn test_tlvs() {
+        let mut dcbx = Dcbx::new(LOCAL, config(true, 0));
+        dcbx.receive(LOWER, &remote(false, 0x08));
+
+        let advertised = lldpdu(120, dcbx.tlvs());
+        let ets = advertised.org_tlvs::<EtsConfiguration>()[0];
+        assert!(ets.willing);
+        assert_eq!(ets.tables.bandwidth, [50, 50, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(
+            advertised.org_tlvs::<PriorityFlowControl>()[0].enabled,
+            0x08
+        );
+        assert!(advertised.org_tlvs::<EtsRecommendation>().is_empty());
+        assert_eq!(
+            format!("{}", dcbx.operational()),
+            "ETS (remote): TC map [0 0 0 0 0 0 0 0], bandwidth [50 50 0 0 0 0 0 0], \
+             TSA [ets ets ets ets ets ets ets ets], PFC (remote): enabled on [3], App (remote): udp 4791 -> 3"
+        );
+    }
+}
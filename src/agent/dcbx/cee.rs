@@ -0,0 +1,197 @@
+//! CEE DCBX support of the DCBX state machines
+//!
+//! Peers which only speak the pre-standard CEE DCBX advertise their whole configuration in a single
+//! [CeeDcbx] TLV. It is translated to the IEEE 802.1Qaz attributes, so that the same state machines apply, and the
+//! port answers with a [CeeDcbx] TLV of its own.
+//!
+//! CEE DCBX acknowledges configurations explicitly: the control sub-TLV carries a sequence number, which the sender
+//! increments whenever its advertised configuration changes, and the last sequence number received from the peer.
+
+use crate::agent::dcbx::{DcbConfig, OperationalDcbConfig, RemoteDcbConfig};
+use crate::tlv::organizationallyspecific_tlv::cee::{
+    CeeAppEntry, CeeControl, CeeDcbx, CeeFeatureHeader, CeePfc, CeePriorityGroups, CeeSubTlv,
+    CEE_OUI,
+};
+use crate::tlv::organizationallyspecific_tlv::ieee8021::dcbx::{
+    ApplicationPriority, ApplicationPriorityEntry, ApplicationSelector, EtsRecommendation,
+    EtsTables, PriorityFlowControl, TransmissionSelectionAlgorithm, PRIORITIES,
+};
+
+/// Willing bits and control sub-TLV of a CEE peer
+#[derive(Debug, Clone, Copy)]
+pub(super) struct RemoteCee {
+    /// Control sub-TLV
+    pub(super) control: CeeControl,
+    /// Whether the peer is willing to accept the Priority Group configuration
+    pub(super) pg_willing: bool,
+    /// Whether the peer is willing to accept the Application configuration
+    pub(super) app_willing: bool,
+}
+
+/// Translate the configuration advertised in a CEE DCBX TLV.
+///
+/// Features which are not enabled are treated as not advertised. The priority groups become the traffic classes of
+/// the ETS Recommendation, priorities in the strict priority group keep its PGID 15. CEE application entries list
+/// all priorities an application may use, they become one entry per priority.
+pub(super) fn remote_config(cee: &CeeDcbx) -> RemoteDcbConfig {
+    let control = cee
+        .control()
+        .copied()
+        .unwrap_or_else(|| CeeControl::new(0, 0, 0, 0));
+    let pg = cee.priority_groups().filter(|(header, _)| header.enabled);
+    let pfc = cee.pfc().filter(|(header, _)| header.enabled);
+    let app = cee.applications().filter(|(header, _)| header.enabled);
+
+    RemoteDcbConfig {
+        ets_recommendation: pg.map(|(_, groups)| {
+            EtsRecommendation::new(EtsTables::new(
+                groups.pgid,
+                groups.percentage,
+                [TransmissionSelectionAlgorithm::Ets; PRIORITIES],
+            ))
+        }),
+        pfc: pfc.map(|(header, pfc)| {
+            PriorityFlowControl::new(header.willing, false, pfc.num_tcs, pfc.enabled)
+        }),
+        app: app.map(|(_, entries)| {
+            ApplicationPriority::new(entries.iter().flat_map(app_entries).collect())
+        }),
+        cee: Some(RemoteCee {
+            control,
+            pg_willing: pg.is_some_and(|(header, _)| header.willing),
+            app_willing: app.is_some_and(|(header, _)| header.willing),
+        }),
+    }
+}
+
+/// Split a CEE application entry into one IEEE entry per priority.
+fn app_entries(entry: &CeeAppEntry) -> Vec<ApplicationPriorityEntry> {
+    let selector = match entry.selector {
+        0 => ApplicationSelector::Ethertype,
+        1 => ApplicationSelector::TcpSctpUdpDccp,
+        selector => ApplicationSelector::Reserved(selector),
+    };
+    (0..PRIORITIES as u8)
+        .filter(|priority| entry.priority_map & (1 << priority) != 0)
+        .map(|priority| ApplicationPriorityEntry::new(priority, selector, entry.protocol))
+        .collect()
+}
+
+/// Merge IEEE application entries into CEE entries with a priority map.
+///
+/// Entries whose selector has no CEE equivalent, e.g. DSCP values, are left out.
+fn cee_app_entries(entries: &[ApplicationPriorityEntry]) -> Vec<CeeAppEntry> {
+    let mut merged: Vec<CeeAppEntry> = Vec::new();
+    for entry in entries {
+        let selector = match entry.selector {
+            ApplicationSelector::Ethertype => 0,
+            ApplicationSelector::TcpSctp
+            | ApplicationSelector::UdpDccp
+            | ApplicationSelector::TcpSctpUdpDccp => 1,
+            _ => continue,
+        };
+        let priority_map = 1 << (entry.priority & 0x07);
+        match merged
+            .iter_mut()
+            .find(|cee| cee.selector == selector && cee.protocol == entry.protocol)
+        {
+            Some(cee) => cee.priority_map |= priority_map,
+            None => merged.push(CeeAppEntry {
+                protocol: entry.protocol,
+                selector,
+                oui: CEE_OUI,
+                priority_map,
+            }),
+        }
+    }
+    merged
+}
+
+/// CEE control state of a port
+#[derive(Debug, Clone, Default)]
+pub(super) struct CeeState {
+    /// Sequence number of the advertised configuration
+    seq_no: u32,
+    /// Last sequence number received from the peer
+    ack_no: u32,
+    /// Last sequence number of the port acknowledged by the peer
+    peer_ack_no: u32,
+    /// Whether a new sequence number of the peer has not been acknowledged yet
+    ack_pending: bool,
+    /// Feature sub-TLVs advertised with `seq_no`
+    features: Vec<CeeSubTlv>,
+}
+
+impl CeeState {
+    /// Process the control sub-TLV received from the peer.
+    pub(super) fn receive(&mut self, control: &CeeControl) {
+        if control.seq_no != self.ack_no {
+            self.ack_no = control.seq_no;
+            self.ack_pending = true;
+        }
+        self.peer_ack_no = control.ack_no;
+    }
+
+    /// Forget the control state of the peer, e.g. because it has gone away.
+    pub(super) fn reset_peer(&mut self) {
+        self.ack_no = 0;
+        self.peer_ack_no = 0;
+        self.ack_pending = false;
+    }
+
+    /// Update the advertised features, starting a new sequence number if they changed.
+    pub(super) fn update(&mut self, local: &DcbConfig, operational: &OperationalDcbConfig) {
+        let features = features(local, operational);
+        if features != self.features {
+            self.seq_no = self.seq_no.wrapping_add(1).max(1);
+            self.features = features;
+        }
+    }
+
+    /// Whether the peer acknowledged the advertised configuration.
+    pub(super) fn acknowledged(&self) -> bool {
+        self.seq_no != 0 && self.peer_ack_no == self.seq_no
+    }
+
+    /// Return `true` once after a new sequence number has been received from the peer.
+    pub(super) fn take_ack_pending(&mut self) -> bool {
+        std::mem::replace(&mut self.ack_pending, false)
+    }
+
+    /// Get the CEE DCBX TLV to advertise.
+    pub(super) fn tlv(&self) -> CeeDcbx {
+        let mut subtlvs = vec![CeeSubTlv::Control(CeeControl::new(
+            0,
+            0,
+            self.seq_no,
+            self.ack_no,
+        ))];
+        subtlvs.extend(self.features.iter().cloned());
+        CeeDcbx::new(subtlvs)
+    }
+}
+
+/// Get the feature sub-TLVs describing the operational configuration along with the local willing bits.
+fn features(local: &DcbConfig, operational: &OperationalDcbConfig) -> Vec<CeeSubTlv> {
+    vec![
+        CeeSubTlv::PriorityGroups(
+            CeeFeatureHeader::new(true, local.ets.willing, false),
+            CeePriorityGroups {
+                pgid: operational.ets.priority_tc,
+                percentage: operational.ets.bandwidth,
+                num_tcs: local.ets.max_tcs,
+            },
+        ),
+        CeeSubTlv::Pfc(
+            CeeFeatureHeader::new(true, local.pfc.willing, operational.pfc_mismatch),
+            CeePfc {
+                enabled: operational.pfc_enabled,
+                num_tcs: local.pfc.capability,
+            },
+        ),
+        CeeSubTlv::Application(
+            CeeFeatureHeader::new(true, local.ets.willing, false),
+            cee_app_entries(&operational.app),
+        ),
+    ]
+}
@@ -0,0 +1,14 @@
+//! LLDP agent library
+//!
+//! Contains the TLV and LLDPDU codecs as well as the [LLDPAgent](agent::LLDPAgent). The `lldp-rs` binary runs an
+//! agent on a network interface.
+//!
+//! Other crates can use the library to decode their own Organizationally Specific TLVs, see
+//! [registry](tlv::organizationallyspecific_tlv::registry), or describe them in a TOML file, see
+//! [layout](tlv::organizationallyspecific_tlv::layout).
+
+pub mod agent;
+pub mod json;
+pub mod lldpdu;
+pub mod tlv;
+pub(crate) mod toml;
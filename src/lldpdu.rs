@@ -1,13 +1,25 @@
+use crate::tlv::organizationallyspecific_tlv::registry::OrgTlvDecode;
 use crate::tlv::{
     chassisid_tlv::ChassisIdTLV, eolldpdu_tlv::EndOfLLDPDUTLV,
-    managementaddress_tlv::ManagementAddressTLV,
+    managementaddress_tlv::ManagementAddressTLV, manifest_tlv::ManifestTLV,
     organizationallyspecific_tlv::OrganizationallySpecificTLV,
     portdescription_tlv::PortDescriptionTLV, portid_tlv::PortIdTLV,
     systemcapabilities_tlv::SystemCapabilitiesTLV, systemdescription_tlv::SystemDescriptionTLV,
-    systemname_tlv::SystemNameTLV, ttl_tlv::TtlTLV, Tlv, TlvType,
+    systemname_tlv::SystemNameTLV, ttl_tlv::TtlTLV, xpdurequest_tlv::XpduRequestTLV, Tlv, TlvType,
 };
 use std::{convert::TryFrom, fmt::Display};
 
+pub mod diff;
+pub mod neighbor;
+pub mod xpdu;
+
+use diff::LldpduDiff;
+
+/// Maximum size of an LLDPDU in bytes
+///
+/// An LLDPDU has to fit into the payload of a single Ethernet frame.
+pub const MAX_LLDPDU_SIZE: usize = 1500;
+
 /// LLDP Data Unit
 ///
 /// The LLDP Data Unit contains an ordered sequence of TLVs, three mandatory TLVs followed by zero or more optional TLVs
@@ -19,11 +31,13 @@ use std::{convert::TryFrom, fmt::Display};
 ///
 /// LLDPDU Format:
 ///
-///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+
-///     |                 |                 |                 |                                 |
-///     | Chassis ID TLV  |   Port ID TLV   |     TTL TLV     |         (Optional TLVs)         |
-///     |                 |                 |                 |                                 |
-///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+
+/// ```text
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+
+/// |                 |                 |                 |                                 |
+/// | Chassis ID TLV  |   Port ID TLV   |     TTL TLV     |         (Optional TLVs)         |
+/// |                 |                 |                 |                                 |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+
+/// ```
 #[derive(Debug, Clone)]
 pub struct Lldpdu {
     has_end: bool,
@@ -73,7 +87,7 @@ impl Lldpdu {
 
             let mut length = data[index + 1] as usize;
             if data[index] & 1 == 1 {
-                length += 1 << 9;
+                length += 1 << 8;
             }
 
             let bytes = &data[index..index + 2 + length];
@@ -96,6 +110,8 @@ impl Lldpdu {
                 TlvType::ManagementAddress => {
                     Tlv::ManagementAddress(ManagementAddressTLV::new_from_bytes(bytes))
                 }
+                TlvType::Manifest => Tlv::Manifest(ManifestTLV::new_from_bytes(bytes)),
+                TlvType::XpduRequest => Tlv::XpduRequest(XpduRequestTLV::new_from_bytes(bytes)),
                 TlvType::OrganizationallySpecific => Tlv::OrganizationallySpecific(
                     OrganizationallySpecificTLV::new_from_bytes(bytes),
                 ),
@@ -126,6 +142,46 @@ impl Lldpdu {
         lldpdu
     }
 
+    /// Constructor for the transmit path
+    ///
+    /// Creates a `Lldpdu` from the `mandatory` [Tlv]s followed by as many of the `optional` TLVs as fit into
+    /// [MAX_LLDPDU_SIZE], leaving room for an End Of LLDPDU TLV.
+    ///
+    /// Each optional TLV comes with a priority, a higher value meaning more important. TLVs are considered from the
+    /// highest to the lowest priority, and a TLV which no longer fits is omitted instead of failing the whole LLDPDU.
+    /// TLVs of equal priority are considered in the given order. The kept TLVs retain their relative order.
+    ///
+    /// Returns the LLDPDU and the omitted TLVs.
+    pub fn new_prioritized(mandatory: Vec<Tlv>, optional: Vec<(Tlv, u8)>) -> (Lldpdu, Vec<Tlv>) {
+        let mut lldpdu = Lldpdu::new(mandatory);
+
+        let mut by_priority: Vec<usize> = (0..optional.len()).collect();
+        by_priority.sort_by_key(|&index| std::cmp::Reverse(optional[index].1));
+
+        let mut budget = MAX_LLDPDU_SIZE - EndOfLLDPDUTLV::new().bytes().len();
+        budget = budget.saturating_sub(lldpdu.size);
+
+        let mut keep = vec![false; optional.len()];
+        for index in by_priority {
+            let tlv_size = optional[index].0.bytes().len();
+            if tlv_size <= budget {
+                keep[index] = true;
+                budget -= tlv_size;
+            }
+        }
+
+        let mut omitted = Vec::new();
+        for ((tlv, _), keep) in optional.into_iter().zip(keep) {
+            if keep {
+                lldpdu.append(tlv);
+            } else {
+                omitted.push(tlv);
+            }
+        }
+
+        (lldpdu, omitted)
+    }
+
     /// Append `tlv` to the LLDPDU.
     ///
     /// This method adds the given [Tlv] to the LLDPDU.
@@ -135,7 +191,7 @@ impl Lldpdu {
     pub fn append(&mut self, tlv: Tlv) {
         let tlv_size = tlv.bytes().len();
 
-        if self.size + tlv_size > 1500 {
+        if self.size + tlv_size > MAX_LLDPDU_SIZE {
             panic!("tlv size overflow");
         }
 
@@ -216,6 +272,34 @@ impl Lldpdu {
             None => panic!("index out of bound"),
         }
     }
+
+    /// Get the TTL of the LLDPDU in seconds, if it carries a TTL TLV
+    pub fn ttl(&self) -> Option<u16> {
+        self.tlvs.iter().find_map(|tlv| match tlv {
+            Tlv::Ttl(ttl) => Some(ttl.value),
+            _ => None,
+        })
+    }
+
+    /// Get all Organizationally Specific TLVs which decode as `T`, in order.
+    ///
+    /// E.g. the port VLAN ID of a neighbor is `lldpdu.org_tlvs::<PortVlanId>().first().map(|tlv| tlv.pvid)`.
+    pub fn org_tlvs<T: OrgTlvDecode>(&self) -> Vec<T> {
+        self.tlvs
+            .iter()
+            .filter_map(|tlv| match tlv {
+                Tlv::OrganizationallySpecific(tlv) => tlv.decode_as::<T>(),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Compute the semantic difference between this LLDPDU and `other`.
+    ///
+    /// `self` is treated as the old and `other` as the new state, see [LldpduDiff] for details.
+    pub fn diff(&self, other: &Lldpdu) -> LldpduDiff {
+        LldpduDiff::between(self, other)
+    }
 }
 
 #[cfg(test)]
@@ -407,6 +491,80 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_prioritized_all_fit() {
+        let mandatory = vec![
+            Tlv::ChassisId(ChassisIdTLV::new(
+                ChassisIdSubType::Local,
+                ChassisIdValue::Other(String::from("unittest")),
+            )),
+            Tlv::PortId(PortIdTLV::new(
+                PortIdSubtype::Local,
+                PortIdValue::Other(String::from("port(12)")),
+            )),
+            Tlv::Ttl(TtlTLV::new(120)),
+        ];
+        let optional = vec![
+            (
+                Tlv::SystemName(SystemNameTLV::new(String::from("HAL9000"))),
+                1,
+            ),
+            (
+                Tlv::SystemDescription(SystemDescriptionTLV::new(String::from("Heuristic"))),
+                0,
+            ),
+        ];
+
+        let (lldpdu, omitted) = Lldpdu::new_prioritized(mandatory, optional);
+        assert_eq!(lldpdu.len(), 5);
+        assert!(omitted.is_empty());
+        assert_eq!(lldpdu.getitem(3).get_type(), TlvType::SystemName);
+        assert_eq!(lldpdu.getitem(4).get_type(), TlvType::SystemDescription);
+    }
+
+    #[test]
+    fn test_prioritized_drops_lowest() {
+        let description =
+            |text: &str| Tlv::SystemDescription(SystemDescriptionTLV::new(text.repeat(250)));
+        let mandatory = vec![
+            Tlv::ChassisId(ChassisIdTLV::new(
+                ChassisIdSubType::Local,
+                ChassisIdValue::Other(String::from("unittest")),
+            )),
+            Tlv::PortId(PortIdTLV::new(
+                PortIdSubtype::Local,
+                PortIdValue::Other(String::from("port(12)")),
+            )),
+            Tlv::Ttl(TtlTLV::new(120)),
+        ];
+        let optional = vec![
+            (description("a"), 3),
+            (description("b"), 1),
+            (description("c"), 5),
+            (description("d"), 2),
+            (description("e"), 4),
+            (description("f"), 0),
+            (description("g"), 6),
+            (
+                Tlv::SystemName(SystemNameTLV::new(String::from("HAL9000"))),
+                0,
+            ),
+        ];
+
+        let (lldpdu, omitted) = Lldpdu::new_prioritized(mandatory, optional);
+
+        assert!(lldpdu.bytes().len() + 2 <= MAX_LLDPDU_SIZE);
+        // Only five descriptions of 252 bytes fit next to the mandatory TLVs, the smaller system name still fits
+        assert_eq!(lldpdu.len(), 3 + 5 + 1);
+        assert_eq!(omitted.len(), 2);
+        assert_eq!(omitted[0].bytes(), description("b").bytes());
+        assert_eq!(omitted[1].bytes(), description("f").bytes());
+        // Kept TLVs retain their order
+        assert_eq!(lldpdu.getitem(3).bytes(), description("a").bytes());
+        assert_eq!(lldpdu.getitem(7).bytes(), description("g").bytes());
+        assert_eq!(lldpdu.getitem(8).get_type(), TlvType::SystemName);
+    }
+
     #[test]
     fn test_lldpdu_complete() {
         let tlvs = vec![
@@ -496,6 +654,21 @@ mod tests {
         assert_eq!(lldpdu.len(), 5);
     }
 
+    #[test]
+    fn test_load_long_tlv() {
+        let description = "x".repeat(300);
+        let mut bytes = b"\x02\x08\x07Voyager\x04\x06\x0710743\x06\x02\x00\xff\x0d\x2c".to_vec();
+        bytes.extend_from_slice(description.as_bytes());
+        bytes.extend_from_slice(b"\x00\x00");
+
+        let lldpdu = Lldpdu::from_bytes(&bytes);
+        assert_eq!(lldpdu.len(), 5);
+        match lldpdu.getitem(3) {
+            Tlv::SystemDescription(tlv) => assert_eq!(tlv.value, description),
+            tlv => panic!("Unexpected TLV {}", tlv),
+        }
+    }
+
     #[test]
     fn test_display() {
         let lldpdu = Lldpdu::new(vec![
@@ -515,4 +688,4 @@ mod tests {
             "LLDPDU(ChassisIdTLV(7, \"chair\"), PortIdTLV(7, \"Mathekeller\"), TtlTLV(1234))"
         );
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,427 @@
+use crate::lldpdu::Lldpdu;
+use crate::tlv::{Tlv, TlvType};
+
+use std::fmt::Display;
+
+/// Identity of a TLV within an LLDPDU
+///
+/// Two TLVs from different LLDPDUs describe the same thing if they share a key. TLVs are matched by their type and,
+/// for Organizationally Specific TLVs, additionally by OUI and subtype.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TlvKey {
+    /// The type of the TLV
+    pub tlv_type: TlvType,
+    /// Organizationally unique identifier (Organizationally Specific TLVs only)
+    pub oui: Option<Vec<u8>>,
+    /// Organizationally defined subtype (Organizationally Specific TLVs only)
+    pub subtype: Option<u8>,
+}
+
+impl TlvKey {
+    /// Get the key of `tlv`.
+    pub fn of(tlv: &Tlv) -> TlvKey {
+        match tlv {
+            Tlv::OrganizationallySpecific(org) => TlvKey {
+                tlv_type: TlvType::OrganizationallySpecific,
+                oui: Some(org.oui.clone()),
+                subtype: Some(org.subtype),
+            },
+            tlv => TlvKey {
+                tlv_type: tlv.get_type(),
+                oui: None,
+                subtype: None,
+            },
+        }
+    }
+}
+
+/// A single difference between two LLDPDUs
+#[derive(Debug, Clone)]
+pub enum TlvChange {
+    /// The TLV is only present in the new LLDPDU
+    Added(Tlv),
+    /// The TLV is only present in the old LLDPDU
+    Removed(Tlv),
+    /// The TLV is present in both LLDPDUs, but its value changed
+    Modified { old: Tlv, new: Tlv },
+}
+
+impl TlvChange {
+    /// Get the key of the TLV affected by this change.
+    pub fn key(&self) -> TlvKey {
+        match self {
+            TlvChange::Added(tlv) | TlvChange::Removed(tlv) => TlvKey::of(tlv),
+            TlvChange::Modified { new, .. } => TlvKey::of(new),
+        }
+    }
+}
+
+impl Display for TlvChange {
+    /// Write a printable representation of the change.
+    ///
+    /// Added TLVs are prefixed with `+`, removed TLVs with `-` and modified TLVs with `~`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TlvChange::Added(tlv) => write!(f, "+ {}", tlv),
+            TlvChange::Removed(tlv) => write!(f, "- {}", tlv),
+            TlvChange::Modified { old, new } => write!(f, "~ {} -> {}", old, new),
+        }
+    }
+}
+
+/// Semantic difference between two LLDPDUs
+///
+/// The difference is computed on the level of TLVs rather than bytes. Optional TLVs may appear in any order, so
+/// TLVs are matched by their [TlvKey]. The End Of LLDPDU TLV carries no information and is ignored.
+///
+/// If several TLVs share a key (e.g. multiple Management Address TLVs), identical TLVs are matched first. The
+/// remaining TLVs are paired up in order of appearance and reported as modified, any surplus is reported as added or
+/// removed.
+///
+/// The diff is available in structured form via [LldpduDiff::changes] and in human readable form via `Display`, which
+/// writes one change per line.
+#[derive(Debug, Clone, Default)]
+pub struct LldpduDiff {
+    changes: Vec<TlvChange>,
+}
+
+impl Display for LldpduDiff {
+    /// Write a printable representation of the diff, one change per line.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, change) in self.changes.iter().enumerate() {
+            if index != 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+impl LldpduDiff {
+    /// Compute the changes that turn `old` into `new`.
+    pub fn between(old: &Lldpdu, new: &Lldpdu) -> LldpduDiff {
+        let old_tlvs: Vec<&Tlv> = old.tlvs.iter().filter(|tlv| is_compared(tlv)).collect();
+        let new_tlvs: Vec<&Tlv> = new.tlvs.iter().filter(|tlv| is_compared(tlv)).collect();
+
+        // Keys in order of first appearance, so the result is deterministic
+        let mut keys: Vec<TlvKey> = Vec::new();
+        for tlv in old_tlvs.iter().chain(new_tlvs.iter()) {
+            let key = TlvKey::of(tlv);
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+
+        let mut changes = Vec::new();
+
+        for key in keys {
+            let mut removed: Vec<&Tlv> = old_tlvs
+                .iter()
+                .copied()
+                .filter(|tlv| TlvKey::of(tlv) == key)
+                .collect();
+            let mut added: Vec<&Tlv> = new_tlvs
+                .iter()
+                .copied()
+                .filter(|tlv| TlvKey::of(tlv) == key)
+                .collect();
+
+            // Drop TLVs present unchanged in both LLDPDUs
+            removed.retain(|old_tlv| {
+                let old_bytes = old_tlv.bytes();
+                match added
+                    .iter()
+                    .position(|new_tlv| new_tlv.bytes() == old_bytes)
+                {
+                    Some(index) => {
+                        added.remove(index);
+                        false
+                    }
+                    None => true,
+                }
+            });
+
+            let paired = removed.len().min(added.len());
+            for (old_tlv, new_tlv) in removed.iter().zip(added.iter()) {
+                changes.push(TlvChange::Modified {
+                    old: (*old_tlv).clone(),
+                    new: (*new_tlv).clone(),
+                });
+            }
+            for tlv in &removed[paired..] {
+                changes.push(TlvChange::Removed((*tlv).clone()));
+            }
+            for tlv in &added[paired..] {
+                changes.push(TlvChange::Added((*tlv).clone()));
+            }
+        }
+
+        LldpduDiff { changes }
+    }
+
+    /// Drop changes to the TTL TLV.
+    ///
+    /// A neighbor re-announcing itself with a different TTL is usually not worth an alert.
+    pub fn ignore_ttl(mut self) -> LldpduDiff {
+        self.changes
+            .retain(|change| change.key().tlv_type != TlvType::Ttl);
+        self
+    }
+
+    /// Check if both LLDPDUs carry the same information
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Get all changes in order
+    pub fn changes(&self) -> &[TlvChange] {
+        &self.changes
+    }
+
+    /// Get the TLVs only present in the new LLDPDU
+    pub fn added(&self) -> Vec<&Tlv> {
+        self.changes
+            .iter()
+            .filter_map(|change| match change {
+                TlvChange::Added(tlv) => Some(tlv),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Get the TLVs only present in the old LLDPDU
+    pub fn removed(&self) -> Vec<&Tlv> {
+        self.changes
+            .iter()
+            .filter_map(|change| match change {
+                TlvChange::Removed(tlv) => Some(tlv),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Get the TLVs present in both LLDPDUs with a different value, as `(old, new)` pairs
+    pub fn modified(&self) -> Vec<(&Tlv, &Tlv)> {
+        self.changes
+            .iter()
+            .filter_map(|change| match change {
+                TlvChange::Modified { old, new } => Some((old, new)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+fn is_compared(tlv: &Tlv) -> bool {
+    tlv.get_type() != TlvType::EndOfLLDPDU
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::chassisid_tlv::{ChassisIdSubType, ChassisIdTLV, ChassisIdValue};
+    use crate::tlv::eolldpdu_tlv::EndOfLLDPDUTLV;
+    use crate::tlv::managementaddress_tlv::{IFNumberingSubtype, ManagementAddressTLV};
+    use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+    use crate::tlv::portid_tlv::{PortIdSubtype, PortIdTLV, PortIdValue};
+    use crate::tlv::systemdescription_tlv::SystemDescriptionTLV;
+    use crate::tlv::systemname_tlv::SystemNameTLV;
+    use crate::tlv::ttl_tlv::TtlTLV;
+
+    fn lldpdu(ttl: u16, optional: Vec<Tlv>) -> Lldpdu {
+        let mut tlvs = vec![
+            Tlv::ChassisId(ChassisIdTLV::new(
+                ChassisIdSubType::Local,
+                ChassisIdValue::Other(String::from("Enterprise")),
+            )),
+            Tlv::PortId(PortIdTLV::new(
+                PortIdSubtype::Local,
+                PortIdValue::Other(String::from("NCC-1701")),
+            )),
+            Tlv::Ttl(TtlTLV::new(ttl)),
+        ];
+        tlvs.extend(optional);
+        tlvs.push(Tlv::EndOfLldpdu(EndOfLLDPDUTLV::new()));
+        Lldpdu::new(tlvs)
+    }
+
+    fn management_address(address: &str) -> Tlv {
+        Tlv::ManagementAddress(ManagementAddressTLV::new(
+            address.parse().unwrap(),
+            1,
+            IFNumberingSubtype::IfIndex,
+            vec![],
+        ))
+    }
+
+    #[test]
+    fn test_identical() {
+        let a = lldpdu(
+            120,
+            vec![Tlv::SystemName(SystemNameTLV::new("Kirk".into()))],
+        );
+        let b = lldpdu(
+            120,
+            vec![Tlv::SystemName(SystemNameTLV::new("Kirk".into()))],
+        );
+        assert!(a.diff(&b).is_empty());
+    }
+
+    #[test]
+    fn test_optional_order_ignored() {
+        let a = lldpdu(
+            120,
+            vec![
+                Tlv::SystemName(SystemNameTLV::new("Kirk".into())),
+                Tlv::SystemDescription(SystemDescriptionTLV::new("Captain".into())),
+                management_address("192.0.2.1"),
+                management_address("192.0.2.2"),
+            ],
+        );
+        let b = lldpdu(
+            120,
+            vec![
+                management_address("192.0.2.2"),
+                Tlv::SystemDescription(SystemDescriptionTLV::new("Captain".into())),
+                management_address("192.0.2.1"),
+                Tlv::SystemName(SystemNameTLV::new("Kirk".into())),
+            ],
+        );
+        assert!(a.diff(&b).is_empty());
+    }
+
+    #[test]
+    fn test_added_removed_modified() {
+        let a = lldpdu(
+            120,
+            vec![
+                Tlv::SystemName(SystemNameTLV::new("Kirk".into())),
+                Tlv::SystemDescription(SystemDescriptionTLV::new("Captain".into())),
+            ],
+        );
+        let b = lldpdu(
+            120,
+            vec![
+                Tlv::SystemName(SystemNameTLV::new("Picard".into())),
+                management_address("192.0.2.1"),
+            ],
+        );
+
+        let diff = a.diff(&b);
+        assert_eq!(diff.changes().len(), 3);
+
+        let modified = diff.modified();
+        assert_eq!(modified.len(), 1);
+        assert_eq!(format!("{}", modified[0].0), "SystemNameTLV(\"Kirk\")");
+        assert_eq!(format!("{}", modified[0].1), "SystemNameTLV(\"Picard\")");
+
+        let removed = diff.removed();
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].get_type(), TlvType::SystemDescription);
+
+        let added = diff.added();
+        assert_eq!(added.len(), 1);
+        assert_eq!(added[0].get_type(), TlvType::ManagementAddress);
+    }
+
+    #[test]
+    fn test_multiple_instances() {
+        let a = lldpdu(
+            120,
+            vec![
+                management_address("192.0.2.1"),
+                management_address("192.0.2.2"),
+            ],
+        );
+        let b = lldpdu(
+            120,
+            vec![
+                management_address("192.0.2.2"),
+                management_address("192.0.2.3"),
+                management_address("192.0.2.4"),
+            ],
+        );
+
+        let diff = a.diff(&b);
+        assert_eq!(diff.modified().len(), 1);
+        assert_eq!(diff.added().len(), 1);
+        assert!(diff.removed().is_empty());
+    }
+
+    #[test]
+    fn test_org_specific_matched_by_oui_and_subtype() {
+        let a = lldpdu(
+            120,
+            vec![
+                Tlv::OrganizationallySpecific(OrganizationallySpecificTLV::new(
+                    b"\x00\x80\xc2".to_vec(),
+                    1,
+                    vec![0, 1],
+                )),
+                Tlv::OrganizationallySpecific(OrganizationallySpecificTLV::new(
+                    b"\x00\x12\x0f".to_vec(),
+                    4,
+                    vec![5, 0xee],
+                )),
+            ],
+        );
+        let b = lldpdu(
+            120,
+            vec![
+                Tlv::OrganizationallySpecific(OrganizationallySpecificTLV::new(
+                    b"\x00\x12\x0f".to_vec(),
+                    4,
+                    vec![0x23, 0x28],
+                )),
+                Tlv::OrganizationallySpecific(OrganizationallySpecificTLV::new(
+                    b"\x00\x80\xc2".to_vec(),
+                    2,
+                    vec![6, 0, 1],
+                )),
+            ],
+        );
+
+        let diff = a.diff(&b);
+        assert_eq!(diff.modified().len(), 1);
+        assert_eq!(
+            diff.modified()[0].1.get_type(),
+            TlvType::OrganizationallySpecific
+        );
+        assert_eq!(diff.added().len(), 1);
+        assert_eq!(diff.removed().len(), 1);
+        assert_eq!(
+            diff.removed()[0].bytes(),
+            b"\xfe\x06\x00\x80\xc2\x01\x00\x01"
+        );
+    }
+
+    #[test]
+    fn test_ignore_ttl() {
+        let a = lldpdu(
+            120,
+            vec![Tlv::SystemName(SystemNameTLV::new("Kirk".into()))],
+        );
+        let b = lldpdu(60, vec![Tlv::SystemName(SystemNameTLV::new("Kirk".into()))]);
+
+        let diff = a.diff(&b);
+        assert_eq!(diff.modified().len(), 1);
+        assert!(diff.ignore_ttl().is_empty());
+    }
+
+    #[test]
+    fn test_display() {
+        let a = lldpdu(
+            120,
+            vec![Tlv::SystemDescription(SystemDescriptionTLV::new(
+                "Captain".into(),
+            ))],
+        );
+        let b = lldpdu(60, vec![Tlv::SystemName(SystemNameTLV::new("Kirk".into()))]);
+
+        assert_eq!(
+            format!("{}", a.diff(&b)),
+            "~ TtlTLV(120) -> TtlTLV(60)\n- SystemDescriptionTLV(\"Captain\")\n+ SystemNameTLV(\"Kirk\")"
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use crate::lldpdu::Lldpdu;
+use crate::tlv::chassisid_tlv::{ChassisIdSubType, ChassisIdTLV, ChassisIdValue};
+use crate::tlv::managementaddress_tlv::{IFNumberingSubtype, ManagementAddressTLV};
+use crate::tlv::organizationallyspecific_tlv::ieee8021::{PortVlanId, VlanName};
+use crate::tlv::organizationallyspecific_tlv::registry::format_oui;
+use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+use crate::tlv::portid_tlv::{PortIdSubtype, PortIdTLV, PortIdValue};
+use crate::tlv::systemcapabilities_tlv::SystemCapabilitiesTLV;
+use crate::tlv::{format_hex, format_mac, Tlv};
+
+use std::fmt::Display;
+
+/// Names of the system capabilities, by bit position
+const CAPABILITY_NAMES: [&str; 11] = [
+    "Other",
+    "Repeater",
+    "Bridge",
+    "WLAN AP",
+    "Router",
+    "Telephone",
+    "DOCSIS",
+    "Station",
+    "C-VLAN",
+    "S-VLAN",
+    "TPMR",
+];
+
+/// Operator-friendly view of an LLDPDU received from a neighbor
+///
+/// Where the `Display` implementation of [Lldpdu] puts all TLVs into a single line, the neighbor view shows one
+/// labelled line per TLV, similar to `lldpctl`:
+///
+/// ```text
+/// Chassis: mac 02:04:df:88:a2:b4
+/// Port: ifname Gi1/0/12
+/// TTL: 120s
+/// System name: switch1
+/// Capabilities: Bridge, Router [enabled: Router]
+/// Management address: 192.0.2.1 (ifindex 4)
+/// VLANs:
+///   VID   Name
+///   100   users (PVID)
+///   200   voice
+/// ```
+///
+/// VLAN Name TLVs are collected into a single table, shown in place of the first one.
+///
+/// See [Lldpdu::neighbor_view].
+#[derive(Debug, Clone, Copy)]
+pub struct NeighborView<'a> {
+    lldpdu: &'a Lldpdu,
+}
+
+impl Display for NeighborView<'_> {
+    /// Write the labelled lines, separated by newlines.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut lines = Vec::new();
+        let mut vlans_rendered = false;
+
+        for tlv in &self.lldpdu.tlvs {
+            if let Tlv::OrganizationallySpecific(tlv) = tlv {
+                if tlv.decode_as::<VlanName>().is_some() {
+                    if !vlans_rendered {
+                        lines.push(vlan_table(self.lldpdu));
+                        vlans_rendered = true;
+                    }
+                    continue;
+                }
+            }
+            lines.extend(render(tlv));
+        }
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+impl Lldpdu {
+    /// Get a multi-line, human readable view of the LLDPDU.
+    pub fn neighbor_view(&self) -> NeighborView<'_> {
+        NeighborView { lldpdu: self }
+    }
+}
+
+/// Render a single TLV as a labelled line, `None` for TLVs which carry no information for the operator.
+fn render(tlv: &Tlv) -> Option<String> {
+    let line = match tlv {
+        Tlv::ChassisId(tlv) => format!("Chassis: {}", chassis_id(tlv)),
+        Tlv::PortId(tlv) => format!("Port: {}", port_id(tlv)),
+        Tlv::Ttl(tlv) => format!("TTL: {}s", tlv.value),
+        Tlv::PortDescription(tlv) => format!("Port description: {}", tlv.value),
+        Tlv::SystemName(tlv) => format!("System name: {}", tlv.value),
+        Tlv::SystemDescription(tlv) => format!("System description: {}", tlv.value),
+        Tlv::SystemCapabilities(tlv) => format!("Capabilities: {}", capabilities(tlv)),
+        Tlv::ManagementAddress(tlv) => format!("Management address: {}", management_address(tlv)),
+        Tlv::OrganizationallySpecific(tlv) => organizationally_specific(tlv),
+        Tlv::Manifest(tlv) => {
+            let numbers: Vec<String> = tlv.value.iter().map(|d| d.number.to_string()).collect();
+            format!("XPDUs: {}", numbers.join(", "))
+        }
+        Tlv::XpduRequest(tlv) => {
+            let numbers: Vec<String> = tlv.value.iter().map(|n| n.to_string()).collect();
+            format!("XPDU request: {}", numbers.join(", "))
+        }
+        Tlv::EndOfLldpdu(_) => return None,
+    };
+
+    Some(line)
+}
+
+fn chassis_id(tlv: &ChassisIdTLV) -> String {
+    let kind = match tlv.subtype {
+        ChassisIdSubType::ChassisComponent => "chassis",
+        ChassisIdSubType::InterfaceAlias => "ifalias",
+        ChassisIdSubType::PortComponent => "port",
+        ChassisIdSubType::MacAddress => "mac",
+        ChassisIdSubType::NetworkAddress => "ip",
+        ChassisIdSubType::InterfaceName => "ifname",
+        ChassisIdSubType::Local => "local",
+    };
+
+    let value = match &tlv.value {
+        ChassisIdValue::Mac(octets) => format_mac(octets),
+        ChassisIdValue::IpAddress(addr) => addr.to_string(),
+        ChassisIdValue::Other(s) => s.clone(),
+    };
+
+    format!("{} {}", kind, value)
+}
+
+fn port_id(tlv: &PortIdTLV) -> String {
+    let kind = match tlv.subtype {
+        PortIdSubtype::InterfaceAlias => "ifalias",
+        PortIdSubtype::PortComponent => "port",
+        PortIdSubtype::MacAddress => "mac",
+        PortIdSubtype::NetworkAddress => "ip",
+        PortIdSubtype::InterfaceName => "ifname",
+        PortIdSubtype::CircuitId => "circuit",
+        PortIdSubtype::Local => "local",
+    };
+
+    let value = match &tlv.value {
+        PortIdValue::Mac(octets) => format_mac(octets),
+        PortIdValue::IpAddress(addr) => addr.to_string(),
+        PortIdValue::CircuitId(circuit_id) => circuit_id.to_string(),
+        PortIdValue::Other(s) => s.clone(),
+    };
+
+    format!("{} {}", kind, value)
+}
+
+fn capability_list(bitmap: u32) -> String {
+    let names: Vec<String> = (0..16)
+        .filter(|bit| bitmap & (1 << bit) != 0)
+        .map(|bit| match CAPABILITY_NAMES.get(bit) {
+            Some(name) => name.to_string(),
+            None => format!("Bit {}", bit),
+        })
+        .collect();
+
+    if names.is_empty() {
+        String::from("none")
+    } else {
+        names.join(", ")
+    }
+}
+
+fn capabilities(tlv: &SystemCapabilitiesTLV) -> String {
+    format!(
+        "{} [enabled: {}]",
+        capability_list(tlv.value >> 16),
+        capability_list(tlv.value & 0xFFFF)
+    )
+}
+
+fn management_address(tlv: &ManagementAddressTLV) -> String {
+    let numbering = match tlv.subtype {
+        IFNumberingSubtype::Unknown => "unknown",
+        IFNumberingSubtype::IfIndex => "ifindex",
+        IFNumberingSubtype::SystemPort => "port",
+    };
+
+    format!("{} ({} {})", tlv.value, numbering, tlv.interface_number)
+}
+
+fn organizationally_specific(tlv: &OrganizationallySpecificTLV) -> String {
+    if let Some(payload) = tlv.decoded() {
+        return format!("{}: {}", payload.name(), payload);
+    }
+
+    format!(
+        "Organizationally specific: OUI {}, subtype {}: {}",
+        format_oui(&tlv.oui),
+        tlv.subtype,
+        format_hex(&tlv.value, " ")
+    )
+}
+
+/// Render all VLAN Name TLVs as a table, marking the port VLAN.
+fn vlan_table(lldpdu: &Lldpdu) -> String {
+    let pvid = lldpdu.org_tlvs::<PortVlanId>().first().map(|tlv| tlv.pvid);
+    let mut vlans = lldpdu.org_tlvs::<VlanName>();
+    vlans.sort_by_key(|vlan| vlan.vid);
+
+    let mut table = String::from("VLANs:\n  VID   Name");
+    for vlan in vlans {
+        table.push_str(&format!("\n  {:<5} {}", vlan.vid, vlan.name));
+        if Some(vlan.vid) == pvid {
+            table.push_str(" (PVID)");
+        }
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::eolldpdu_tlv::EndOfLLDPDUTLV;
+    use crate::tlv::systemname_tlv::SystemNameTLV;
+    use crate::tlv::ttl_tlv::TtlTLV;
+
+    fn set_up() -> Lldpdu {
+        Lldpdu::new(vec![
+            Tlv::ChassisId(ChassisIdTLV::new(
+                ChassisIdSubType::MacAddress,
+                ChassisIdValue::Mac(b"\x02\x04\xdf\x88\xa2\xb4".to_vec()),
+            )),
+            Tlv::PortId(PortIdTLV::new(
+                PortIdSubtype::InterfaceName,
+                PortIdValue::Other(String::from("Gi1/0/12")),
+            )),
+            Tlv::Ttl(TtlTLV::new(120)),
+            Tlv::SystemName(SystemNameTLV::new(String::from("switch1"))),
+            Tlv::SystemCapabilities(SystemCapabilitiesTLV::new(0b10100, 0b10000)),
+            Tlv::ManagementAddress(ManagementAddressTLV::new(
+                "192.0.2.1".parse().unwrap(),
+                4,
+                IFNumberingSubtype::IfIndex,
+                vec![],
+            )),
+            Tlv::OrganizationallySpecific(OrganizationallySpecificTLV::new(
+                b"\x00\x80\xc2".to_vec(),
+                1,
+                b"\x00\x64".to_vec(),
+            )),
+            Tlv::EndOfLldpdu(EndOfLLDPDUTLV::new()),
+        ])
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            format!("{}", set_up().neighbor_view()),
+            "Chassis: mac 02:04:df:88:a2:b4\n\
+             Port: ifname Gi1/0/12\n\
+             TTL: 120s\n\
+             System name: switch1\n\
+             Capabilities: Bridge, Router [enabled: Router]\n\
+             Management address: 192.0.2.1 (ifindex 4)\n\
+             Port VLAN ID: 100"
+        );
+    }
+
+    #[test]
+    fn test_vlan_table() {
+        let mut lldpdu = set_up();
+        let end = lldpdu.tlvs.pop().unwrap();
+        for (vid, name) in [(200, "voice"), (100, "users")] {
+            lldpdu.tlvs.push(Tlv::OrganizationallySpecific(
+                OrganizationallySpecificTLV::from_payload(&VlanName::new(vid, name.to_string())),
+            ));
+        }
+        lldpdu.tlvs.push(end);
+
+        let view = format!("{}", lldpdu.neighbor_view());
+        assert!(view.ends_with(
+            "Port VLAN ID: 100\n\
+             VLANs:\n  \
+             VID   Name\n  \
+             100   users (PVID)\n  \
+             200   voice"
+        ));
+    }
+
+    #[test]
+    fn test_power_via_mdi() {
+        let tlv = OrganizationallySpecificTLV::new(
+            b"\x00\x12\x0f".to_vec(),
+            2,
+            b"\x07\x01\x05\x13\x00\xff\x00\xff".to_vec(),
+        );
+        assert_eq!(
+            organizationally_specific(&tlv),
+            "Power via MDI: PSE, MDI supported, enabled, signal pairs, class 4; \
+             Type 2 PSE, primary source, priority low, requested 25.5 W, allocated 25.5 W"
+        );
+    }
+
+    #[test]
+    fn test_unknown_organizationally_specific() {
+        let tlv =
+            OrganizationallySpecificTLV::new(b"\xac\xde\x48".to_vec(), 7, b"\x0a\xff".to_vec());
+        assert_eq!(
+            organizationally_specific(&tlv),
+            "Organizationally specific: OUI AC-DE-48, subtype 7: 0A FF"
+        );
+    }
+
+    #[test]
+    fn test_no_capabilities_enabled() {
+        let tlv = SystemCapabilitiesTLV::new(0b10000000, 0);
+        assert_eq!(capabilities(&tlv), "Station [enabled: none]");
+    }
+}
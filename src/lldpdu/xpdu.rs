@@ -0,0 +1,338 @@
+use crate::lldpdu::{Lldpdu, MAX_LLDPDU_SIZE};
+use crate::tlv::eolldpdu_tlv::EndOfLLDPDUTLV;
+use crate::tlv::manifest_tlv::{ManifestTLV, XpduDescriptor};
+use crate::tlv::xpdurequest_tlv::XpduRequestTLV;
+use crate::tlv::{Tlv, TlvType};
+
+/// Maximum number of XPDUs a single Manifest TLV can describe
+pub const MAX_XPDUS: usize = 102;
+
+/// Stand-in descriptor used to reserve space for a Manifest TLV
+const PLACEHOLDER: XpduDescriptor = XpduDescriptor {
+    number: 0,
+    check: 0,
+};
+
+/// An LLDPDU together with the XPDUs carrying the optional TLVs which did not fit into it
+///
+/// See [Lldpdu::new_multiframe].
+#[derive(Debug, Clone)]
+pub struct Multiframe {
+    /// The regular LLDPDU, including a Manifest TLV if there are XPDUs
+    pub lldpdu: Lldpdu,
+    /// The XPDUs, numbered from 1 in order
+    pub xpdus: Vec<Lldpdu>,
+    /// Optional TLVs which neither fit into the LLDPDU nor into an XPDU
+    pub omitted: Vec<Tlv>,
+}
+
+impl Lldpdu {
+    /// Constructor for the multiframe transmit path
+    ///
+    /// Works like [Lldpdu::new_prioritized], but instead of omitting the optional TLVs which do not fit, they are
+    /// moved into XPDUs. The regular LLDPDU then carries a Manifest TLV listing the XPDUs, which takes precedence
+    /// over all optional TLVs.
+    ///
+    /// Each XPDU starts with the `mandatory` TLVs, followed by a Manifest TLV describing only the XPDU itself and the
+    /// XPDU's share of the optional TLVs.
+    pub fn new_multiframe(mandatory: Vec<Tlv>, optional: Vec<(Tlv, u8)>) -> Multiframe {
+        // The manifest competes with the optional TLVs for space. Reserve room for it and grow the reservation until
+        // it is large enough for the XPDUs it has to describe.
+        let mut reserved = 0;
+
+        loop {
+            let mut head = mandatory.clone();
+            if reserved > 0 {
+                head.push(Tlv::Manifest(ManifestTLV::new(vec![PLACEHOLDER; reserved])));
+            }
+
+            let (lldpdu, omitted) = Lldpdu::new_prioritized(head, optional.clone());
+            let (xpdus, omitted) = pack_xpdus(&mandatory, omitted);
+
+            if xpdus.len() <= reserved || reserved == MAX_XPDUS {
+                let mut tlvs = mandatory.clone();
+                if !xpdus.is_empty() {
+                    let descriptors = xpdus
+                        .iter()
+                        .map(|xpdu| xpdu.manifest().unwrap().value[0])
+                        .collect();
+                    tlvs.push(Tlv::Manifest(ManifestTLV::new(descriptors)));
+                }
+
+                let skip = mandatory.len() + if reserved > 0 { 1 } else { 0 };
+                tlvs.extend(lldpdu.tlvs.into_iter().skip(skip));
+
+                return Multiframe {
+                    lldpdu: Lldpdu::new(tlvs),
+                    xpdus,
+                    omitted,
+                };
+            }
+
+            reserved = xpdus.len();
+        }
+    }
+
+    /// Get the Manifest TLV of the LLDPDU, if any
+    pub fn manifest(&self) -> Option<&ManifestTLV> {
+        self.tlvs.iter().find_map(|tlv| match tlv {
+            Tlv::Manifest(manifest) => Some(manifest),
+            _ => None,
+        })
+    }
+
+    /// Get the XPDU Request TLV of the LLDPDU, if any
+    pub fn xpdu_request(&self) -> Option<&XpduRequestTLV> {
+        self.tlvs.iter().find_map(|tlv| match tlv {
+            Tlv::XpduRequest(request) => Some(request),
+            _ => None,
+        })
+    }
+
+    /// Combine this LLDPDU with the optional TLVs carried in its `xpdus`.
+    ///
+    /// The Manifest TLVs and the mandatory TLVs repeated in the XPDUs are dropped. The result is a view of all information advertised by the remote agent and is
+    /// not subject to [MAX_LLDPDU_SIZE], so it must not be transmitted.
+    pub fn reassemble(&self, xpdus: &[Lldpdu]) -> Lldpdu {
+        let mut tlvs: Vec<Tlv> = self
+            .tlvs
+            .iter()
+            .filter(|tlv| !is_framing(tlv))
+            .cloned()
+            .collect();
+
+        for xpdu in xpdus {
+            tlvs.extend(
+                xpdu.tlvs
+                    .iter()
+                    .filter(|tlv| !is_framing(tlv) && !is_mandatory(tlv))
+                    .cloned(),
+            );
+        }
+
+        if self.has_end {
+            tlvs.push(Tlv::EndOfLldpdu(EndOfLLDPDUTLV::new()));
+        }
+
+        let size = tlvs.iter().map(|tlv| tlv.bytes().len()).sum();
+
+        Lldpdu {
+            has_end: self.has_end,
+            tlvs,
+            size,
+        }
+    }
+}
+
+/// Compute the check value of XPDU contents.
+///
+/// The check value is the 32 bit FNV-1a hash of the encoded TLVs. This is not the algorithm of IEEE 802.1AB-2016,
+/// but a choice of this crate: check values computed here only match those of other instances of this crate.
+///
+/// Receivers never recompute the check value of a remote XPDU, they only compare the values announced by the
+/// sender. Reassembly therefore works with peers using any algorithm, as long as it changes with the contents.
+pub fn check_value(tlvs: &[Tlv]) -> u32 {
+    let mut hash: u32 = 0x811c_9dc5;
+    for tlv in tlvs {
+        for byte in tlv.bytes() {
+            hash ^= byte as u32;
+            hash = hash.wrapping_mul(0x0100_0193);
+        }
+    }
+    hash
+}
+
+fn is_framing(tlv: &Tlv) -> bool {
+    matches!(tlv.get_type(), TlvType::Manifest | TlvType::EndOfLLDPDU)
+}
+
+fn is_mandatory(tlv: &Tlv) -> bool {
+    matches!(
+        tlv.get_type(),
+        TlvType::ChassisId | TlvType::PortId | TlvType::Ttl
+    )
+}
+
+/// Distribute `tlvs` over as few XPDUs as possible, keeping their order.
+///
+/// Returns the XPDUs and the TLVs which did not fit.
+fn pack_xpdus(mandatory: &[Tlv], tlvs: Vec<Tlv>) -> (Vec<Lldpdu>, Vec<Tlv>) {
+    let overhead: usize = mandatory.iter().map(|tlv| tlv.bytes().len()).sum::<usize>()
+        + ManifestTLV::new(vec![PLACEHOLDER]).bytes().len()
+        + EndOfLLDPDUTLV::new().bytes().len();
+    let capacity = MAX_LLDPDU_SIZE.saturating_sub(overhead);
+
+    let mut contents: Vec<Vec<Tlv>> = Vec::new();
+    // Start out "full", so the first TLV opens the first XPDU
+    let mut used = capacity;
+    let mut omitted = Vec::new();
+
+    for tlv in tlvs {
+        let size = tlv.bytes().len();
+        if size > capacity {
+            omitted.push(tlv);
+            continue;
+        }
+
+        if used + size > capacity {
+            if contents.len() == MAX_XPDUS {
+                omitted.push(tlv);
+                continue;
+            }
+            contents.push(Vec::new());
+            used = 0;
+        }
+
+        used += size;
+        contents.last_mut().unwrap().push(tlv);
+    }
+
+    let xpdus = contents
+        .into_iter()
+        .enumerate()
+        .map(|(index, content)| {
+            let descriptor = XpduDescriptor {
+                number: index as u8 + 1,
+                check: check_value(&content),
+            };
+
+            let mut tlvs = mandatory.to_vec();
+            tlvs.push(Tlv::Manifest(ManifestTLV::new(vec![descriptor])));
+            tlvs.extend(content);
+            Lldpdu::new(tlvs)
+        })
+        .collect();
+
+    (xpdus, omitted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::chassisid_tlv::{ChassisIdSubType, ChassisIdTLV, ChassisIdValue};
+    use crate::tlv::portid_tlv::{PortIdSubtype, PortIdTLV, PortIdValue};
+    use crate::tlv::systemdescription_tlv::SystemDescriptionTLV;
+    use crate::tlv::systemname_tlv::SystemNameTLV;
+    use crate::tlv::ttl_tlv::TtlTLV;
+
+    fn mandatory() -> Vec<Tlv> {
+        vec![
+            Tlv::ChassisId(ChassisIdTLV::new(
+                ChassisIdSubType::Local,
+                ChassisIdValue::Other(String::from("unittest")),
+            )),
+            Tlv::PortId(PortIdTLV::new(
+                PortIdSubtype::Local,
+                PortIdValue::Other(String::from("port(12)")),
+            )),
+            Tlv::Ttl(TtlTLV::new(120)),
+        ]
+    }
+
+    fn description(index: usize) -> Tlv {
+        Tlv::SystemDescription(SystemDescriptionTLV::new(format!("{:0>250}", index)))
+    }
+
+    #[test]
+    fn test_no_xpdus_if_everything_fits() {
+        let frames = Lldpdu::new_multiframe(
+            mandatory(),
+            vec![(Tlv::SystemName(SystemNameTLV::new("HAL9000".into())), 0)],
+        );
+        assert!(frames.xpdus.is_empty());
+        assert!(frames.omitted.is_empty());
+        assert!(frames.lldpdu.manifest().is_none());
+        assert_eq!(frames.lldpdu.len(), 4);
+    }
+
+    #[test]
+    fn test_split_and_reassemble() {
+        let optional: Vec<(Tlv, u8)> = (0..12).map(|index| (description(index), 0)).collect();
+        let frames = Lldpdu::new_multiframe(mandatory(), optional);
+
+        assert!(frames.omitted.is_empty());
+        assert!(frames.lldpdu.bytes().len() <= MAX_LLDPDU_SIZE);
+        assert_eq!(frames.xpdus.len(), 2);
+        for xpdu in &frames.xpdus {
+            assert!(xpdu.bytes().len() <= MAX_LLDPDU_SIZE);
+        }
+
+        let manifest = frames.lldpdu.manifest().unwrap();
+        assert_eq!(manifest.value.len(), 2);
+        assert_eq!(manifest.value[0].number, 1);
+        assert_eq!(manifest.value[1].number, 2);
+        assert_eq!(
+            manifest.value[1],
+            frames.xpdus[1].manifest().unwrap().value[0]
+        );
+
+        assert_eq!(frames.lldpdu.len(), 3 + 1 + 5);
+
+        let full = frames.lldpdu.reassemble(&frames.xpdus);
+        assert_eq!(full.len(), 3 + 12);
+        assert!(full.manifest().is_none());
+        for index in 0..12 {
+            assert!(full
+                .tlvs
+                .iter()
+                .any(|tlv| tlv.bytes() == description(index).bytes()));
+        }
+    }
+
+    #[test]
+    fn test_reassemble_malformed_xpdu() {
+        let lldpdu = Lldpdu::new(mandatory());
+        let descriptor = XpduDescriptor {
+            number: 1,
+            check: check_value(&[description(1), description(2)]),
+        };
+        // No Port ID TLV, and the Manifest TLV follows the optional TLVs
+        let xpdu = Lldpdu {
+            has_end: false,
+            tlvs: vec![
+                mandatory()[0].clone(),
+                mandatory()[2].clone(),
+                description(1),
+                description(2),
+                Tlv::Manifest(ManifestTLV::new(vec![descriptor])),
+            ],
+            size: 0,
+        };
+
+        let full = lldpdu.reassemble(&[xpdu]);
+        assert_eq!(full.len(), 3 + 2);
+        assert_eq!(full.tlvs[3].bytes(), description(1).bytes());
+        assert_eq!(full.tlvs[4].bytes(), description(2).bytes());
+    }
+
+    #[test]
+    fn test_round_trip_through_bytes() {
+        let optional: Vec<(Tlv, u8)> = (0..8).map(|index| (description(index), 0)).collect();
+        let frames = Lldpdu::new_multiframe(mandatory(), optional);
+
+        let lldpdu = Lldpdu::from_bytes(&frames.lldpdu.bytes());
+        let xpdus: Vec<Lldpdu> = frames
+            .xpdus
+            .iter()
+            .map(|xpdu| Lldpdu::from_bytes(&xpdu.bytes()))
+            .collect();
+
+        assert_eq!(
+            lldpdu.manifest().unwrap().value,
+            frames.lldpdu.manifest().unwrap().value
+        );
+        assert_eq!(lldpdu.reassemble(&xpdus).len(), 3 + 8);
+    }
+
+    #[test]
+    fn test_check_value_changes_with_content() {
+        assert_eq!(
+            check_value(&[description(1)]),
+            check_value(&[description(1)])
+        );
+        assert_ne!(
+            check_value(&[description(1)]),
+            check_value(&[description(2)])
+        );
+    }
+}
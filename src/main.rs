@@ -1,13 +1,99 @@
-use agent::LLDPAgent;
-use pnet::datalink;
+use lldp_rs::agent::{LLDPAgent, LogFormat};
+use lldp_rs::tlv::organizationallyspecific_tlv::fabric_attach::{
+    FaAssignment, FaAssignments, FaElement,
+};
+use lldp_rs::tlv::organizationallyspecific_tlv::ieee8021::{ProtocolIdentity, VlanName};
+use lldp_rs::tlv::organizationallyspecific_tlv::ieee8023::MaxFrameSize;
+use lldp_rs::tlv::organizationallyspecific_tlv::registry::{registry_mut, OrgTlvPayload};
+use lldp_rs::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+use lldp_rs::tlv::Tlv;
+use pnet::datalink::{self, MacAddr};
 
-mod agent;
-mod lldpdu;
-mod tlv;
+const USAGE: &str = "usage: lldp-rs [INTERFACE] [--vlan VID:NAME]... [--protocol PROTOCOL]... [--max-frame-size OCTETS] [--fa-isid ISID:VID]... [--tlv-layouts FILE]...";
 
-fn main() {
-    let interface_name = std::env::args().nth(1).unwrap_or_else(|| "eth0".into());
+/// FA element type announced when requesting I-SID/VLAN assignments (FA Client - Server/Endpoint)
+const FA_CLIENT_TYPE: u8 = 15;
+
+/// Register the vendor TLV layouts given with `--tlv-layouts`, so that they are decoded when received.
+fn load_layouts(args: &[String]) {
+    for option in args.chunks(2).filter(|option| option[0] == "--tlv-layouts") {
+        let path = option
+            .get(1)
+            .unwrap_or_else(|| panic!("Missing value for {}\n{}", option[0], USAGE));
+        let text = std::fs::read_to_string(path)
+            .unwrap_or_else(|err| panic!("Cannot read TLV layouts {}: {}", path, err));
+        let count = registry_mut()
+            .load_layouts(&text)
+            .unwrap_or_else(|err| panic!("Invalid TLV layouts {}: {}", path, err));
+        println!("Loaded {} TLV layout(s) from {}", count, path);
+    }
+}
+
+/// Parse the optional TLVs to announce from the command line options following the interface name.
+///
+/// Requested I-SID/VLAN assignments are announced as an FA client, identified by `mac_address`.
+fn optional_tlvs(args: &[String], mac_address: MacAddr) -> Vec<Box<dyn OrgTlvPayload>> {
+    let mut payloads: Vec<Box<dyn OrgTlvPayload>> = vec![];
+    let mut assignments: Vec<FaAssignment> = vec![];
+
+    for option in args.chunks(2) {
+        let value = option
+            .get(1)
+            .unwrap_or_else(|| panic!("Missing value for {}\n{}", option[0], USAGE));
+        if option[0] == "--tlv-layouts" {
+            continue;
+        }
+        if option[0] == "--fa-isid" {
+            assignments.push(
+                value
+                    .parse()
+                    .unwrap_or_else(|err| panic!("Invalid I-SID assignment {}: {}", value, err)),
+            );
+            continue;
+        }
+        let payload: Box<dyn OrgTlvPayload> = match option[0].as_str() {
+            "--vlan" => Box::new(
+                value
+                    .parse::<VlanName>()
+                    .unwrap_or_else(|err| panic!("Invalid VLAN {}: {}", value, err)),
+            ),
+            "--protocol" => Box::new(
+                value
+                    .parse::<ProtocolIdentity>()
+                    .unwrap_or_else(|err| panic!("Invalid protocol {}: {}", value, err)),
+            ),
+            "--max-frame-size" => {
+                Box::new(MaxFrameSize::new(value.parse().unwrap_or_else(|err| {
+                    panic!("Invalid frame size {}: {}", value, err)
+                })))
+            }
+            other => panic!("Unknown option {}\n{}", other, USAGE),
+        };
+        payloads.push(payload);
+    }
 
+    if !assignments.is_empty() {
+        let mut system_id = [0; 10];
+        system_id[..6].copy_from_slice(&mac_address.octets());
+        payloads.push(Box::new(
+            FaElement::try_new(FA_CLIENT_TYPE, 0, 0, system_id).unwrap(),
+        ));
+        payloads.push(Box::new(
+            FaAssignments::try_new(assignments)
+                .unwrap_or_else(|err| panic!("Invalid I-SID assignments: {}", err)),
+        ));
+    }
+
+    payloads
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (interface_name, options) = match args.first() {
+        Some(name) if !name.starts_with("--") => (name.clone(), &args[1..]),
+        _ => (String::from("eth0"), &args[..]),
+    };
+    load_layouts(options);
     let interface = datalink::interfaces()
         .into_iter()
         .find(|iface| iface.name == interface_name)
@@ -17,9 +103,20 @@ fn main() {
         .mac
         .unwrap_or_else(|| panic!("Interface {} does not have a MAC address", interface_name));
 
+    let payloads = optional_tlvs(options, mac_address);
+
     println!("Starting LLDP Agent on interface {}", interface_name);
 
     let mut agent = LLDPAgent::new(mac_address, interface_name, 1.0, None, None);
+    agent.set_log_format(LogFormat::Detailed);
+    for payload in payloads {
+        agent.add_optional_tlv(
+            Tlv::OrganizationallySpecific(OrganizationallySpecificTLV::from_payload(
+                payload.as_ref(),
+            )),
+            0,
+        );
+    }
 
     agent.run(false);
 }
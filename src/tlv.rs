@@ -4,6 +4,7 @@ use std::fmt::Display;
 pub mod chassisid_tlv;
 pub mod eolldpdu_tlv;
 pub mod managementaddress_tlv;
+pub mod manifest_tlv;
 pub mod organizationallyspecific_tlv;
 pub mod portdescription_tlv;
 pub mod portid_tlv;
@@ -11,6 +12,7 @@ pub mod systemcapabilities_tlv;
 pub mod systemdescription_tlv;
 pub mod systemname_tlv;
 pub mod ttl_tlv;
+pub mod xpdurequest_tlv;
 
 #[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
 pub enum TlvType {
@@ -23,6 +25,8 @@ pub enum TlvType {
     SystemDescription = 6,
     SystemCapabilities = 7,
     ManagementAddress = 8,
+    Manifest = 9,
+    XpduRequest = 10,
     OrganizationallySpecific = 127,
 }
 
@@ -40,6 +44,8 @@ impl TryFrom<u8> for TlvType {
             x if x == TlvType::SystemDescription as u8 => Ok(TlvType::SystemDescription),
             x if x == TlvType::SystemCapabilities as u8 => Ok(TlvType::SystemCapabilities),
             x if x == TlvType::ManagementAddress as u8 => Ok(TlvType::ManagementAddress),
+            x if x == TlvType::Manifest as u8 => Ok(TlvType::Manifest),
+            x if x == TlvType::XpduRequest as u8 => Ok(TlvType::XpduRequest),
             x if x == TlvType::OrganizationallySpecific as u8 => {
                 Ok(TlvType::OrganizationallySpecific)
             }
@@ -48,11 +54,99 @@ impl TryFrom<u8> for TlvType {
     }
 }
 
+/// Error returned by the validating `try_new` constructors of the TLVs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TlvError {
+    /// The value does not match the subtype, e.g. a MAC address subtype with a string value
+    SubtypeMismatch { tlv_type: TlvType, subtype: u8 },
+    /// The value is shorter or longer than the TLV allows, all lengths in octets
+    InvalidLength {
+        tlv_type: TlvType,
+        length: usize,
+        min: usize,
+        max: usize,
+    },
+    /// The OUI of an Organizationally Specific TLV is not exactly 3 octets long
+    InvalidOui(usize),
+    /// Capabilities are enabled without being supported
+    UnsupportedCapabilities { supported: u16, enabled: u16 },
+    /// The value is malformed or out of range, with a description of the problem
+    InvalidValue(String),
+}
+
+impl Display for TlvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TlvError::SubtypeMismatch { tlv_type, subtype } => {
+                write!(f, "{:?} value does not match subtype {}", tlv_type, subtype)
+            }
+            TlvError::InvalidLength {
+                tlv_type,
+                length,
+                min,
+                max,
+            } => write!(
+                f,
+                "{:?} value of {} octets, expected {} - {} octets",
+                tlv_type, length, min, max
+            ),
+            TlvError::InvalidOui(length) => {
+                write!(f, "OUI of {} octets, expected 3 octets", length)
+            }
+            TlvError::UnsupportedCapabilities { supported, enabled } => write!(
+                f,
+                "enabled capabilities {:#06x} not supported by {:#06x}",
+                enabled, supported
+            ),
+            TlvError::InvalidValue(description) => write!(f, "invalid value: {}", description),
+        }
+    }
+}
+
+impl std::error::Error for TlvError {}
+
+/// Check that a value of `length` octets lies within `min` and `max` (inclusive).
+pub(crate) fn check_length(
+    tlv_type: TlvType,
+    length: usize,
+    min: usize,
+    max: usize,
+) -> Result<(), TlvError> {
+    if length < min || length > max {
+        return Err(TlvError::InvalidLength {
+            tlv_type,
+            length,
+            min,
+            max,
+        });
+    }
+    Ok(())
+}
+
+/// Format a MAC address, or another identifier made up of octets, as colon separated lowercase hex octets.
+pub(crate) fn format_mac(octets: &[u8]) -> String {
+    let octets: Vec<String> = octets
+        .iter()
+        .map(|octet| format!("{:02x}", octet))
+        .collect();
+    octets.join(":")
+}
+
+/// Format octets as uppercase hex, e.g. `0A 1B` with the separator `" "`.
+pub(crate) fn format_hex(octets: &[u8], separator: &str) -> String {
+    let octets: Vec<String> = octets
+        .iter()
+        .map(|octet| format!("{:02X}", octet))
+        .collect();
+    octets.join(separator)
+}
+
 // create bare tlv class, this allows for calling default TLV::functions
 
 use crate::tlv::chassisid_tlv::ChassisIdTLV;
 use crate::tlv::eolldpdu_tlv::EndOfLLDPDUTLV;
 use crate::tlv::managementaddress_tlv::ManagementAddressTLV;
+use crate::tlv::manifest_tlv::ManifestTLV;
 use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
 use crate::tlv::portdescription_tlv::PortDescriptionTLV;
 use crate::tlv::portid_tlv::PortIdTLV;
@@ -60,6 +154,7 @@ use crate::tlv::systemcapabilities_tlv::SystemCapabilitiesTLV;
 use crate::tlv::systemdescription_tlv::SystemDescriptionTLV;
 use crate::tlv::systemname_tlv::SystemNameTLV;
 use crate::tlv::ttl_tlv::TtlTLV;
+use crate::tlv::xpdurequest_tlv::XpduRequestTLV;
 
 /// TLV Base class
 ///
@@ -78,6 +173,7 @@ pub enum Tlv {
     ChassisId(ChassisIdTLV),
     EndOfLldpdu(EndOfLLDPDUTLV),
     ManagementAddress(ManagementAddressTLV),
+    Manifest(ManifestTLV),
     OrganizationallySpecific(OrganizationallySpecificTLV),
     PortId(PortIdTLV),
     PortDescription(PortDescriptionTLV),
@@ -85,6 +181,7 @@ pub enum Tlv {
     SystemName(SystemNameTLV),
     SystemCapabilities(SystemCapabilitiesTLV),
     Ttl(TtlTLV),
+    XpduRequest(XpduRequestTLV),
 }
 
 impl Display for Tlv {
@@ -100,6 +197,7 @@ impl Display for Tlv {
             Tlv::ChassisId(tlv) => write!(f, "{}",tlv),
             Tlv::EndOfLldpdu(tlv) => write!(f, "{}",tlv),
             Tlv::ManagementAddress(tlv) => write!(f, "{}",tlv),
+            Tlv::Manifest(tlv) => write!(f, "{}", tlv),
             Tlv::OrganizationallySpecific(tlv) => write!(f, "{}",tlv),
             Tlv::PortId(tlv) => write!(f, "{}",tlv),
             Tlv::PortDescription(tlv) => write!(f, "{}",tlv),
@@ -107,6 +205,7 @@ impl Display for Tlv {
             Tlv::SystemName(tlv) => write!(f, "{}",tlv),
             Tlv::SystemCapabilities(tlv) => write!(f, "{}",tlv),
             Tlv::Ttl(tlv) => write!(f, "{}",tlv),
+            Tlv::XpduRequest(tlv) => write!(f, "{}", tlv),
         }
     }
 }
@@ -118,6 +217,7 @@ impl Tlv {
             Tlv::ChassisId(tlv) => tlv.tlv_type,
             Tlv::EndOfLldpdu(tlv) => tlv.tlv_type,
             Tlv::ManagementAddress(tlv) => tlv.tlv_type,
+            Tlv::Manifest(tlv) => tlv.tlv_type,
             Tlv::OrganizationallySpecific(tlv) => tlv.tlv_type,
             Tlv::PortId(tlv) => tlv.tlv_type,
             Tlv::PortDescription(tlv) => tlv.tlv_type,
@@ -125,6 +225,7 @@ impl Tlv {
             Tlv::SystemName(tlv) => tlv.tlv_type,
             Tlv::SystemCapabilities(tlv) => tlv.tlv_type,
             Tlv::Ttl(tlv) => tlv.tlv_type,
+            Tlv::XpduRequest(tlv) => tlv.tlv_type,
         }
     }
 
@@ -132,13 +233,15 @@ impl Tlv {
     ///
     /// Consider the following TLV:
     ///
-    ///      0                   1                   2                   3
-    ///      0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
-    ///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
-    ///     |             |                 |                               |
-    ///     |     0x3     |       0x2       |            0x003c             |
-    ///     |             |                 |                               |
-    ///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+    /// ```text
+    ///  0                   1                   2                   3
+    ///  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+    /// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+    /// |             |                 |                               |
+    /// |     0x3     |       0x2       |            0x003c             |
+    /// |             |                 |                               |
+    /// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+    /// ```
     ///
     /// When called on this TLV, this method should return `b"\x06\x02\x00\x3c".to_vec()`.
     pub fn bytes(&self) -> Vec<u8> {
@@ -147,6 +250,7 @@ impl Tlv {
             Tlv::ChassisId(tlv) => tlv.bytes(),
             Tlv::EndOfLldpdu(tlv) => tlv.bytes(),
             Tlv::ManagementAddress(tlv) => tlv.bytes(),
+            Tlv::Manifest(tlv) => tlv.bytes(),
             Tlv::OrganizationallySpecific(tlv) => tlv.bytes(),
             Tlv::PortId(tlv) => tlv.bytes(),
             Tlv::PortDescription(tlv) => tlv.bytes(),
@@ -154,6 +258,7 @@ impl Tlv {
             Tlv::SystemName(tlv) => tlv.bytes(),
             Tlv::SystemCapabilities(tlv) => tlv.bytes(),
             Tlv::Ttl(tlv) => tlv.bytes(),
+            Tlv::XpduRequest(tlv) => tlv.bytes(),
         }
     }
 
@@ -190,6 +295,8 @@ impl Tlv {
             TlvType::SystemDescription => Tlv::SystemDescription((SystemDescriptionTLV::new_from_bytes(bytes))),
             TlvType::SystemCapabilities => Tlv::SystemCapabilities((SystemCapabilitiesTLV::new_from_bytes(bytes))),
             TlvType::ManagementAddress => Tlv::ManagementAddress((ManagementAddressTLV::new_from_bytes(bytes))),
+            TlvType::Manifest => Tlv::Manifest(ManifestTLV::new_from_bytes(bytes)),
+            TlvType::XpduRequest => Tlv::XpduRequest(XpduRequestTLV::new_from_bytes(bytes)),
             TlvType::OrganizationallySpecific => Tlv::OrganizationallySpecific((OrganizationallySpecificTLV::new_from_bytes(bytes))),
         }
     }
@@ -1,4 +1,4 @@
-use crate::tlv::TlvType;
+use crate::tlv::{check_length, TlvError, TlvType};
 
 use std::convert::{TryFrom, TryInto};
 use std::fmt::Display;
@@ -53,15 +53,17 @@ pub enum ChassisIdValue {
 ///
 /// # TLV Format:
 ///
-///      0                   1                   2
-///      0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6
-///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+...+-+-+-+
-///     |             |                 |               |               |
-///     |      1      |      Length     |    Subtype    |   Chassis ID  |
-///     |             |                 |               |               |
-///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+...+-+-+-+
+/// ```text
+///  0                   1                   2
+///  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+...+-+-+-+
+/// |             |                 |               |               |
+/// |      1      |      Length     |    Subtype    |   Chassis ID  |
+/// |             |                 |               |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+...+-+-+-+
 ///
-///                                                        1 - 255 byte
+///                                                    1 - 255 byte
+/// ```
 ///
 /// # Subtypes:
 ///
@@ -107,11 +109,13 @@ pub enum ChassisIdValue {
 /// |   IPv4   |             1 |
 /// |   IPv6   |             2 |
 ///
-///     Examples (Address -> Bytes -> Prefixed Bytes):
-///         134.96.86.110  ->  b"\x86\x60\x56\x6E"  -> b"\x01\x86\x60\x56\x6E"
+/// ```text
+/// Examples (Address -> Bytes -> Prefixed Bytes):
+///     134.96.86.110  ->  b"\x86\x60\x56\x6E"  -> b"\x01\x86\x60\x56\x6E"
 ///
-///         20db::1        ->  b"\x20\xdb\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01"
-///                        ->  b"\x02\x20\xdb\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01"
+///     20db::1        ->  b"\x20\xdb\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01"
+///                    ->  b"\x02\x20\xdb\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01"
+/// ```
 ///
 ///  The full list of registered protocol families is available at:
 ///  <https://www.iana.org/assignments/address-family-numbers/address-family-numbers.xhtml>
@@ -173,6 +177,34 @@ impl ChassisIdTLV {
         }
     }
 
+    /// Validating constructor
+    ///
+    /// Like [ChassisIdTLV::new], but checks that `id` matches the subtype, that MAC addresses are 6 octets long and
+    /// that other IDs are 1 - 255 octets long.
+    pub fn try_new(
+        subtype: ChassisIdSubType,
+        id: ChassisIdValue,
+    ) -> Result<ChassisIdTLV, TlvError> {
+        match (&subtype, &id) {
+            (ChassisIdSubType::MacAddress, ChassisIdValue::Mac(mac)) => {
+                check_length(TlvType::ChassisId, mac.len(), 6, 6)?
+            }
+            (ChassisIdSubType::NetworkAddress, ChassisIdValue::IpAddress(_)) => (),
+            (ChassisIdSubType::MacAddress, _)
+            | (ChassisIdSubType::NetworkAddress, _)
+            | (_, ChassisIdValue::Mac(_))
+            | (_, ChassisIdValue::IpAddress(_)) => {
+                return Err(TlvError::SubtypeMismatch {
+                    tlv_type: TlvType::ChassisId,
+                    subtype: subtype.clone() as u8,
+                })
+            }
+            (_, ChassisIdValue::Other(s)) => check_length(TlvType::ChassisId, s.len(), 1, 255)?,
+        }
+
+        Ok(ChassisIdTLV::new(subtype, id))
+    }
+
     /// Create a TLV instance from raw bytes.
     ///
     /// Panics if the provided TLV contains errors (e.g. has the wrong type).
@@ -239,6 +271,11 @@ impl ChassisIdTLV {
         }
     }
 
+    /// Check whether the TLV value is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Return the byte representation of the TLV.
     pub fn bytes(&self) -> Vec<u8> {
         let mut type_field = (self.tlv_type as u8) << 1;
@@ -524,4 +561,72 @@ mod tests {
 
         assert_eq!(format!("{}", tlv), "ChassisIdTLV(5, \"127.0.0.1\")")
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_try_new() {
+        assert!(ChassisIdTLV::try_new(
+            ChassisIdSubType::MacAddress,
+            ChassisIdValue::Mac(b"\x02\x04\xdf\x88\xa2\xb4".to_vec())
+        )
+        .is_ok());
+        assert!(ChassisIdTLV::try_new(
+            ChassisIdSubType::NetworkAddress,
+            ChassisIdValue::IpAddress("192.0.2.1".parse().unwrap())
+        )
+        .is_ok());
+        assert_eq!(
+            ChassisIdTLV::try_new(
+                ChassisIdSubType::MacAddress,
+                ChassisIdValue::Other(String::from("foobar"))
+            )
+            .unwrap_err(),
+            TlvError::SubtypeMismatch {
+                tlv_type: TlvType::ChassisId,
+                subtype: 4
+            }
+        );
+        assert_eq!(
+            ChassisIdTLV::try_new(
+                ChassisIdSubType::Local,
+                ChassisIdValue::IpAddress("192.0.2.1".parse().unwrap())
+            )
+            .unwrap_err(),
+            TlvError::SubtypeMismatch {
+                tlv_type: TlvType::ChassisId,
+                subtype: 7
+            }
+        );
+        assert_eq!(
+            ChassisIdTLV::try_new(
+                ChassisIdSubType::MacAddress,
+                ChassisIdValue::Mac(b"\x02\x04\xdf\x88\xa2".to_vec())
+            )
+            .unwrap_err(),
+            TlvError::InvalidLength {
+                tlv_type: TlvType::ChassisId,
+                length: 5,
+                min: 6,
+                max: 6
+            }
+        );
+    }
+
+    #[test]
+    fn test_try_new_length() {
+        assert!(ChassisIdTLV::try_new(
+            ChassisIdSubType::Local,
+            ChassisIdValue::Other("x".repeat(255))
+        )
+        .is_ok());
+        assert!(ChassisIdTLV::try_new(
+            ChassisIdSubType::Local,
+            ChassisIdValue::Other("x".repeat(256))
+        )
+        .is_err());
+        assert!(ChassisIdTLV::try_new(
+            ChassisIdSubType::Local,
+            ChassisIdValue::Other(String::new())
+        )
+        .is_err());
+    }
+}
@@ -9,13 +9,15 @@ use crate::tlv::TlvType;
 ///
 /// # TLV Format:
 ///
-///      0                   1
-///      0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5
-///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
-///     |             |                 |
-///     |      0      |       0x0       |
-///     |             |                 |
-///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```text
+///  0                   1
+///  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |             |                 |
+/// |      0      |       0x0       |
+/// |             |                 |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
 #[derive(Debug, Clone)]
 pub struct EndOfLLDPDUTLV {
     /// The type of the TLV
@@ -30,6 +32,12 @@ impl Display for EndOfLLDPDUTLV {
     }
 }
 
+impl Default for EndOfLLDPDUTLV {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl EndOfLLDPDUTLV {
     /// Constructor
     pub fn new() -> EndOfLLDPDUTLV {
@@ -69,6 +77,11 @@ impl EndOfLLDPDUTLV {
         0
     }
 
+    /// Check whether the TLV value is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Return the byte representation of the TLV.
     pub fn bytes(&self) -> Vec<u8> {
         // TODO: Implement
@@ -1,6 +1,5 @@
-use crate::tlv::TlvType;
+use crate::tlv::{check_length, TlvError, TlvType};
 
-use bytes::{Buf, BufMut};
 use std::convert::{TryFrom, TryInto};
 use std::fmt::Display;
 use std::net::IpAddr;
@@ -35,19 +34,21 @@ impl TryFrom<u8> for IFNumberingSubtype {
 ///
 /// # TLV Format:
 ///
-///       0               1               2               3               4
-///      +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+~
-///      |             |                 |  Management   |  Management   |   Management    |
-///      |     0x1     |      Length     |    Address    |    Address    |     Address     |
-///      |             |                 | String Length |    Subtype    | (m=1-31 octets) |
-///      +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+~
+/// ```text
+///   0               1               2               3               4
+///  +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+~
+///  |             |                 |  Management   |  Management   |   Management    |
+///  |     0x1     |      Length     |    Address    |    Address    |     Address     |
+///  |             |                 | String Length |    Subtype    | (m=1-31 octets) |
+///  +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+~
 ///
-///       5+m             6+m              10+m           11+m
-///     ~+-+-+-+-+-+-+-+-+-+-+-+...+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+.....+-+-+-+-+-+-+-+
-///      |   Interface   |   Interface   |  OID String   |        Object identifier        |
-///      |   Numbering   |    Number     |    Length     |         (0-128 octets)          |
-///      |    Subtype    |   (4 octets)  |   (1 octet)   |                                 |
-///     ~+-+-+-+-+-+-+-+-+-+-+-+...+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+.....+-+-+-+-+-+-+-+
+///   5+m             6+m              10+m           11+m
+/// ~+-+-+-+-+-+-+-+-+-+-+-+...+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+.....+-+-+-+-+-+-+-+
+///  |   Interface   |   Interface   |  OID String   |        Object identifier        |
+///  |   Numbering   |    Number     |    Length     |         (0-128 octets)          |
+///  |    Subtype    |   (4 octets)  |   (1 octet)   |                                 |
+/// ~+-+-+-+-+-+-+-+-+-+-+-+...+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+.....+-+-+-+-+-+-+-+
+/// ```
 ///
 /// # Management Address Subtype and Management Address String Length:
 ///
@@ -95,10 +96,12 @@ impl TryFrom<u8> for IFNumberingSubtype {
 ///
 /// Example:
 ///
-///     let tlv = ManagementAddressTLV::new( "192.2.0.1".parse().unwrap(), 4, IFNumberingSubtype::IF_INDEX, b"\x00\x08\x15".to_vec());
-///     println!("{:?}", tlv.oid);
-///     // Should print:
-///     [0, 8, 21]
+/// ```text
+/// let tlv = ManagementAddressTLV::new( "192.2.0.1".parse().unwrap(), 4, IFNumberingSubtype::IF_INDEX, b"\x00\x08\x15".to_vec());
+/// println!("{:?}", tlv.oid);
+/// // Should print:
+/// [0, 8, 21]
+/// ```
 #[derive(Debug, Clone)]
 pub struct ManagementAddressTLV {
     /// The type of the TLV
@@ -146,6 +149,24 @@ impl ManagementAddressTLV {
         }
     }
 
+    /// Validating constructor
+    ///
+    /// Like [ManagementAddressTLV::new], but checks that the OID is at most 128 octets long.
+    pub fn try_new(
+        address: IpAddr,
+        interface_number: u32,
+        ifsubtype: IFNumberingSubtype,
+        oid: Vec<u8>,
+    ) -> Result<ManagementAddressTLV, TlvError> {
+        check_length(TlvType::ManagementAddress, oid.len(), 0, 128)?;
+        Ok(ManagementAddressTLV::new(
+            address,
+            interface_number,
+            ifsubtype,
+            oid,
+        ))
+    }
+
     /// Create a TLV instance from raw bytes.
     ///
     /// Panics if the provided TLV contains errors (e.g. has the wrong type).
@@ -232,6 +253,11 @@ impl ManagementAddressTLV {
 
     }
 
+    /// Check whether the TLV value is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Return the byte representation of the TLV.
     pub fn bytes(&self) -> Vec<u8> {
         // TODO: Implement
@@ -283,6 +309,7 @@ impl ManagementAddressTLV {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bytes::BufMut;
     use std::net::{Ipv4Addr, Ipv6Addr};
 
     fn set_up() -> (ManagementAddressTLV, ManagementAddressTLV) {
@@ -489,4 +516,23 @@ mod tests {
             "ManagementAddressTLV(\"2001:db::4\", 5, \"2B0601040182371514\")"
         )
     }
+
+    #[test]
+    fn test_try_new() {
+        let address: IpAddr = "192.0.2.1".parse().unwrap();
+        assert!(ManagementAddressTLV::try_new(
+            address,
+            4,
+            IFNumberingSubtype::IfIndex,
+            vec![0; 128]
+        )
+        .is_ok());
+        assert!(ManagementAddressTLV::try_new(
+            address,
+            4,
+            IFNumberingSubtype::IfIndex,
+            vec![0; 129]
+        )
+        .is_err());
+    }
 }
@@ -0,0 +1,200 @@
+use std::fmt::Display;
+
+use crate::tlv::{check_length, TlvError, TlvType};
+
+/// Entry of a Manifest TLV describing a single XPDU
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XpduDescriptor {
+    /// The XPDU number, unique per LLDP agent
+    pub number: u8,
+    /// Check value of the XPDU contents, changes whenever the contents change
+    pub check: u32,
+}
+
+/// Manifest TLV
+///
+/// IEEE 802.1AB-2016 allows an LLDP agent to advertise more information than fits into a single LLDPDU. The
+/// additional TLVs are carried in extension LLDPDUs (XPDUs), which a receiver fetches on request.
+///
+/// The Manifest TLV lists the XPDUs an agent currently offers. Its check values allow a receiver to only request
+/// XPDUs it has not seen yet or whose contents changed. The check values are opaque to the receiver, see
+/// [check_value](crate::lldpdu::xpdu::check_value) for how this crate computes them.
+///
+/// The Manifest TLV is sent in the regular LLDPDU. Each XPDU carries a Manifest TLV describing only itself.
+///
+/// It is an optional TLV and as such may be included in an LLDPDU between the TTL TLV and the End of LLDPDU TLV.
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               3
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+
+/// |             |                 |     XPDU      |         Check Value         |
+/// |      9      |      Length     |    Number     |         (4 octets)          |  ...
+/// |             |                 |               |                             |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+
+///
+///                                 |<------ XPDU descriptor, 0 - 102 times ----->|
+/// ```
+#[derive(Debug, Clone)]
+pub struct ManifestTLV {
+    /// The type of the TLV
+    pub tlv_type: TlvType,
+    /// The XPDUs offered by the sender
+    pub value: Vec<XpduDescriptor>,
+}
+
+impl Display for ManifestTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let descriptors: Vec<String> = self
+            .value
+            .iter()
+            .map(|descriptor| format!("{}: {:08X}", descriptor.number, descriptor.check))
+            .collect();
+
+        write!(f, "ManifestTLV({})", descriptors.join(", "))
+    }
+}
+
+impl ManifestTLV {
+    /// Constructor
+    pub fn new(descriptors: Vec<XpduDescriptor>) -> ManifestTLV {
+        ManifestTLV {
+            tlv_type: TlvType::Manifest,
+            value: descriptors,
+        }
+    }
+
+    /// Validating constructor
+    ///
+    /// Like [ManifestTLV::new], but checks that there are at most 102 descriptors.
+    pub fn try_new(descriptors: Vec<XpduDescriptor>) -> Result<ManifestTLV, TlvError> {
+        check_length(TlvType::Manifest, 5 * descriptors.len(), 0, 510)?;
+        Ok(ManifestTLV::new(descriptors))
+    }
+
+    /// Create a TLV instance from raw bytes.
+    ///
+    /// Panics if the provided TLV contains errors (e.g. has the wrong type).
+    pub fn new_from_bytes(bytes: &[u8]) -> ManifestTLV {
+        let type_field = bytes[0] >> 1;
+        if type_field != TlvType::Manifest as u8 {
+            panic!("Wrong TLV Type for Manifest_Tlv");
+        }
+
+        let length = ((bytes[0] as usize & 1) << 8) | bytes[1] as usize;
+        assert_eq!(length, bytes[2..].len(), "Manifest TLV length mismatch");
+        assert_eq!(length % 5, 0, "Manifest TLV length must be a multiple of 5");
+
+        let descriptors = bytes[2..]
+            .chunks(5)
+            .map(|chunk| XpduDescriptor {
+                number: chunk[0],
+                check: u32::from_be_bytes([chunk[1], chunk[2], chunk[3], chunk[4]]),
+            })
+            .collect();
+
+        ManifestTLV::new(descriptors)
+    }
+
+    /// Return the length of the TLV value
+    pub fn len(&self) -> usize {
+        5 * self.value.len()
+    }
+
+    /// Check whether the TLV value is empty
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    /// Return the byte representation of the TLV.
+    pub fn bytes(&self) -> Vec<u8> {
+        let length = self.len();
+
+        let mut result = vec![
+            ((self.tlv_type as u8) << 1) | ((length >> 8) as u8 & 1),
+            (length & 0xFF) as u8,
+        ];
+
+        for descriptor in &self.value {
+            result.push(descriptor.number);
+            result.extend_from_slice(&descriptor.check.to_be_bytes());
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set_up() -> ManifestTLV {
+        ManifestTLV::new(vec![
+            XpduDescriptor {
+                number: 1,
+                check: 0xDEADBEEF,
+            },
+            XpduDescriptor {
+                number: 2,
+                check: 0x00C0FFEE,
+            },
+        ])
+    }
+
+    #[test]
+    fn test_type() {
+        let tlv = set_up();
+        assert_eq!(tlv.tlv_type as u8, 9);
+    }
+
+    #[test]
+    fn test_length() {
+        let tlv = set_up();
+        assert_eq!(tlv.len(), 10);
+    }
+
+    #[test]
+    fn test_dump() {
+        let tlv = set_up();
+        assert_eq!(
+            tlv.bytes(),
+            b"\x12\x0a\x01\xde\xad\xbe\xef\x02\x00\xc0\xff\xee".to_vec()
+        );
+    }
+
+    #[test]
+    fn test_load() {
+        let tlv = ManifestTLV::new_from_bytes(b"\x12\x05\x07\x01\x02\x03\x04".as_ref());
+        assert_eq!(
+            tlv.value,
+            vec![XpduDescriptor {
+                number: 7,
+                check: 0x01020304
+            }]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_load_invalid_length() {
+        ManifestTLV::new_from_bytes(b"\x12\x04\x07\x01\x02\x03".as_ref());
+    }
+
+    #[test]
+    fn test_display() {
+        let tlv = set_up();
+        assert_eq!(format!("{}", tlv), "ManifestTLV(1: DEADBEEF, 2: 00C0FFEE)");
+    }
+
+    #[test]
+    fn test_try_new() {
+        let descriptor = XpduDescriptor {
+            number: 1,
+            check: 0,
+        };
+        assert!(ManifestTLV::try_new(vec![descriptor; 102]).is_ok());
+        assert!(ManifestTLV::try_new(vec![descriptor; 103]).is_err());
+    }
+}
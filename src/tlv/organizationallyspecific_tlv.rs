@@ -1,7 +1,20 @@
+use std::convert::TryInto;
 use std::fmt::Display;
 
-use crate::tlv::TlvType;
-use bytes::BufMut;
+use crate::json::Json;
+use crate::tlv::{check_length, format_hex, TlvError, TlvType};
+
+pub mod cee;
+pub mod cisco;
+pub mod fabric_attach;
+pub mod ieee8021;
+pub mod ieee8023;
+pub mod layout;
+pub mod med;
+pub mod profinet;
+pub mod registry;
+
+use registry::{format_oui, registry, OrgTlvDecode, OrgTlvPayload, OrgTlvRegistry};
 
 /// Organizationally Specific TLV
 ///
@@ -13,18 +26,26 @@ use bytes::BufMut;
 ///
 /// # TLV Format:
 ///
-///      0               1               2               5               6
-///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-|
-///     |             |                 |    Organiz.   |    Organiz.   |   Organizationally  |
-///     |     127     |      Length     |   Unique ID   |    Defined    | Defined Information |
-///     |             |                 |     (OUI)     |    Subtype    |       (Value)       |
-///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-|
+/// ```text
+///  0               1               2               5               6
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-|
+/// |             |                 |    Organiz.   |    Organiz.   |   Organizationally  |
+/// |     127     |      Length     |   Unique ID   |    Defined    | Defined Information |
+/// |             |                 |     (OUI)     |    Subtype    |       (Value)       |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-|
 ///
-///                                                                          0 - 507 byte
+///                                                                      0 - 507 byte
+/// ```
 ///
 /// The OUI is a 24 bit number uniquely identifying a vendor, manufacturer or organization.
 ///
 /// The subtype should be a unique subtype value assigned by the defining organization.
+///
+/// # Decoding:
+///
+/// The value of TLVs with a known (OUI, subtype) pair can be decoded into a typed representation, see
+/// [OrganizationallySpecificTLV::decoded]. Decoders are looked up in the process-wide [registry](registry::registry),
+/// to which other crates may add their own vendor TLVs. Values of unknown pairs are kept as raw bytes.
 #[derive(Debug, Clone)]
 pub struct OrganizationallySpecificTLV {
     /// The type of the TLV
@@ -40,16 +61,12 @@ pub struct OrganizationallySpecificTLV {
 impl Display for OrganizationallySpecificTLV {
     /// Write a printable representation of the TLV object.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // TODO: Implement
         let mut oui = String::new();
         for i in &self.oui {
-            oui.push_str(&format!("{:X}", i));
+            oui.push_str(&format!("{:02X}", i));
         }
 
-        let mut value = String::new();
-        for i in &self.value {
-            value.push_str(&format!("{:X}", i));
-        }
+        let value = self.value_string(&registry());
         write!(f,"OrganizationallySpecificTLV(\"{}\", {}, \"{}\")",oui,self.subtype,value)
     }
 }
@@ -66,6 +83,27 @@ impl OrganizationallySpecificTLV {
         }
     }
 
+    /// Create a TLV carrying the encoded `payload`.
+    pub fn from_payload(payload: &dyn OrgTlvPayload) -> OrganizationallySpecificTLV {
+        OrganizationallySpecificTLV::new(payload.oui().to_vec(), payload.subtype(), payload.value())
+    }
+
+    /// Validating constructor
+    ///
+    /// Like [OrganizationallySpecificTLV::new], but checks that the OUI is 3 octets and the value at most 507 octets
+    /// long.
+    pub fn try_new(
+        oui: Vec<u8>,
+        subtype: u8,
+        value: Vec<u8>,
+    ) -> Result<OrganizationallySpecificTLV, TlvError> {
+        if oui.len() != 3 {
+            return Err(TlvError::InvalidOui(oui.len()));
+        }
+        check_length(TlvType::OrganizationallySpecific, value.len(), 0, 507)?;
+        Ok(OrganizationallySpecificTLV::new(oui, subtype, value))
+    }
+
     /// Create a TLV instance from raw bytes.
     ///
     /// Panics if the provided TLV contains errors (e.g. has the wrong type).
@@ -98,6 +136,86 @@ impl OrganizationallySpecificTLV {
         OrganizationallySpecificTLV::new(org_uni_id_vec, org_def_subtype, org_def_info)
     }
 
+    /// Decode the value using the decoder registered for the TLV's OUI and subtype.
+    ///
+    /// Returns `None` if no decoder is registered and an error if the value is malformed.
+    pub fn decode(&self) -> Option<Result<Box<dyn OrgTlvPayload>, TlvError>> {
+        self.decode_with(&registry())
+    }
+
+    /// Like [OrganizationallySpecificTLV::decode], but look up the decoder in `registry`.
+    fn decode_with(
+        &self,
+        registry: &OrgTlvRegistry,
+    ) -> Option<Result<Box<dyn OrgTlvPayload>, TlvError>> {
+        let oui: [u8; 3] = self.oui.as_slice().try_into().ok()?;
+        registry.decode(oui, self.subtype, &self.value)
+    }
+
+    /// Get the value as written by `Display`: the name and decoded value, or uppercase hex if it can't be decoded
+    /// using `registry`.
+    fn value_string(&self, registry: &OrgTlvRegistry) -> String {
+        match self.decode_with(registry).and_then(Result::ok) {
+            Some(payload) => format!("{}: {}", payload.name(), payload),
+            None => format_hex(&self.value, ""),
+        }
+    }
+
+    /// Get the decoded value, `None` if the OUI and subtype are unknown or the value is malformed.
+    pub fn decoded(&self) -> Option<Box<dyn OrgTlvPayload>> {
+        self.decode().and_then(Result::ok)
+    }
+
+    /// Decode the value as `T`, `None` if the TLV's OUI and subtype do not belong to `T` or the value is malformed.
+    ///
+    /// Unlike [OrganizationallySpecificTLV::decoded] this does not require `T` to be registered.
+    pub fn decode_as<T: OrgTlvDecode>(&self) -> Option<T> {
+        let oui: [u8; 3] = self.oui.as_slice().try_into().ok()?;
+        let key = (oui, self.subtype);
+        if !T::KEYS.contains(&key) {
+            return None;
+        }
+        T::from_value(key, &self.value).ok()
+    }
+
+    /// Get the name of the organization owning the OUI, if known.
+    pub fn organization(&self) -> Option<String> {
+        self.organization_with(&registry())
+    }
+
+    fn organization_with(&self, registry: &OrgTlvRegistry) -> Option<String> {
+        let oui: [u8; 3] = self.oui.as_slice().try_into().ok()?;
+        registry.organization(oui).map(String::from)
+    }
+
+    /// Get a machine readable representation of the TLV.
+    ///
+    /// The raw value is always included. Decoded values are included as `value`, decoding errors as `error`.
+    pub fn to_json(&self) -> Json {
+        self.to_json_with(&registry())
+    }
+
+    /// Like [OrganizationallySpecificTLV::to_json], but look up the decoder and organization in `registry`.
+    fn to_json_with(&self, registry: &OrgTlvRegistry) -> Json {
+        let mut members = vec![
+            ("oui", Json::from(format_oui(&self.oui))),
+            ("subtype", self.subtype.into()),
+            ("organization", self.organization_with(registry).into()),
+        ];
+
+        match self.decode_with(registry) {
+            Some(Ok(payload)) => {
+                members.push(("name", payload.name().into()));
+                members.push(("value", payload.to_json()));
+            }
+            Some(Err(err)) => members.push(("error", err.to_string().into())),
+            None => (),
+        }
+
+        members.push(("raw", Json::hex(&self.value)));
+        Json::object(members)
+    }
+
     /// Return the length of the TLV value
     pub fn len(&self) -> usize {
         // TODO: Implement
@@ -110,6 +228,11 @@ impl OrganizationallySpecificTLV {
         total_len
     }
 
+    /// Check whether the TLV value is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Return the byte representation of the TLV.
     pub fn bytes(&self) -> Vec<u8> {
         // TODO: Implement
@@ -144,6 +267,7 @@ impl OrganizationallySpecificTLV {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bytes::BufMut;
 
     fn set_up() -> (OrganizationallySpecificTLV, Vec<u8>, u8, String) {
         let oui = b"\xAA\xBB\xCC".to_vec();
@@ -208,4 +332,76 @@ mod tests {
             "OrganizationallySpecificTLV(\"AABBCC\", 5, \"4855525A21\")"
         );
     }
+
+    #[test]
+    fn test_try_new() {
+        assert!(
+            OrganizationallySpecificTLV::try_new(vec![0xAA, 0xBB, 0xCC], 5, vec![0; 507]).is_ok()
+        );
+        assert_eq!(
+            OrganizationallySpecificTLV::try_new(vec![0xAA, 0xBB, 0xCC, 0xDD], 5, vec![])
+                .unwrap_err(),
+            TlvError::InvalidOui(4)
+        );
+        assert_eq!(
+            OrganizationallySpecificTLV::try_new(vec![0xAA, 0xBB, 0xCC], 5, vec![0; 508])
+                .unwrap_err(),
+            TlvError::InvalidLength {
+                tlv_type: TlvType::OrganizationallySpecific,
+                length: 508,
+                min: 0,
+                max: 507
+            }
+        );
+    }
+
+    #[test]
+    fn test_display_padded() {
+        let tlv =
+            OrganizationallySpecificTLV::new(b"\x00\x80\xc2".to_vec(), 99, b"\x00\x0a".to_vec());
+        assert_eq!(
+            format!("{}", tlv),
+            "OrganizationallySpecificTLV(\"0080C2\", 99, \"000A\")"
+        );
+    }
+
+    #[test]
+    fn test_registered_decoder() {
+        use registry::tests::Answer;
+
+        let mut registry = OrgTlvRegistry::new();
+        registry.register_type::<Answer>();
+
+        let tlv = OrganizationallySpecificTLV::from_payload(&Answer(42));
+        assert_eq!(tlv.bytes(), b"\xfe\x05\xac\xde\x48\x2a\x2a".to_vec());
+        assert_eq!(tlv.decode_as::<Answer>(), Some(Answer(42)));
+        let payload = tlv.decode_with(&registry).unwrap().unwrap();
+        assert_eq!(format!("{}", payload), "42");
+        assert_eq!(tlv.value_string(&registry), "Answer: 42");
+        assert_eq!(
+            format!("{}", tlv.to_json_with(&registry)),
+            "{\"oui\":\"AC-DE-48\",\"subtype\":42,\"organization\":null,\"name\":\"Answer\",\"value\":{\"answer\":42},\"raw\":\"2a\"}"
+        );
+
+        let malformed = OrganizationallySpecificTLV::new(b"\xac\xde\x48".to_vec(), 42, vec![]);
+        assert!(malformed.decode_with(&registry).unwrap().is_err());
+        assert!(malformed.to_json_with(&registry).get("error").is_some());
+
+        // The process-wide registry is left alone
+        assert!(tlv.decoded().is_none());
+        assert_eq!(
+            format!("{}", tlv),
+            "OrganizationallySpecificTLV(\"ACDE48\", 42, \"2A\")"
+        );
+    }
+
+    #[test]
+    fn test_unknown_json() {
+        let (tlv, _, _, _) = set_up();
+        assert_eq!(
+            format!("{}", tlv.to_json()),
+            "{\"oui\":\"AA-BB-CC\",\"subtype\":5,\"organization\":null,\"raw\":\"4855525a21\"}"
+        );
+        assert!(tlv.decode_as::<registry::tests::Answer>().is_none());
+    }
 }
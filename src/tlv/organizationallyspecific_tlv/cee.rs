@@ -0,0 +1,655 @@
+//! Pre-standard CEE DCBX TLV (OUI 00-1B-21)
+//!
+//! The Converged Enhanced Ethernet version of DCBX (DCBX 1.01) predates IEEE 802.1Qaz. It carries the whole DCB
+//! configuration in a single Organizationally Specific TLV made up of nested sub-TLVs.
+//!
+//! The agent negotiates with CEE peers, see [Dcbx](crate::agent::dcbx::Dcbx).
+
+use crate::json::Json;
+use crate::tlv::organizationallyspecific_tlv::registry::{
+    OrgTlvDecode, OrgTlvPayload, OrgTlvRegistry,
+};
+use crate::tlv::TlvError;
+
+use std::any::Any;
+use std::fmt::Display;
+
+/// OUI used by CEE DCBX
+pub const CEE_OUI: [u8; 3] = [0x00, 0x1B, 0x21];
+
+/// Subtype of the CEE DCBX TLV
+pub const CEE_SUBTYPE: u8 = 2;
+
+/// PGID of priorities which are not subject to bandwidth limits (strict priority)
+pub const STRICT_PGID: u8 = 15;
+
+/// Register the CEE DCBX TLV.
+pub(crate) fn register(registry: &mut OrgTlvRegistry) {
+    registry.register_organization(CEE_OUI, "Intel");
+    registry.register_type::<CeeDcbx>();
+}
+
+/// Join the values with spaces.
+fn join<T: Display>(values: &[T]) -> String {
+    let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+    values.join(" ")
+}
+
+/// Control sub-TLV (type 1)
+///
+/// ```text
+///  0               2               3               4               8               12
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-...-+-+-+-+
+/// |  Type  |  Length  |     Oper      |      Max      |  Sequence     |  Acknowledge  |
+/// |   1    |    10    |    Version    |    Version    |   Number      |    Number     |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-...-+-+-+-+
+/// ```
+///
+/// The sequence number is incremented whenever the sender's configuration changes, the acknowledge number echoes the
+/// last sequence number received from the peer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CeeControl {
+    /// Operational protocol version
+    pub oper_version: u8,
+    /// Highest protocol version supported
+    pub max_version: u8,
+    /// Sequence number of the sender's configuration
+    pub seq_no: u32,
+    /// Last sequence number received from the peer
+    pub ack_no: u32,
+}
+
+impl CeeControl {
+    /// Constructor
+    pub fn new(oper_version: u8, max_version: u8, seq_no: u32, ack_no: u32) -> CeeControl {
+        CeeControl {
+            oper_version,
+            max_version,
+            seq_no,
+            ack_no,
+        }
+    }
+}
+
+impl Display for CeeControl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "control v{}/{} seq {} ack {}",
+            self.oper_version, self.max_version, self.seq_no, self.ack_no
+        )
+    }
+}
+
+/// Header shared by the feature sub-TLVs
+///
+/// ```text
+///  0               2               3               4               5               6
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...
+/// |  Type  |  Length  |     Oper      |      Max      |E|W|E|Reserved |   Subtype     |  Feature data
+/// |        |          |    Version    |    Version    |n|i|r|         |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CeeFeatureHeader {
+    /// Operational feature version
+    pub oper_version: u8,
+    /// Highest feature version supported
+    pub max_version: u8,
+    /// Whether the feature is enabled
+    pub enabled: bool,
+    /// Whether the sender accepts the configuration of its peer
+    pub willing: bool,
+    /// Whether the sender failed to configure the feature
+    pub error: bool,
+    /// Feature subtype, 0 for all features defined so far
+    pub subtype: u8,
+}
+
+impl CeeFeatureHeader {
+    /// Constructor for version 0 features
+    pub fn new(enabled: bool, willing: bool, error: bool) -> CeeFeatureHeader {
+        CeeFeatureHeader {
+            oper_version: 0,
+            max_version: 0,
+            enabled,
+            willing,
+            error,
+            subtype: 0,
+        }
+    }
+
+    fn from_bytes(bytes: &[u8]) -> CeeFeatureHeader {
+        CeeFeatureHeader {
+            oper_version: bytes[0],
+            max_version: bytes[1],
+            enabled: bytes[2] & 0x80 != 0,
+            willing: bytes[2] & 0x40 != 0,
+            error: bytes[2] & 0x20 != 0,
+            subtype: bytes[3],
+        }
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        let flags = (self.enabled as u8) << 7 | (self.willing as u8) << 6 | (self.error as u8) << 5;
+        vec![self.oper_version, self.max_version, flags, self.subtype]
+    }
+}
+
+impl Display for CeeFeatureHeader {
+    /// Write the version and set flags, e.g. `v0 enabled willing`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "v{}", self.oper_version)?;
+        for (flag, name) in [
+            (self.enabled, "enabled"),
+            (self.willing, "willing"),
+            (self.error, "error"),
+        ] {
+            if flag {
+                write!(f, " {}", name)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Priority Group feature data (sub-TLV type 2)
+///
+/// ```text
+///  0               4               12              13
+/// +-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+
+/// |     PGID      |      PG       |    Num TCs    |
+/// |  by priority  |  Percentage   |   Supported   |
+/// +-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+
+/// ```
+///
+/// The PGIDs are packed in four bits, starting with priority 0 in the upper bits of the first octet. A PGID of
+/// [STRICT_PGID] puts the priority into the strict priority group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CeePriorityGroups {
+    /// Priority group, by priority
+    pub pgid: [u8; 8],
+    /// Bandwidth share in percent, by priority group
+    pub percentage: [u8; 8],
+    /// Number of traffic classes supported
+    pub num_tcs: u8,
+}
+
+/// PFC feature data (sub-TLV type 3)
+///
+/// ```text
+///  0               1               2
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |  PFC Enable   |    Num TCs    |
+/// |    Bitmap     |   Supported   |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CeePfc {
+    /// Priorities PFC is enabled on, by bit
+    pub enabled: u8,
+    /// Number of traffic classes supported
+    pub num_tcs: u8,
+}
+
+/// Entry of the Application feature data (sub-TLV type 4)
+///
+/// ```text
+///  0               2               3               5               6
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |  Protocol ID  |OUI (upper)|Sel|   OUI (lower) |  Priority Map |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// A selector of 0 identifies the protocol by Ethertype, a selector of 1 by TCP/UDP port.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CeeAppEntry {
+    /// Ethertype or port of the application
+    pub protocol: u16,
+    /// Selector (0 - 3)
+    pub selector: u8,
+    /// OUI, of which only the lower six bits of the first octet are carried
+    pub oui: [u8; 3],
+    /// Priorities the application may use, by bit
+    pub priority_map: u8,
+}
+
+impl Display for CeeAppEntry {
+    /// Write the entry, e.g. `ethertype 0x8906 -> [3]`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let priorities: Vec<u8> = (0..8)
+            .filter(|bit| self.priority_map & (1 << bit) != 0)
+            .collect();
+        match self.selector {
+            0 => write!(f, "ethertype {:#06x}", self.protocol)?,
+            1 => write!(f, "port {}", self.protocol)?,
+            selector => write!(f, "selector-{} {}", selector, self.protocol)?,
+        }
+        write!(f, " -> [{}]", join(&priorities))
+    }
+}
+
+/// Sub-TLV of the [CeeDcbx] TLV
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CeeSubTlv {
+    /// Control sub-TLV (type 1)
+    Control(CeeControl),
+    /// Priority Group feature (type 2)
+    PriorityGroups(CeeFeatureHeader, CeePriorityGroups),
+    /// PFC feature (type 3)
+    Pfc(CeeFeatureHeader, CeePfc),
+    /// Application feature (type 4)
+    Application(CeeFeatureHeader, Vec<CeeAppEntry>),
+    /// Sub-TLV of another type, kept as it is
+    Unknown { sub_type: u8, value: Vec<u8> },
+}
+
+impl CeeSubTlv {
+    /// Get the sub-TLV type.
+    pub fn sub_type(&self) -> u8 {
+        match self {
+            CeeSubTlv::Control(_) => 1,
+            CeeSubTlv::PriorityGroups(..) => 2,
+            CeeSubTlv::Pfc(..) => 3,
+            CeeSubTlv::Application(..) => 4,
+            CeeSubTlv::Unknown { sub_type, .. } => *sub_type,
+        }
+    }
+
+    /// Decode a sub-TLV from its type and value.
+    fn from_value(sub_type: u8, value: &[u8]) -> Result<CeeSubTlv, TlvError> {
+        let invalid = |expected: &str| {
+            TlvError::InvalidValue(format!(
+                "CEE sub-TLV {} of {} octets, expected {}",
+                sub_type,
+                value.len(),
+                expected
+            ))
+        };
+
+        let subtlv = match sub_type {
+            1 => {
+                if value.len() != 10 {
+                    return Err(invalid("10 octets"));
+                }
+                CeeSubTlv::Control(CeeControl::new(
+                    value[0],
+                    value[1],
+                    u32::from_be_bytes([value[2], value[3], value[4], value[5]]),
+                    u32::from_be_bytes([value[6], value[7], value[8], value[9]]),
+                ))
+            }
+            2 => {
+                if value.len() != 17 {
+                    return Err(invalid("17 octets"));
+                }
+                let mut groups = CeePriorityGroups {
+                    pgid: [0; 8],
+                    percentage: [0; 8],
+                    num_tcs: value[16],
+                };
+                for priority in 0..8 {
+                    let octet = value[4 + priority / 2];
+                    groups.pgid[priority] = if priority % 2 == 0 {
+                        octet >> 4
+                    } else {
+                        octet & 0x0F
+                    };
+                }
+                groups.percentage.copy_from_slice(&value[8..16]);
+                CeeSubTlv::PriorityGroups(CeeFeatureHeader::from_bytes(value), groups)
+            }
+            3 => {
+                if value.len() != 6 {
+                    return Err(invalid("6 octets"));
+                }
+                CeeSubTlv::Pfc(
+                    CeeFeatureHeader::from_bytes(value),
+                    CeePfc {
+                        enabled: value[4],
+                        num_tcs: value[5],
+                    },
+                )
+            }
+            4 => {
+                if value.len() < 4 || !(value.len() - 4).is_multiple_of(6) {
+                    return Err(invalid("4 + 6n octets"));
+                }
+                let entries = value[4..]
+                    .chunks(6)
+                    .map(|entry| CeeAppEntry {
+                        protocol: u16::from_be_bytes([entry[0], entry[1]]),
+                        selector: entry[2] & 0x03,
+                        oui: [entry[2] >> 2, entry[3], entry[4]],
+                        priority_map: entry[5],
+                    })
+                    .collect();
+                CeeSubTlv::Application(CeeFeatureHeader::from_bytes(value), entries)
+            }
+            sub_type => CeeSubTlv::Unknown {
+                sub_type,
+                value: value.to_vec(),
+            },
+        };
+        Ok(subtlv)
+    }
+
+    /// Encode the value of the sub-TLV, without header.
+    fn value(&self) -> Vec<u8> {
+        match self {
+            CeeSubTlv::Control(control) => {
+                let mut value = vec![control.oper_version, control.max_version];
+                value.extend_from_slice(&control.seq_no.to_be_bytes());
+                value.extend_from_slice(&control.ack_no.to_be_bytes());
+                value
+            }
+            CeeSubTlv::PriorityGroups(header, groups) => {
+                let mut value = header.bytes();
+                value.extend(
+                    groups
+                        .pgid
+                        .chunks(2)
+                        .map(|pair| (pair[0] & 0x0F) << 4 | (pair[1] & 0x0F)),
+                );
+                value.extend_from_slice(&groups.percentage);
+                value.push(groups.num_tcs);
+                value
+            }
+            CeeSubTlv::Pfc(header, pfc) => {
+                let mut value = header.bytes();
+                value.extend_from_slice(&[pfc.enabled, pfc.num_tcs]);
+                value
+            }
+            CeeSubTlv::Application(header, entries) => {
+                let mut value = header.bytes();
+                for entry in entries {
+                    value.extend_from_slice(&entry.protocol.to_be_bytes());
+                    value.push(entry.oui[0] << 2 | (entry.selector & 0x03));
+                    value.extend_from_slice(&[entry.oui[1], entry.oui[2], entry.priority_map]);
+                }
+                value
+            }
+            CeeSubTlv::Unknown { value, .. } => value.clone(),
+        }
+    }
+}
+
+impl Display for CeeSubTlv {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CeeSubTlv::Control(control) => write!(f, "{}", control),
+            CeeSubTlv::PriorityGroups(header, groups) => write!(
+                f,
+                "PG {}: PGID [{}], bandwidth [{}], {} TCs",
+                header,
+                join(&groups.pgid),
+                join(&groups.percentage),
+                groups.num_tcs
+            ),
+            CeeSubTlv::Pfc(header, pfc) => {
+                let enabled: Vec<u8> = (0..8).filter(|bit| pfc.enabled & (1 << bit) != 0).collect();
+                write!(
+                    f,
+                    "PFC {}: enabled on [{}], {} TCs",
+                    header,
+                    join(&enabled),
+                    pfc.num_tcs
+                )
+            }
+            CeeSubTlv::Application(header, entries) => {
+                let entries: Vec<String> = entries.iter().map(|entry| entry.to_string()).collect();
+                write!(f, "App {}: {}", header, entries.join(", "))
+            }
+            CeeSubTlv::Unknown { sub_type, value } => {
+                write!(f, "sub-TLV {}: {} octets", sub_type, value.len())
+            }
+        }
+    }
+}
+
+/// CEE DCBX TLV (OUI 00-1B-21, subtype 2)
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+
+/// |             |                 |               |               |                       |
+/// |     127     |     Length      |   00-1B-21    |       2       |       Sub-TLVs        |
+/// |             |                 |               |               |                       |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+
+/// ```
+///
+/// Each sub-TLV starts with a 7 bit type and a 9 bit length, like an LLDP TLV. The control sub-TLV comes first,
+/// followed by the Priority Group, PFC and Application feature sub-TLVs, see [CeeSubTlv].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CeeDcbx {
+    /// The sub-TLVs, in their original order
+    pub subtlvs: Vec<CeeSubTlv>,
+}
+
+impl CeeDcbx {
+    /// Constructor
+    pub fn new(subtlvs: Vec<CeeSubTlv>) -> CeeDcbx {
+        CeeDcbx { subtlvs }
+    }
+
+    /// Get the control sub-TLV, if present
+    pub fn control(&self) -> Option<&CeeControl> {
+        self.subtlvs.iter().find_map(|subtlv| match subtlv {
+            CeeSubTlv::Control(control) => Some(control),
+            _ => None,
+        })
+    }
+
+    /// Get the Priority Group feature, if present
+    pub fn priority_groups(&self) -> Option<(&CeeFeatureHeader, &CeePriorityGroups)> {
+        self.subtlvs.iter().find_map(|subtlv| match subtlv {
+            CeeSubTlv::PriorityGroups(header, groups) => Some((header, groups)),
+            _ => None,
+        })
+    }
+
+    /// Get the PFC feature, if present
+    pub fn pfc(&self) -> Option<(&CeeFeatureHeader, &CeePfc)> {
+        self.subtlvs.iter().find_map(|subtlv| match subtlv {
+            CeeSubTlv::Pfc(header, pfc) => Some((header, pfc)),
+            _ => None,
+        })
+    }
+
+    /// Get the Application feature, if present
+    pub fn applications(&self) -> Option<(&CeeFeatureHeader, &[CeeAppEntry])> {
+        self.subtlvs.iter().find_map(|subtlv| match subtlv {
+            CeeSubTlv::Application(header, entries) => Some((header, entries.as_slice())),
+            _ => None,
+        })
+    }
+}
+
+impl Display for CeeDcbx {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let subtlvs: Vec<String> = self
+            .subtlvs
+            .iter()
+            .map(|subtlv| subtlv.to_string())
+            .collect();
+        write!(f, "{}", subtlvs.join("; "))
+    }
+}
+
+impl OrgTlvPayload for CeeDcbx {
+    fn oui(&self) -> [u8; 3] {
+        CEE_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        CEE_SUBTYPE
+    }
+
+    fn name(&self) -> String {
+        String::from("CEE DCBX")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let mut value = Vec::new();
+        for subtlv in &self.subtlvs {
+            let subvalue = subtlv.value();
+            let header = (subtlv.sub_type() as u16) << 9 | (subvalue.len() as u16 & 0x01FF);
+            value.extend_from_slice(&header.to_be_bytes());
+            value.extend(subvalue);
+        }
+        value
+    }
+
+    fn to_json(&self) -> Json {
+        let subtlvs: Vec<Json> = self
+            .subtlvs
+            .iter()
+            .map(|subtlv| {
+                Json::object(vec![
+                    ("type", subtlv.sub_type().into()),
+                    ("description", subtlv.to_string().into()),
+                    ("value", Json::hex(&subtlv.value())),
+                ])
+            })
+            .collect();
+        Json::object(vec![("subtlvs", Json::Array(subtlvs))])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for CeeDcbx {
+    const KEYS: &'static [([u8; 3], u8)] = &[(CEE_OUI, CEE_SUBTYPE)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        let mut subtlvs = Vec::new();
+        let mut index = 0;
+
+        while index < value.len() {
+            if index + 2 > value.len() {
+                return Err(TlvError::InvalidValue(String::from(
+                    "truncated CEE sub-TLV header",
+                )));
+            }
+            let header = u16::from_be_bytes([value[index], value[index + 1]]);
+            let sub_type = (header >> 9) as u8;
+            let length = (header & 0x01FF) as usize;
+            let end = index + 2 + length;
+            if end > value.len() {
+                return Err(TlvError::InvalidValue(format!(
+                    "CEE sub-TLV {} exceeds the TLV",
+                    sub_type
+                )));
+            }
+
+            subtlvs.push(CeeSubTlv::from_value(sub_type, &value[index + 2..end])?);
+            index = end;
+        }
+
+        Ok(CeeDcbx::new(subtlvs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+
+    /// Control, PG, PFC and App sub-TLVs as sent by a CEE switch
+    const CEE_TLV: &[u8] = b"\xfe\x37\x00\x1b\x21\x02\
+        \x02\x0a\x00\x00\x00\x00\x00\x05\x00\x00\x00\x03\
+        \x04\x11\x00\x00\x80\x00\x00\x01\x00\x00\x32\x32\x00\x00\x00\x00\x00\x00\x08\
+        \x06\x06\x00\x00\xc0\x00\x08\x08\
+        \x08\x0a\x00\x00\x80\x00\x89\x06\x00\x1b\x21\x08";
+
+    fn cee() -> CeeDcbx {
+        CeeDcbx::new(vec![
+            CeeSubTlv::Control(CeeControl::new(0, 0, 5, 3)),
+            CeeSubTlv::PriorityGroups(
+                CeeFeatureHeader::new(true, false, false),
+                CeePriorityGroups {
+                    pgid: [0, 0, 0, 1, 0, 0, 0, 0],
+                    percentage: [50, 50, 0, 0, 0, 0, 0, 0],
+                    num_tcs: 8,
+                },
+            ),
+            CeeSubTlv::Pfc(
+                CeeFeatureHeader::new(true, true, false),
+                CeePfc {
+                    enabled: 0x08,
+                    num_tcs: 8,
+                },
+            ),
+            CeeSubTlv::Application(
+                CeeFeatureHeader::new(true, false, false),
+                vec![CeeAppEntry {
+                    protocol: 0x8906,
+                    selector: 0,
+                    oui: [0x00, 0x1B, 0x21],
+                    priority_map: 0x08,
+                }],
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_dump() {
+        assert_eq!(
+            OrganizationallySpecificTLV::from_payload(&cee()).bytes(),
+            CEE_TLV.to_vec()
+        );
+    }
+
+    #[test]
+    fn test_load() {
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(CEE_TLV);
+        let cee = tlv.decode_as::<CeeDcbx>().unwrap();
+        assert_eq!(cee, self::cee());
+        assert_eq!(cee.control().unwrap().seq_no, 5);
+        assert_eq!(cee.pfc().unwrap().1.enabled, 0x08);
+        assert!(cee.pfc().unwrap().0.willing);
+        assert_eq!(cee.applications().unwrap().1.len(), 1);
+        assert_eq!(
+            format!("{}", cee),
+            "control v0/0 seq 5 ack 3; \
+             PG v0 enabled: PGID [0 0 0 1 0 0 0 0], bandwidth [50 50 0 0 0 0 0 0], 8 TCs; \
+             PFC v0 enabled willing: enabled on [3], 8 TCs; \
+             App v0 enabled: ethertype 0x8906 -> [3]"
+        );
+        assert_eq!(tlv.organization(), Some(String::from("Intel")));
+    }
+
+    #[test]
+    fn test_unknown_subtlv() {
+        let tlv =
+            OrganizationallySpecificTLV::new(CEE_OUI.to_vec(), 2, b"\x0c\x02\x01\x02".to_vec());
+        let cee = tlv.decode_as::<CeeDcbx>().unwrap();
+        assert_eq!(
+            cee.subtlvs,
+            vec![CeeSubTlv::Unknown {
+                sub_type: 6,
+                value: vec![1, 2]
+            }]
+        );
+        assert_eq!(
+            OrganizationallySpecificTLV::from_payload(&cee).bytes(),
+            tlv.bytes()
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        // Sub-TLV exceeding the TLV
+        let tlv =
+            OrganizationallySpecificTLV::new(CEE_OUI.to_vec(), 2, b"\x06\x06\x00\x00".to_vec());
+        assert!(tlv.decode().unwrap().is_err());
+
+        // PFC sub-TLV of the wrong length
+        let tlv = OrganizationallySpecificTLV::new(CEE_OUI.to_vec(), 2, b"\x06\x01\x00".to_vec());
+        assert!(tlv.decode_as::<CeeDcbx>().is_none());
+    }
+}
@@ -0,0 +1,265 @@
+//! Cisco Organizationally Specific TLVs (OUI 00-01-42)
+//!
+//! Only the 4-wire Power via MDI TLV is publicly documented. The remaining subtypes are kept as raw bytes, but
+//! labelled as Cisco TLVs, see [CiscoTlv].
+
+use crate::json::Json;
+use crate::tlv::organizationallyspecific_tlv::registry::{
+    OrgTlvDecode, OrgTlvPayload, OrgTlvRegistry,
+};
+use crate::tlv::{check_length, format_hex, TlvError, TlvType};
+
+use std::any::Any;
+use std::fmt::Display;
+
+/// OUI of Cisco Systems
+pub const CISCO_OUI: [u8; 3] = [0x00, 0x01, 0x42];
+
+/// Register the Cisco TLVs.
+///
+/// Subtypes without a typed decoder are decoded as [CiscoTlv].
+pub(crate) fn register(registry: &mut OrgTlvRegistry) {
+    registry.register_organization(CISCO_OUI, "Cisco");
+    registry.register_fallback(CISCO_OUI, |subtype, value| {
+        Ok(Box::new(CiscoTlv::new(subtype, value.to_vec())) as Box<dyn OrgTlvPayload>)
+    });
+    registry.register_type::<FourWirePower>();
+}
+
+/// Names of the 4-wire Power via MDI flags, by bit position
+const FOUR_WIRE_FLAGS: [&str; 4] = [
+    "4-pair PoE supported",
+    "spare pair detection/classification required",
+    "PD spare pair desired",
+    "PSE spare pair operational",
+];
+
+/// 4-wire Power via MDI TLV (subtype 1)
+///
+/// Used by Cisco UPOE to negotiate powering over the spare pairs in addition to the signal pairs.
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               7
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |             |                 |               |               |    4-wire     |
+/// |     127     |        5        |   00-01-42    |       1       |  Power-via-   |
+/// |             |                 |               |               |      MDI      |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// | Bit | Function                                          |
+/// |-----|---------------------------------------------------|
+/// | 0   | PSE supports 4-pair PoE                           |
+/// | 1   | PD requires spare pair detection/classification   |
+/// | 2   | PD desires spare pair power                       |
+/// | 3   | PSE spare pair power is operational               |
+/// | 4-7 | Reserved                                          |
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FourWirePower {
+    /// 4-pair PoE is supported
+    pub four_pair_supported: bool,
+    /// Spare pair detection/classification is required
+    pub detection_required: bool,
+    /// The PD desires power on the spare pair
+    pub pd_spare_pair_desired: bool,
+    /// The PSE supplies power on the spare pair
+    pub pse_spare_pair_operational: bool,
+}
+
+impl FourWirePower {
+    /// Constructor
+    pub fn new(
+        four_pair_supported: bool,
+        detection_required: bool,
+        pd_spare_pair_desired: bool,
+        pse_spare_pair_operational: bool,
+    ) -> FourWirePower {
+        FourWirePower {
+            four_pair_supported,
+            detection_required,
+            pd_spare_pair_desired,
+            pse_spare_pair_operational,
+        }
+    }
+
+    fn bits(&self) -> u8 {
+        self.four_pair_supported as u8
+            | (self.detection_required as u8) << 1
+            | (self.pd_spare_pair_desired as u8) << 2
+            | (self.pse_spare_pair_operational as u8) << 3
+    }
+}
+
+impl Display for FourWirePower {
+    /// Write the set flags, e.g. `4-pair PoE supported, PSE spare pair operational`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let flags: Vec<&str> = FOUR_WIRE_FLAGS
+            .iter()
+            .enumerate()
+            .filter(|(bit, _)| self.bits() & (1 << bit) != 0)
+            .map(|(_, name)| *name)
+            .collect();
+        if flags.is_empty() {
+            write!(f, "4-pair PoE not supported")
+        } else {
+            write!(f, "{}", flags.join(", "))
+        }
+    }
+}
+
+impl OrgTlvPayload for FourWirePower {
+    fn oui(&self) -> [u8; 3] {
+        CISCO_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        1
+    }
+
+    fn name(&self) -> String {
+        String::from("4-wire Power via MDI")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        vec![self.bits()]
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("four_pair_supported", self.four_pair_supported.into()),
+            ("detection_required", self.detection_required.into()),
+            ("pd_spare_pair_desired", self.pd_spare_pair_desired.into()),
+            (
+                "pse_spare_pair_operational",
+                self.pse_spare_pair_operational.into(),
+            ),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for FourWirePower {
+    const KEYS: &'static [([u8; 3], u8)] = &[(CISCO_OUI, 1)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 1, 1)?;
+        Ok(FourWirePower::new(
+            value[0] & 0x01 != 0,
+            value[0] & 0x02 != 0,
+            value[0] & 0x04 != 0,
+            value[0] & 0x08 != 0,
+        ))
+    }
+}
+
+/// Cisco TLV of an undocumented subtype
+///
+/// The value is kept as raw bytes. It is named `Cisco subtype N`, so that it can be told apart from TLVs of unknown
+/// organizations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CiscoTlv {
+    /// Organizationally defined subtype
+    pub subtype: u8,
+    /// Raw value
+    pub value: Vec<u8>,
+}
+
+impl CiscoTlv {
+    /// Constructor
+    pub fn new(subtype: u8, value: Vec<u8>) -> CiscoTlv {
+        CiscoTlv { subtype, value }
+    }
+}
+
+impl Display for CiscoTlv {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format_hex(&self.value, ""))
+    }
+}
+
+impl OrgTlvPayload for CiscoTlv {
+    fn oui(&self) -> [u8; 3] {
+        CISCO_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        self.subtype
+    }
+
+    fn name(&self) -> String {
+        format!("Cisco subtype {}", self.subtype)
+    }
+
+    fn value(&self) -> Vec<u8> {
+        self.value.clone()
+    }
+
+    fn to_json(&self) -> Json {
+        Json::hex(&self.value)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+
+    #[test]
+    fn test_four_wire_power() {
+        let bytes = b"\xfe\x05\x00\x01\x42\x01\x0d";
+        let power = FourWirePower::new(true, false, true, true);
+        let tlv = OrganizationallySpecificTLV::from_payload(&power);
+        assert_eq!(tlv.bytes(), bytes.to_vec());
+
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(bytes.as_ref());
+        assert_eq!(tlv.decode_as::<FourWirePower>(), Some(power));
+        assert_eq!(
+            format!("{}", tlv),
+            "OrganizationallySpecificTLV(\"000142\", 1, \"4-wire Power via MDI: 4-pair PoE supported, \
+             PD spare pair desired, PSE spare pair operational\")"
+        );
+        assert_eq!(
+            format!("{}", FourWirePower::new(false, false, false, false)),
+            "4-pair PoE not supported"
+        );
+    }
+
+    #[test]
+    fn test_undocumented_subtype() {
+        let tlv =
+            OrganizationallySpecificTLV::new(b"\x00\x01\x42".to_vec(), 201, b"\x01\x0a".to_vec());
+        assert_eq!(
+            format!("{}", tlv),
+            "OrganizationallySpecificTLV(\"000142\", 201, \"Cisco subtype 201: 010A\")"
+        );
+        assert_eq!(
+            format!("{}", tlv.to_json()),
+            "{\"oui\":\"00-01-42\",\"subtype\":201,\"organization\":\"Cisco\",\"name\":\"Cisco subtype 201\",\
+             \"value\":\"010a\",\"raw\":\"010a\"}"
+        );
+
+        let payload = tlv.decoded().unwrap();
+        assert_eq!(
+            OrganizationallySpecificTLV::from_payload(payload.as_ref()).bytes(),
+            tlv.bytes()
+        );
+    }
+
+    #[test]
+    fn test_invalid_length() {
+        let tlv = OrganizationallySpecificTLV::new(b"\x00\x01\x42".to_vec(), 1, vec![]);
+        assert!(matches!(
+            tlv.decode().unwrap(),
+            Err(TlvError::InvalidLength { length: 0, .. })
+        ));
+    }
+}
@@ -0,0 +1,595 @@
+//! Fabric Attach Organizationally Specific TLVs (OUI 00-04-0D)
+//!
+//! Fabric Attach (FA), originally developed by Avaya and now maintained by Extreme Networks, extends an SPB fabric
+//! to edge devices. FA clients and proxies advertise their element type in the [FaElement] TLV and request the
+//! mapping of VLANs onto service instances (I-SIDs) with the [FaAssignments] TLV. The FA server answers with the
+//! same list, updating the status of each assignment.
+//!
+//! Both TLVs start with an HMAC-SHA256 digest used for authenticating the TLV. It is carried as is; computing and
+//! checking it is left to the application. Without authentication the digest is all zero.
+
+use crate::json::Json;
+use crate::tlv::organizationallyspecific_tlv::registry::{
+    OrgTlvDecode, OrgTlvPayload, OrgTlvRegistry,
+};
+use crate::tlv::{check_length, format_mac, TlvError, TlvType};
+
+use std::any::Any;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// OUI of Avaya, used for the Fabric Attach TLVs
+pub const FA_OUI: [u8; 3] = [0x00, 0x04, 0x0D];
+
+/// Length of the HMAC-SHA256 digest preceding the TLV contents
+pub const FA_DIGEST_LENGTH: usize = 32;
+
+/// Maximum number of I-SID/VLAN assignments in an [FaAssignments] TLV
+pub const MAX_FA_ASSIGNMENTS: usize = (507 - FA_DIGEST_LENGTH) / 5;
+
+/// Register the Fabric Attach TLVs.
+pub(crate) fn register(registry: &mut OrgTlvRegistry) {
+    registry.register_organization(FA_OUI, "Avaya");
+    registry.register_type::<FaElement>();
+    registry.register_type::<FaAssignments>();
+}
+
+/// Names of the FA element types
+const ELEMENT_TYPES: [(u8, &str); 15] = [
+    (1, "Other"),
+    (2, "FA Server"),
+    (3, "FA Proxy"),
+    (4, "FA Server (no authentication)"),
+    (5, "FA Proxy (no authentication)"),
+    (6, "FA Client - Wireless AP (Type 1)"),
+    (7, "FA Client - Wireless AP (Type 2)"),
+    (8, "FA Client - Switch"),
+    (9, "FA Client - Router"),
+    (10, "FA Client - IP Phone"),
+    (11, "FA Client - IP Camera"),
+    (12, "FA Client - IP Video"),
+    (13, "FA Client - Security Device"),
+    (14, "FA Client - Virtual Switch"),
+    (15, "FA Client - Server/Endpoint"),
+];
+
+fn digest_at(value: &[u8]) -> [u8; FA_DIGEST_LENGTH] {
+    let mut digest = [0; FA_DIGEST_LENGTH];
+    digest.copy_from_slice(&value[..FA_DIGEST_LENGTH]);
+    digest
+}
+
+/// FA Element TLV (subtype 11)
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               38
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
+/// |             |                 |               |               |     HMAC      |
+/// |     127     |       50        |   00-04-0D    |      11       |    SHA256     |
+/// |             |                 |               |               |    Digest     |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
+///  38                                                            42              52
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
+/// |  Element  |  Element  |      Management       |               |    Element    |
+/// |   Type    |   State   |         VLAN          |   Reserved    |   System ID   |
+/// |           |           |                       |               |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
+///    6 bits      6 bits          12 bits              8 bits        10 octets
+/// ```
+///
+/// The element type and state are 6 bit fields. The system ID is commonly the base MAC address of the element followed
+/// by link and port information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FaElement {
+    /// HMAC-SHA256 digest
+    pub digest: [u8; FA_DIGEST_LENGTH],
+    /// Element type, see [FaElement::element_type_name]
+    pub element_type: u8,
+    /// Element state
+    pub state: u8,
+    /// Management VLAN, 0 if none
+    pub management_vlan: u16,
+    /// Element system ID
+    pub system_id: [u8; 10],
+}
+
+impl FaElement {
+    /// Constructor without authentication digest
+    ///
+    /// Fails if the element type or state exceed 6 bits or the management VLAN is not a valid VLAN ID.
+    pub fn try_new(
+        element_type: u8,
+        state: u8,
+        management_vlan: u16,
+        system_id: [u8; 10],
+    ) -> Result<FaElement, TlvError> {
+        if element_type > 0x3F {
+            return Err(TlvError::InvalidValue(format!(
+                "FA element type {}",
+                element_type
+            )));
+        }
+        if state > 0x3F {
+            return Err(TlvError::InvalidValue(format!(
+                "FA element state {}",
+                state
+            )));
+        }
+        if management_vlan > 4094 {
+            return Err(TlvError::InvalidValue(format!(
+                "management VLAN {}",
+                management_vlan
+            )));
+        }
+        Ok(FaElement {
+            digest: [0; FA_DIGEST_LENGTH],
+            element_type,
+            state,
+            management_vlan,
+            system_id,
+        })
+    }
+
+    /// Get the name of the element type, e.g. `FA Client - Switch`.
+    pub fn element_type_name(&self) -> String {
+        ELEMENT_TYPES
+            .iter()
+            .find(|(element_type, _)| *element_type == self.element_type)
+            .map(|(_, name)| name.to_string())
+            .unwrap_or_else(|| format!("element type {}", self.element_type))
+    }
+
+    /// Check whether the element is an FA client, as opposed to a server or proxy.
+    pub fn is_client(&self) -> bool {
+        self.element_type >= 6
+    }
+}
+
+impl Display for FaElement {
+    /// Write the element, e.g. `FA Client - Switch, state 0x00, management VLAN 10, system ID 00:11:...`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}, state 0x{:02x}, management VLAN {}, system ID {}",
+            self.element_type_name(),
+            self.state,
+            self.management_vlan,
+            format_mac(&self.system_id)
+        )
+    }
+}
+
+impl OrgTlvPayload for FaElement {
+    fn oui(&self) -> [u8; 3] {
+        FA_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        11
+    }
+
+    fn name(&self) -> String {
+        String::from("FA Element")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let fields = (self.element_type as u32 & 0x3F) << 18
+            | (self.state as u32 & 0x3F) << 12
+            | (self.management_vlan as u32 & 0x0FFF);
+        let mut value = self.digest.to_vec();
+        value.extend_from_slice(&fields.to_be_bytes()[1..]);
+        value.push(0);
+        value.extend_from_slice(&self.system_id);
+        value
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("element_type", self.element_type.into()),
+            ("element_type_name", self.element_type_name().into()),
+            ("state", self.state.into()),
+            ("management_vlan", self.management_vlan.into()),
+            ("system_id", Json::hex(&self.system_id)),
+            ("digest", Json::hex(&self.digest)),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for FaElement {
+    const KEYS: &'static [([u8; 3], u8)] = &[(FA_OUI, 11)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(
+            TlvType::OrganizationallySpecific,
+            value.len(),
+            FA_DIGEST_LENGTH + 14,
+            FA_DIGEST_LENGTH + 14,
+        )?;
+        let fields = &value[FA_DIGEST_LENGTH..];
+        let fields_word = u32::from_be_bytes([0, fields[0], fields[1], fields[2]]);
+        let mut system_id = [0; 10];
+        system_id.copy_from_slice(&fields[4..]);
+        Ok(FaElement {
+            digest: digest_at(value),
+            element_type: (fields_word >> 18) as u8,
+            state: (fields_word >> 12) as u8 & 0x3F,
+            management_vlan: fields_word as u16 & 0x0FFF,
+            system_id,
+        })
+    }
+}
+
+/// Status of an [FaAssignment]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FaAssignmentStatus {
+    /// Requested by the client, not yet processed by the server (1)
+    Pending,
+    /// Accepted by the server (2)
+    Active,
+    /// Rejected by the server, with the reason code (3-15)
+    Rejected(u8),
+    /// Reserved value (0)
+    Reserved(u8),
+}
+
+impl From<u8> for FaAssignmentStatus {
+    fn from(value: u8) -> FaAssignmentStatus {
+        match value {
+            1 => FaAssignmentStatus::Pending,
+            2 => FaAssignmentStatus::Active,
+            3..=15 => FaAssignmentStatus::Rejected(value),
+            value => FaAssignmentStatus::Reserved(value),
+        }
+    }
+}
+
+impl From<FaAssignmentStatus> for u8 {
+    fn from(status: FaAssignmentStatus) -> u8 {
+        match status {
+            FaAssignmentStatus::Pending => 1,
+            FaAssignmentStatus::Active => 2,
+            FaAssignmentStatus::Rejected(value) => value,
+            FaAssignmentStatus::Reserved(value) => value,
+        }
+    }
+}
+
+impl Display for FaAssignmentStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FaAssignmentStatus::Pending => write!(f, "pending"),
+            FaAssignmentStatus::Active => write!(f, "active"),
+            FaAssignmentStatus::Rejected(3) => write!(f, "rejected"),
+            FaAssignmentStatus::Rejected(4) => write!(f, "rejected: resources unavailable"),
+            FaAssignmentStatus::Rejected(6) => write!(f, "rejected: invalid"),
+            FaAssignmentStatus::Rejected(7) => write!(f, "rejected: VLAN resources unavailable"),
+            FaAssignmentStatus::Rejected(8) => write!(f, "rejected: application interaction"),
+            FaAssignmentStatus::Rejected(value) => write!(f, "rejected ({})", value),
+            FaAssignmentStatus::Reserved(value) => write!(f, "status {}", value),
+        }
+    }
+}
+
+/// Mapping of a VLAN onto an I-SID, see [FaAssignments]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FaAssignment {
+    /// Status of the assignment
+    pub status: FaAssignmentStatus,
+    /// VLAN ID
+    pub vlan: u16,
+    /// Service instance identifier (24 bits)
+    pub isid: u32,
+}
+
+impl FaAssignment {
+    /// Constructor
+    ///
+    /// Fails if the VLAN ID or the I-SID are out of range.
+    pub fn try_new(
+        status: FaAssignmentStatus,
+        vlan: u16,
+        isid: u32,
+    ) -> Result<FaAssignment, TlvError> {
+        if !(1..=4094).contains(&vlan) {
+            return Err(TlvError::InvalidValue(format!("VLAN ID {}", vlan)));
+        }
+        if isid == 0 || isid > 0xFF_FFFF {
+            return Err(TlvError::InvalidValue(format!("I-SID {}", isid)));
+        }
+        Ok(FaAssignment { status, vlan, isid })
+    }
+
+    fn bytes(&self) -> [u8; 5] {
+        let status = u8::from(self.status) as u64 & 0x0F;
+        let entry =
+            status << 36 | (self.vlan as u64 & 0x0FFF) << 24 | (self.isid as u64 & 0xFF_FFFF);
+        let mut bytes = [0; 5];
+        bytes.copy_from_slice(&entry.to_be_bytes()[3..]);
+        bytes
+    }
+
+    fn to_json(self) -> Json {
+        Json::object(vec![
+            ("status", u8::from(self.status).into()),
+            ("status_name", self.status.to_string().into()),
+            ("vlan", self.vlan.into()),
+            ("isid", self.isid.into()),
+        ])
+    }
+}
+
+impl Display for FaAssignment {
+    /// Write the assignment, e.g. `I-SID 10100 -> VLAN 100 (pending)`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "I-SID {} -> VLAN {} ({})",
+            self.isid, self.vlan, self.status
+        )
+    }
+}
+
+impl FromStr for FaAssignment {
+    type Err = TlvError;
+
+    /// Parse a pending assignment in the form `ISID:VID`, e.g. `10100:100`.
+    fn from_str(s: &str) -> Result<FaAssignment, TlvError> {
+        let (isid, vid) = s
+            .split_once(':')
+            .ok_or_else(|| TlvError::InvalidValue(format!("expected ISID:VID, got {:?}", s)))?;
+        let isid = isid
+            .trim()
+            .parse()
+            .map_err(|_| TlvError::InvalidValue(format!("I-SID {:?}", isid)))?;
+        let vid = vid
+            .trim()
+            .parse()
+            .map_err(|_| TlvError::InvalidValue(format!("VLAN ID {:?}", vid)))?;
+        FaAssignment::try_new(FaAssignmentStatus::Pending, vid, isid)
+    }
+}
+
+/// FA I-SID/VLAN Assignments TLV (subtype 12)
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               38
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
+/// |             |                 |               |               |     HMAC      |
+/// |     127     |    36 + 5*n     |   00-04-0D    |      12       |    SHA256     |
+/// |             |                 |               |               |    Digest     |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
+///  38                                                            43
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
+/// | Status  |         VLAN          |             I-SID             |  n - 1 more   |
+/// |         |                       |                               |  assignments  |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
+///   4 bits          12 bits                   24 bits
+/// ```
+///
+/// | Status | Meaning                                   |
+/// |--------|-------------------------------------------|
+/// | 1      | Pending                                   |
+/// | 2      | Active                                    |
+/// | 3      | Rejected                                  |
+/// | 4      | Rejected, resources unavailable           |
+/// | 6      | Rejected, invalid assignment              |
+/// | 7      | Rejected, VLAN resources unavailable      |
+/// | 8      | Rejected, application interaction issue   |
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FaAssignments {
+    /// HMAC-SHA256 digest
+    pub digest: [u8; FA_DIGEST_LENGTH],
+    /// I-SID/VLAN assignments
+    pub assignments: Vec<FaAssignment>,
+}
+
+impl FaAssignments {
+    /// Constructor without authentication digest
+    ///
+    /// Fails if there are more than [MAX_FA_ASSIGNMENTS] assignments.
+    pub fn try_new(assignments: Vec<FaAssignment>) -> Result<FaAssignments, TlvError> {
+        if assignments.len() > MAX_FA_ASSIGNMENTS {
+            return Err(TlvError::InvalidValue(format!(
+                "{} FA assignments, at most {} fit into a TLV",
+                assignments.len(),
+                MAX_FA_ASSIGNMENTS
+            )));
+        }
+        Ok(FaAssignments {
+            digest: [0; FA_DIGEST_LENGTH],
+            assignments,
+        })
+    }
+}
+
+impl Display for FaAssignments {
+    /// Write the assignments, e.g. `I-SID 10100 -> VLAN 100 (active), I-SID 10200 -> VLAN 200 (pending)`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.assignments.is_empty() {
+            return write!(f, "none");
+        }
+        let assignments: Vec<String> = self
+            .assignments
+            .iter()
+            .map(|assignment| assignment.to_string())
+            .collect();
+        write!(f, "{}", assignments.join(", "))
+    }
+}
+
+impl OrgTlvPayload for FaAssignments {
+    fn oui(&self) -> [u8; 3] {
+        FA_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        12
+    }
+
+    fn name(&self) -> String {
+        String::from("FA I-SID/VLAN Assignments")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let mut value = self.digest.to_vec();
+        for assignment in &self.assignments {
+            value.extend_from_slice(&assignment.bytes());
+        }
+        value
+    }
+
+    fn to_json(&self) -> Json {
+        let assignments: Vec<Json> = self
+            .assignments
+            .iter()
+            .map(|assignment| assignment.to_json())
+            .collect();
+        Json::object(vec![
+            ("assignments", assignments.into()),
+            ("digest", Json::hex(&self.digest)),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for FaAssignments {
+    const KEYS: &'static [([u8; 3], u8)] = &[(FA_OUI, 12)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(
+            TlvType::OrganizationallySpecific,
+            value.len(),
+            FA_DIGEST_LENGTH,
+            FA_DIGEST_LENGTH + 5 * MAX_FA_ASSIGNMENTS,
+        )?;
+        let entries = &value[FA_DIGEST_LENGTH..];
+        if !entries.len().is_multiple_of(5) {
+            return Err(TlvError::InvalidValue(format!(
+                "FA assignments of {} octets, expected a multiple of 5",
+                entries.len()
+            )));
+        }
+        let assignments = entries
+            .chunks(5)
+            .map(|entry| {
+                let isid = u32::from_be_bytes([0, entry[2], entry[3], entry[4]]);
+                FaAssignment {
+                    status: FaAssignmentStatus::from(entry[0] >> 4),
+                    vlan: u16::from_be_bytes([entry[0], entry[1]]) & 0x0FFF,
+                    isid,
+                }
+            })
+            .collect();
+        Ok(FaAssignments {
+            digest: digest_at(value),
+            assignments,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+
+    fn with_digest(header: &[u8], fields: &[u8]) -> Vec<u8> {
+        let mut bytes = header.to_vec();
+        bytes.extend_from_slice(&[0; FA_DIGEST_LENGTH]);
+        bytes.extend_from_slice(fields);
+        bytes
+    }
+
+    #[test]
+    fn test_element() {
+        let bytes = with_digest(
+            b"\xfe\x32\x00\x04\x0d\x0b",
+            b"\x20\x20\x0a\x00\x00\x11\x22\x33\x44\x55\x00\x00\x00\x01",
+        );
+        let element = FaElement::try_new(
+            8,
+            2,
+            10,
+            [0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x00, 0x00, 0x00, 0x01],
+        )
+        .unwrap();
+        let tlv = OrganizationallySpecificTLV::from_payload(&element);
+        assert_eq!(tlv.bytes(), bytes);
+
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(&bytes);
+        assert_eq!(tlv.decode_as::<FaElement>(), Some(element.clone()));
+        assert!(element.is_client());
+        assert_eq!(
+            format!("{}", element),
+            "FA Client - Switch, state 0x02, management VLAN 10, system ID 00:11:22:33:44:55:00:00:00:01"
+        );
+        assert!(FaElement::try_new(64, 0, 0, [0; 10]).is_err());
+        assert!(FaElement::try_new(8, 0, 4095, [0; 10]).is_err());
+    }
+
+    #[test]
+    fn test_assignments() {
+        let bytes = with_digest(
+            b"\xfe\x2e\x00\x04\x0d\x0c",
+            b"\x10\x64\x00\x27\x74\x20\xc8\x00\x27\xd8",
+        );
+        let assignments = FaAssignments::try_new(vec![
+            "10100:100".parse().unwrap(),
+            FaAssignment::try_new(FaAssignmentStatus::Active, 200, 10200).unwrap(),
+        ])
+        .unwrap();
+        let tlv = OrganizationallySpecificTLV::from_payload(&assignments);
+        assert_eq!(tlv.bytes(), bytes);
+
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(&bytes);
+        assert_eq!(tlv.decode_as::<FaAssignments>(), Some(assignments.clone()));
+        assert_eq!(
+            format!("{}", assignments),
+            "I-SID 10100 -> VLAN 100 (pending), I-SID 10200 -> VLAN 200 (active)"
+        );
+        assert_eq!(
+            format!("{}", assignments.to_json()),
+            "{\"assignments\":[{\"status\":1,\"status_name\":\"pending\",\"vlan\":100,\"isid\":10100},\
+             {\"status\":2,\"status_name\":\"active\",\"vlan\":200,\"isid\":10200}],\
+             \"digest\":\"0000000000000000000000000000000000000000000000000000000000000000\"}"
+        );
+    }
+
+    #[test]
+    fn test_rejected_assignment() {
+        let assignment =
+            FaAssignments::from_value((FA_OUI, 12), &with_digest(&[], b"\x70\x64\x00\x27\x74"))
+                .unwrap()
+                .assignments[0];
+        assert_eq!(assignment.status, FaAssignmentStatus::Rejected(7));
+        assert_eq!(
+            format!("{}", assignment),
+            "I-SID 10100 -> VLAN 100 (rejected: VLAN resources unavailable)"
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!("100".parse::<FaAssignment>().is_err());
+        assert!("0:100".parse::<FaAssignment>().is_err());
+        assert!("10100:4095".parse::<FaAssignment>().is_err());
+        assert!(matches!(
+            FaAssignments::from_value((FA_OUI, 12), &[0; FA_DIGEST_LENGTH + 4]),
+            Err(TlvError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            FaElement::from_value((FA_OUI, 11), &[0; FA_DIGEST_LENGTH]),
+            Err(TlvError::InvalidLength { .. })
+        ));
+    }
+}
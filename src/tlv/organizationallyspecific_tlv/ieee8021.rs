@@ -0,0 +1,1186 @@
+//! IEEE 802.1 Organizationally Specific TLVs (OUI 00-80-C2)
+
+use crate::json::Json;
+use crate::tlv::organizationallyspecific_tlv::ieee8023::IEEE_8023_OUI;
+use crate::tlv::organizationallyspecific_tlv::registry::{
+    OrgTlvDecode, OrgTlvPayload, OrgTlvRegistry,
+};
+use crate::tlv::{check_length, TlvError, TlvType};
+
+use std::any::Any;
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub mod dcbx;
+pub mod evb;
+
+/// OUI of the IEEE 802.1 working group
+pub const IEEE_8021_OUI: [u8; 3] = [0x00, 0x80, 0xC2];
+
+/// Register the IEEE 802.1 TLVs.
+pub(crate) fn register(registry: &mut OrgTlvRegistry) {
+    registry.register_organization(IEEE_8021_OUI, "IEEE 802.1");
+    registry.register_type::<PortVlanId>();
+    registry.register_type::<PortProtocolVlanId>();
+    registry.register_type::<VlanName>();
+    registry.register_type::<ProtocolIdentity>();
+    registry.register_type::<VidUsageDigest>();
+    registry.register_type::<ManagementVid>();
+    registry.register_type::<LinkAggregation>();
+    registry.register_type::<CongestionNotification>();
+    dcbx::register(registry);
+    evb::register(registry);
+}
+
+/// Maximum length of a VLAN name in octets
+pub const MAX_VLAN_NAME_LENGTH: usize = 32;
+
+/// Well-known protocol identities, as listed in IEEE 802.1Q Annex D
+///
+/// Each identity consists of the first octets of the protocol's frames following the source MAC address.
+const PROTOCOLS: [(&str, &[u8]); 6] = [
+    ("STP", &[0x00, 0x26, 0x42, 0x42, 0x03, 0x00, 0x00, 0x00]),
+    ("RSTP", &[0x00, 0x27, 0x42, 0x42, 0x03, 0x00, 0x00, 0x02]),
+    ("MSTP", &[0x00, 0x69, 0x42, 0x42, 0x03, 0x00, 0x00, 0x03]),
+    ("LACP", &[0x88, 0x09, 0x01]),
+    ("EAPOL", &[0x88, 0x8E, 0x01]),
+    ("LLDP", &[0x88, 0xCC]),
+];
+
+/// Port VLAN ID TLV (subtype 1)
+///
+/// Advertises the VLAN identifier untagged and priority tagged frames are assigned to (the "native VLAN").
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               8
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |             |                 |               |               |               |
+/// |     127     |        6        |   00-80-C2    |       1       |     PVID      |
+/// |             |                 |               |               |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// A PVID of 0 indicates that the port does not support port-based VLANs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PortVlanId {
+    /// The port VLAN identifier
+    pub pvid: u16,
+}
+
+impl PortVlanId {
+    /// Constructor
+    pub fn new(pvid: u16) -> PortVlanId {
+        PortVlanId { pvid }
+    }
+}
+
+impl Display for PortVlanId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pvid)
+    }
+}
+
+impl OrgTlvPayload for PortVlanId {
+    fn oui(&self) -> [u8; 3] {
+        IEEE_8021_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        1
+    }
+
+    fn name(&self) -> String {
+        String::from("Port VLAN ID")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        self.pvid.to_be_bytes().to_vec()
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![("pvid", self.pvid.into())])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for PortVlanId {
+    const KEYS: &'static [([u8; 3], u8)] = &[(IEEE_8021_OUI, 1)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 2, 2)?;
+        Ok(PortVlanId::new(u16::from_be_bytes([value[0], value[1]])))
+    }
+}
+
+/// Port and Protocol VLAN ID TLV (subtype 2)
+///
+/// Advertises a VLAN identifier used for protocol-based VLAN classification. A port may send one TLV per PPVID.
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               7               9
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |             |                 |               |               |               |               |
+/// |     127     |        7        |   00-80-C2    |       2       |     Flags     |     PPVID     |
+/// |             |                 |               |               |               |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// # Flags:
+///
+/// |  Bit  |        Meaning          |
+/// | ----- | ----------------------- |
+/// |   0   | reserved                |
+/// |   1   | PPVID supported         |
+/// |   2   | PPVID enabled           |
+/// |  3-7  | reserved                |
+///
+/// A PPVID of 0 indicates that the port is not a member of any protocol VLAN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PortProtocolVlanId {
+    /// Whether the port supports protocol-based VLANs
+    pub supported: bool,
+    /// Whether protocol-based VLANs are enabled on the port
+    pub enabled: bool,
+    /// The port and protocol VLAN identifier
+    pub ppvid: u16,
+}
+
+impl PortProtocolVlanId {
+    /// Constructor
+    pub fn new(supported: bool, enabled: bool, ppvid: u16) -> PortProtocolVlanId {
+        PortProtocolVlanId {
+            supported,
+            enabled,
+            ppvid,
+        }
+    }
+}
+
+impl Display for PortProtocolVlanId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({}, {})",
+            self.ppvid,
+            if self.supported {
+                "supported"
+            } else {
+                "not supported"
+            },
+            if self.enabled { "enabled" } else { "disabled" }
+        )
+    }
+}
+
+impl OrgTlvPayload for PortProtocolVlanId {
+    fn oui(&self) -> [u8; 3] {
+        IEEE_8021_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        2
+    }
+
+    fn name(&self) -> String {
+        String::from("Port and Protocol VLAN ID")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let flags = (self.supported as u8) << 1 | (self.enabled as u8) << 2;
+        let mut value = vec![flags];
+        value.extend_from_slice(&self.ppvid.to_be_bytes());
+        value
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("supported", self.supported.into()),
+            ("enabled", self.enabled.into()),
+            ("ppvid", self.ppvid.into()),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for PortProtocolVlanId {
+    const KEYS: &'static [([u8; 3], u8)] = &[(IEEE_8021_OUI, 2)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 3, 3)?;
+        Ok(PortProtocolVlanId::new(
+            value[0] & 0b010 != 0,
+            value[0] & 0b100 != 0,
+            u16::from_be_bytes([value[1], value[2]]),
+        ))
+    }
+}
+
+/// VLAN Name TLV (subtype 3)
+///
+/// Advertises the name assigned to a VLAN the port is a member of. A port sends one TLV per VLAN.
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               8               9
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+
+/// |             |                 |               |               |               |    Name       |             |
+/// |     127     |    7 - 39       |   00-80-C2    |       3       |      VID      |    Length     |  VLAN Name  |
+/// |             |                 |               |               |               |               |             |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+
+///
+///                                                                                             |<- 0 - 32 byte ->|
+/// ```
+///
+/// VLANs can be read from configuration in the form `VID:NAME`, e.g. `100:users`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VlanName {
+    /// The VLAN identifier
+    pub vid: u16,
+    /// The name of the VLAN
+    pub name: String,
+}
+
+impl VlanName {
+    /// Constructor
+    pub fn new(vid: u16, name: String) -> VlanName {
+        VlanName { vid, name }
+    }
+
+    /// Validating constructor
+    ///
+    /// Fails if the name exceeds [MAX_VLAN_NAME_LENGTH] octets or the VID is not within 1 - 4094.
+    pub fn try_new(vid: u16, name: String) -> Result<VlanName, TlvError> {
+        if vid == 0 || vid > 4094 {
+            return Err(TlvError::InvalidValue(format!("VLAN ID {}", vid)));
+        }
+        check_length(
+            TlvType::OrganizationallySpecific,
+            3 + name.len(),
+            3,
+            3 + MAX_VLAN_NAME_LENGTH,
+        )?;
+        Ok(VlanName::new(vid, name))
+    }
+}
+
+impl Display for VlanName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.vid, self.name)
+    }
+}
+
+impl FromStr for VlanName {
+    type Err = TlvError;
+
+    /// Parse a VLAN in the form `VID:NAME`, e.g. `100:users`.
+    fn from_str(s: &str) -> Result<VlanName, TlvError> {
+        let (vid, name) = s
+            .split_once(':')
+            .ok_or_else(|| TlvError::InvalidValue(format!("expected VID:NAME, got {:?}", s)))?;
+        let vid = vid
+            .trim()
+            .parse()
+            .map_err(|_| TlvError::InvalidValue(format!("VLAN ID {:?}", vid)))?;
+        VlanName::try_new(vid, name.to_string())
+    }
+}
+
+impl OrgTlvPayload for VlanName {
+    fn oui(&self) -> [u8; 3] {
+        IEEE_8021_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        3
+    }
+
+    fn name(&self) -> String {
+        String::from("VLAN Name")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let mut value = self.vid.to_be_bytes().to_vec();
+        value.push(self.name.len() as u8);
+        value.extend_from_slice(self.name.as_bytes());
+        value
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("vid", self.vid.into()),
+            ("name", self.name.as_str().into()),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for VlanName {
+    const KEYS: &'static [([u8; 3], u8)] = &[(IEEE_8021_OUI, 3)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(
+            TlvType::OrganizationallySpecific,
+            value.len(),
+            3,
+            3 + MAX_VLAN_NAME_LENGTH,
+        )?;
+        let length = 3 + value[2] as usize;
+        check_length(
+            TlvType::OrganizationallySpecific,
+            value.len(),
+            length,
+            length,
+        )?;
+
+        Ok(VlanName::new(
+            u16::from_be_bytes([value[0], value[1]]),
+            String::from_utf8_lossy(&value[3..]).into_owned(),
+        ))
+    }
+}
+
+/// Protocol Identity TLV (subtype 4)
+///
+/// Advertises a protocol accessible through the port, identified by the first octets of its frames following the
+/// source MAC address, e.g. `88 09 01` for LACP. A port sends one TLV per protocol.
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               7
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+
+/// |             |                 |               |               |   Identity    |             |
+/// |     127     |   5 - 260       |   00-80-C2    |       4       |    Length     |  Identity   |
+/// |             |                 |               |               |               |             |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+
+///
+///                                                                                 |<- 0 - 255 ->|
+/// ```
+///
+/// Well-known protocols (STP, RSTP, MSTP, LACP, EAPOL and LLDP) can be read from configuration by name, other
+/// protocols as hex string, e.g. `0x88cc`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtocolIdentity {
+    /// The leading octets of the protocol's frames
+    pub identity: Vec<u8>,
+}
+
+impl ProtocolIdentity {
+    /// Constructor
+    pub fn new(identity: Vec<u8>) -> ProtocolIdentity {
+        ProtocolIdentity { identity }
+    }
+
+    /// Get the name of a well-known protocol, e.g. `LACP`.
+    pub fn protocol(&self) -> Option<&'static str> {
+        PROTOCOLS
+            .iter()
+            .find(|(_, identity)| *identity == self.identity.as_slice())
+            .map(|(name, _)| *name)
+    }
+}
+
+impl Display for ProtocolIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.protocol() {
+            Some(name) => write!(f, "{}", name),
+            None => {
+                write!(f, "0x")?;
+                for octet in &self.identity {
+                    write!(f, "{:02x}", octet)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FromStr for ProtocolIdentity {
+    type Err = TlvError;
+
+    /// Parse the name of a well-known protocol (case insensitive) or a hex string with a `0x` prefix.
+    fn from_str(s: &str) -> Result<ProtocolIdentity, TlvError> {
+        if let Some((_, identity)) = PROTOCOLS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(s))
+        {
+            return Ok(ProtocolIdentity::new(identity.to_vec()));
+        }
+
+        let invalid = || TlvError::InvalidValue(format!("unknown protocol {:?}", s));
+        let hex = s.strip_prefix("0x").ok_or_else(invalid)?;
+        if hex.len() % 2 != 0 || hex.len() > 510 {
+            return Err(invalid());
+        }
+        let identity = (0..hex.len())
+            .step_by(2)
+            .map(|index| u8::from_str_radix(&hex[index..index + 2], 16).map_err(|_| invalid()))
+            .collect::<Result<Vec<u8>, TlvError>>()?;
+        Ok(ProtocolIdentity::new(identity))
+    }
+}
+
+impl OrgTlvPayload for ProtocolIdentity {
+    fn oui(&self) -> [u8; 3] {
+        IEEE_8021_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        4
+    }
+
+    fn name(&self) -> String {
+        String::from("Protocol Identity")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let mut value = vec![self.identity.len() as u8];
+        value.extend_from_slice(&self.identity);
+        value
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("protocol", self.protocol().into()),
+            ("identity", Json::hex(&self.identity)),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for ProtocolIdentity {
+    const KEYS: &'static [([u8; 3], u8)] = &[(IEEE_8021_OUI, 4)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 1, 256)?;
+        let length = 1 + value[0] as usize;
+        check_length(
+            TlvType::OrganizationallySpecific,
+            value.len(),
+            length,
+            length,
+        )?;
+
+        Ok(ProtocolIdentity::new(value[1..].to_vec()))
+    }
+}
+
+/// VID Usage Digest TLV (subtype 5)
+///
+/// Advertises a CRC32 digest of the VID Usage Table, allowing a neighbor to detect inconsistent VLAN to MSTI
+/// allocations.
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               10
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+
+/// |             |                 |               |               |                    |
+/// |     127     |        8        |   00-80-C2    |       5       |  VID Usage Digest  |
+/// |             |                 |               |               |                    |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VidUsageDigest {
+    /// The CRC32 digest of the VID Usage Table
+    pub digest: u32,
+}
+
+impl VidUsageDigest {
+    /// Constructor
+    pub fn new(digest: u32) -> VidUsageDigest {
+        VidUsageDigest { digest }
+    }
+}
+
+impl Display for VidUsageDigest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#010x}", self.digest)
+    }
+}
+
+impl OrgTlvPayload for VidUsageDigest {
+    fn oui(&self) -> [u8; 3] {
+        IEEE_8021_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        5
+    }
+
+    fn name(&self) -> String {
+        String::from("VID Usage Digest")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        self.digest.to_be_bytes().to_vec()
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![("digest", self.digest.into())])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for VidUsageDigest {
+    const KEYS: &'static [([u8; 3], u8)] = &[(IEEE_8021_OUI, 5)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 4, 4)?;
+        Ok(VidUsageDigest::new(u32::from_be_bytes([
+            value[0], value[1], value[2], value[3],
+        ])))
+    }
+}
+
+/// Management VID TLV (subtype 6)
+///
+/// Advertises the VLAN identifier used for management of the system.
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               8
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |             |                 |               |               |  Management   |
+/// |     127     |        6        |   00-80-C2    |       6       |      VID      |
+/// |             |                 |               |               |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// A VID of 0 indicates that the system is not managed through a VLAN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ManagementVid {
+    /// The management VLAN identifier
+    pub vid: u16,
+}
+
+impl ManagementVid {
+    /// Constructor
+    pub fn new(vid: u16) -> ManagementVid {
+        ManagementVid { vid }
+    }
+}
+
+impl Display for ManagementVid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.vid)
+    }
+}
+
+impl OrgTlvPayload for ManagementVid {
+    fn oui(&self) -> [u8; 3] {
+        IEEE_8021_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        6
+    }
+
+    fn name(&self) -> String {
+        String::from("Management VID")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        self.vid.to_be_bytes().to_vec()
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![("vid", self.vid.into())])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for ManagementVid {
+    const KEYS: &'static [([u8; 3], u8)] = &[(IEEE_8021_OUI, 6)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 2, 2)?;
+        Ok(ManagementVid::new(u16::from_be_bytes([value[0], value[1]])))
+    }
+}
+
+/// Type of the port advertising a [LinkAggregation] TLV
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggregationPortType {
+    /// No port type specified, always the case for the deprecated 802.3 form
+    Unspecified = 0,
+    /// The port is an Aggregator
+    Aggregator = 1,
+    /// The port is an Aggregation Port
+    AggregationPort = 2,
+    /// The port is an Aggregator with a single Aggregation Port
+    AggregatorWithSinglePort = 3,
+}
+
+impl From<u8> for AggregationPortType {
+    /// Convert the two port type bits.
+    fn from(bits: u8) -> AggregationPortType {
+        match bits & 0b11 {
+            1 => AggregationPortType::Aggregator,
+            2 => AggregationPortType::AggregationPort,
+            3 => AggregationPortType::AggregatorWithSinglePort,
+            _ => AggregationPortType::Unspecified,
+        }
+    }
+}
+
+impl Display for AggregationPortType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            AggregationPortType::Unspecified => "unspecified",
+            AggregationPortType::Aggregator => "aggregator",
+            AggregationPortType::AggregationPort => "aggregation port",
+            AggregationPortType::AggregatorWithSinglePort => "aggregator with single port",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Link Aggregation TLV (802.1 subtype 7, deprecated 802.3 subtype 3)
+///
+/// Advertises whether the link is capable of being aggregated, whether it currently is and, if so, the port ID of
+/// the aggregation. Both forms share the same layout, the 802.3 form does not define the port type bits.
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               7               11
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+
+/// |             |                 |   00-80-C2    |       7       |  Aggregation  |  Aggregated   |
+/// |     127     |        9        |      or       |      or       |    Status     |    Port ID    |
+/// |             |                 |   00-12-0F    |       3       |               |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+
+/// ```
+///
+/// # Aggregation Status:
+///
+/// |  Bit  |        Meaning          |
+/// | ----- | ----------------------- |
+/// |   0   | aggregation capability  |
+/// |   1   | aggregation status      |
+/// |  2-3  | port type (802.1 only)  |
+/// |  4-7  | reserved                |
+///
+/// The aggregated port ID is the ifIndex of the aggregation, or 0 if the link is not aggregated. Links ending up in
+/// the same aggregation on the remote system advertise the same aggregated port ID, see
+/// [LinkAggregation::same_aggregation].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinkAggregation {
+    /// Whether the link can be aggregated
+    pub capable: bool,
+    /// Whether the link is currently aggregated
+    pub aggregated: bool,
+    /// The type of the port
+    pub port_type: AggregationPortType,
+    /// The ifIndex of the aggregation
+    pub port_id: u32,
+    /// Whether the TLV uses the deprecated 802.3 form
+    pub legacy: bool,
+}
+
+impl LinkAggregation {
+    /// Constructor for the 802.1 form
+    pub fn new(
+        capable: bool,
+        aggregated: bool,
+        port_type: AggregationPortType,
+        port_id: u32,
+    ) -> LinkAggregation {
+        LinkAggregation {
+            capable,
+            aggregated,
+            port_type,
+            port_id,
+            legacy: false,
+        }
+    }
+
+    /// Check whether two links are members of the same aggregation.
+    ///
+    /// Both links have to be aggregated under the same aggregated port ID. As the port ID is only unique within a
+    /// system, the caller has to make sure both TLVs were sent by the same chassis.
+    pub fn same_aggregation(&self, other: &LinkAggregation) -> bool {
+        self.aggregated && other.aggregated && self.port_id == other.port_id
+    }
+}
+
+impl Display for LinkAggregation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.aggregated {
+            write!(f, "aggregated, port ID {}", self.port_id)?;
+        } else if self.capable {
+            write!(f, "capable, not aggregated")?;
+        } else {
+            write!(f, "not capable")?;
+        }
+
+        if self.port_type != AggregationPortType::Unspecified {
+            write!(f, " ({})", self.port_type)?;
+        }
+        Ok(())
+    }
+}
+
+impl OrgTlvPayload for LinkAggregation {
+    fn oui(&self) -> [u8; 3] {
+        if self.legacy {
+            IEEE_8023_OUI
+        } else {
+            IEEE_8021_OUI
+        }
+    }
+
+    fn subtype(&self) -> u8 {
+        if self.legacy {
+            3
+        } else {
+            7
+        }
+    }
+
+    fn name(&self) -> String {
+        String::from("Link Aggregation")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let status =
+            self.capable as u8 | (self.aggregated as u8) << 1 | (self.port_type as u8) << 2;
+        let mut value = vec![status];
+        value.extend_from_slice(&self.port_id.to_be_bytes());
+        value
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("capable", self.capable.into()),
+            ("aggregated", self.aggregated.into()),
+            ("port_type", self.port_type.to_string().into()),
+            ("port_id", self.port_id.into()),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for LinkAggregation {
+    const KEYS: &'static [([u8; 3], u8)] = &[(IEEE_8021_OUI, 7), (IEEE_8023_OUI, 3)];
+
+    fn from_value(key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 5, 5)?;
+        let legacy = key.0 == IEEE_8023_OUI;
+
+        Ok(LinkAggregation {
+            capable: value[0] & 0b01 != 0,
+            aggregated: value[0] & 0b10 != 0,
+            port_type: if legacy {
+                AggregationPortType::Unspecified
+            } else {
+                AggregationPortType::from(value[0] >> 2)
+            },
+            port_id: u32::from_be_bytes([value[1], value[2], value[3], value[4]]),
+            legacy,
+        })
+    }
+}
+
+/// Congestion Notification TLV (subtype 8)
+///
+/// Advertises the congestion notification state (IEEE 802.1Qau) of the port for each of the eight priorities.
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               7               8
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |             |                 |               |               |     CNPV      |     Ready     |
+/// |     127     |        6        |   00-80-C2    |       8       |  Indicators   |  Indicators   |
+/// |             |                 |               |               |               |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// Bit n of each bitmap refers to priority n. A priority is a congestion notification priority value (CNPV) if its
+/// CNPV indicator is set, and ready once the port has determined that the neighbor supports congestion notification
+/// for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CongestionNotification {
+    /// CNPV indicators, by priority
+    pub cnpv: u8,
+    /// Ready indicators, by priority
+    pub ready: u8,
+}
+
+impl CongestionNotification {
+    /// Constructor
+    pub fn new(cnpv: u8, ready: u8) -> CongestionNotification {
+        CongestionNotification { cnpv, ready }
+    }
+
+    /// Check whether `priority` is a congestion notification priority value.
+    pub fn is_cnpv(&self, priority: u8) -> bool {
+        priority < 8 && self.cnpv & (1 << priority) != 0
+    }
+
+    /// Check whether the port is ready for congestion notification on `priority`.
+    pub fn is_ready(&self, priority: u8) -> bool {
+        priority < 8 && self.ready & (1 << priority) != 0
+    }
+}
+
+/// List the priorities set in `bitmap`, e.g. `[3, 5]`.
+fn priorities(bitmap: u8) -> Vec<u8> {
+    (0..8).filter(|bit| bitmap & (1 << bit) != 0).collect()
+}
+
+/// Render the priorities set in `bitmap` as comma separated list, `none` if no bit is set.
+fn priority_list(bitmap: u8) -> String {
+    let priorities: Vec<String> = priorities(bitmap).iter().map(u8::to_string).collect();
+    if priorities.is_empty() {
+        String::from("none")
+    } else {
+        priorities.join(", ")
+    }
+}
+
+impl Display for CongestionNotification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CNPV {} [ready: {}]",
+            priority_list(self.cnpv),
+            priority_list(self.ready)
+        )
+    }
+}
+
+impl OrgTlvPayload for CongestionNotification {
+    fn oui(&self) -> [u8; 3] {
+        IEEE_8021_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        8
+    }
+
+    fn name(&self) -> String {
+        String::from("Congestion Notification")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        vec![self.cnpv, self.ready]
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("cnpv", priorities(self.cnpv).into()),
+            ("ready", priorities(self.ready).into()),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for CongestionNotification {
+    const KEYS: &'static [([u8; 3], u8)] = &[(IEEE_8021_OUI, 8)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 2, 2)?;
+        Ok(CongestionNotification::new(value[0], value[1]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+
+    #[test]
+    fn test_pvid_dump() {
+        let tlv = OrganizationallySpecificTLV::from_payload(&PortVlanId::new(100));
+        assert_eq!(tlv.bytes(), b"\xfe\x06\x00\x80\xc2\x01\x00\x64".to_vec());
+    }
+
+    #[test]
+    fn test_pvid_load() {
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(
+            b"\xfe\x06\x00\x80\xc2\x01\x0f\xfe".as_ref(),
+        );
+        assert_eq!(tlv.decode_as::<PortVlanId>(), Some(PortVlanId::new(4094)));
+        assert_eq!(
+            format!("{}", tlv),
+            "OrganizationallySpecificTLV(\"0080C2\", 1, \"Port VLAN ID: 4094\")"
+        );
+    }
+
+    #[test]
+    fn test_pvid_invalid_length() {
+        let tlv = OrganizationallySpecificTLV::new(IEEE_8021_OUI.to_vec(), 1, vec![0x64]);
+        assert!(tlv.decode_as::<PortVlanId>().is_none());
+        assert!(tlv.decode().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_ppvid_dump() {
+        let tlv =
+            OrganizationallySpecificTLV::from_payload(&PortProtocolVlanId::new(true, true, 10));
+        assert_eq!(
+            tlv.bytes(),
+            b"\xfe\x07\x00\x80\xc2\x02\x06\x00\x0a".to_vec()
+        );
+    }
+
+    #[test]
+    fn test_ppvid_load() {
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(
+            b"\xfe\x07\x00\x80\xc2\x02\x02\x00\x0a".as_ref(),
+        );
+        let ppvid = tlv.decode_as::<PortProtocolVlanId>().unwrap();
+        assert_eq!(ppvid, PortProtocolVlanId::new(true, false, 10));
+        assert_eq!(format!("{}", ppvid), "10 (supported, disabled)");
+        assert_eq!(
+            format!("{}", tlv.to_json()),
+            "{\"oui\":\"00-80-C2\",\"subtype\":2,\"organization\":\"IEEE 802.1\",\
+             \"name\":\"Port and Protocol VLAN ID\",\
+             \"value\":{\"supported\":true,\"enabled\":false,\"ppvid\":10},\"raw\":\"02000a\"}"
+        );
+    }
+
+    #[test]
+    fn test_vlan_name_dump() {
+        let vlan = VlanName::new(100, String::from("users"));
+        let tlv = OrganizationallySpecificTLV::from_payload(&vlan);
+        assert_eq!(
+            tlv.bytes(),
+            b"\xfe\x0c\x00\x80\xc2\x03\x00\x64\x05users".to_vec()
+        );
+    }
+
+    #[test]
+    fn test_vlan_name_load() {
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(
+            b"\xfe\x0c\x00\x80\xc2\x03\x00\xc8\x05voice".as_ref(),
+        );
+        assert_eq!(
+            tlv.decode_as::<VlanName>(),
+            Some(VlanName::new(200, String::from("voice")))
+        );
+        assert_eq!(
+            format!("{}", tlv),
+            "OrganizationallySpecificTLV(\"0080C2\", 3, \"VLAN Name: 200 voice\")"
+        );
+    }
+
+    #[test]
+    fn test_vlan_name_invalid_length() {
+        // Name length does not match the TLV length
+        let tlv = OrganizationallySpecificTLV::new(
+            IEEE_8021_OUI.to_vec(),
+            3,
+            b"\x00\x64\x06users".to_vec(),
+        );
+        assert!(tlv.decode_as::<VlanName>().is_none());
+
+        assert!(VlanName::try_new(100, "x".repeat(32)).is_ok());
+        assert!(VlanName::try_new(100, "x".repeat(33)).is_err());
+        assert!(VlanName::try_new(4095, String::from("users")).is_err());
+    }
+
+    #[test]
+    fn test_vlan_name_from_str() {
+        assert_eq!(
+            "100:users".parse::<VlanName>().unwrap(),
+            VlanName::new(100, String::from("users"))
+        );
+        assert_eq!(
+            "10:a:b".parse::<VlanName>().unwrap(),
+            VlanName::new(10, String::from("a:b"))
+        );
+        assert!("users".parse::<VlanName>().is_err());
+        assert!("x:users".parse::<VlanName>().is_err());
+    }
+
+    #[test]
+    fn test_protocol_identity_dump() {
+        let tlv =
+            OrganizationallySpecificTLV::from_payload(&"lacp".parse::<ProtocolIdentity>().unwrap());
+        assert_eq!(
+            tlv.bytes(),
+            b"\xfe\x08\x00\x80\xc2\x04\x03\x88\x09\x01".to_vec()
+        );
+    }
+
+    #[test]
+    fn test_protocol_identity_load() {
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(
+            b"\xfe\x0d\x00\x80\xc2\x04\x08\x00\x26\x42\x42\x03\x00\x00\x00".as_ref(),
+        );
+        let identity = tlv.decode_as::<ProtocolIdentity>().unwrap();
+        assert_eq!(identity.protocol(), Some("STP"));
+        assert_eq!(
+            format!("{}", tlv.to_json()),
+            "{\"oui\":\"00-80-C2\",\"subtype\":4,\"organization\":\"IEEE 802.1\",\
+             \"name\":\"Protocol Identity\",\
+             \"value\":{\"protocol\":\"STP\",\"identity\":\"0026424203000000\"},\
+             \"raw\":\"080026424203000000\"}"
+        );
+    }
+
+    #[test]
+    fn test_protocol_identity_unknown() {
+        let identity = "0x88b5".parse::<ProtocolIdentity>().unwrap();
+        assert_eq!(identity, ProtocolIdentity::new(vec![0x88, 0xb5]));
+        assert_eq!(identity.protocol(), None);
+        assert_eq!(format!("{}", identity), "0x88b5");
+        assert!("0x8".parse::<ProtocolIdentity>().is_err());
+        assert!("ospf".parse::<ProtocolIdentity>().is_err());
+
+        let tlv = OrganizationallySpecificTLV::new(IEEE_8021_OUI.to_vec(), 4, vec![0x02, 0x88]);
+        assert!(tlv.decode().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_link_aggregation_dump() {
+        let lag = LinkAggregation::new(true, true, AggregationPortType::AggregationPort, 0x2711);
+        let tlv = OrganizationallySpecificTLV::from_payload(&lag);
+        assert_eq!(
+            tlv.bytes(),
+            b"\xfe\x09\x00\x80\xc2\x07\x0b\x00\x00\x27\x11".to_vec()
+        );
+    }
+
+    #[test]
+    fn test_link_aggregation_load() {
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(
+            b"\xfe\x09\x00\x80\xc2\x07\x0b\x00\x00\x27\x11".as_ref(),
+        );
+        let lag = tlv.decode_as::<LinkAggregation>().unwrap();
+        assert_eq!(
+            lag,
+            LinkAggregation::new(true, true, AggregationPortType::AggregationPort, 10001)
+        );
+        assert_eq!(
+            format!("{}", lag),
+            "aggregated, port ID 10001 (aggregation port)"
+        );
+    }
+
+    #[test]
+    fn test_link_aggregation_legacy() {
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(
+            b"\xfe\x09\x00\x12\x0f\x03\x03\x00\x00\x27\x11".as_ref(),
+        );
+        let lag = tlv.decode_as::<LinkAggregation>().unwrap();
+        assert!(lag.legacy);
+        assert_eq!(lag.port_type, AggregationPortType::Unspecified);
+        assert_eq!(format!("{}", lag), "aggregated, port ID 10001");
+        assert_eq!(
+            format!("{}", tlv),
+            "OrganizationallySpecificTLV(\"00120F\", 3, \"Link Aggregation: aggregated, port ID 10001\")"
+        );
+
+        // Encoding keeps the form
+        assert_eq!(
+            OrganizationallySpecificTLV::from_payload(&lag).bytes(),
+            tlv.bytes()
+        );
+    }
+
+    #[test]
+    fn test_link_aggregation_same_aggregation() {
+        let first = LinkAggregation::new(true, true, AggregationPortType::AggregationPort, 10001);
+        let legacy = OrganizationallySpecificTLV::new_from_bytes(
+            b"\xfe\x09\x00\x12\x0f\x03\x03\x00\x00\x27\x11".as_ref(),
+        )
+        .decode_as::<LinkAggregation>()
+        .unwrap();
+        let other = LinkAggregation::new(true, true, AggregationPortType::AggregationPort, 10002);
+        let standalone = LinkAggregation::new(true, false, AggregationPortType::Unspecified, 0);
+
+        assert!(first.same_aggregation(&legacy));
+        assert!(!first.same_aggregation(&other));
+        assert!(!standalone.same_aggregation(&standalone));
+        assert_eq!(format!("{}", standalone), "capable, not aggregated");
+    }
+
+    #[test]
+    fn test_vid_usage_digest() {
+        let tlv = OrganizationallySpecificTLV::from_payload(&VidUsageDigest::new(0xdeadbeef));
+        assert_eq!(
+            tlv.bytes(),
+            b"\xfe\x08\x00\x80\xc2\x05\xde\xad\xbe\xef".to_vec()
+        );
+        assert_eq!(
+            format!("{}", tlv),
+            "OrganizationallySpecificTLV(\"0080C2\", 5, \"VID Usage Digest: 0xdeadbeef\")"
+        );
+    }
+
+    #[test]
+    fn test_management_vid() {
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(
+            b"\xfe\x06\x00\x80\xc2\x06\x00\x0a".as_ref(),
+        );
+        assert_eq!(
+            tlv.decode_as::<ManagementVid>(),
+            Some(ManagementVid::new(10))
+        );
+        assert_eq!(
+            OrganizationallySpecificTLV::from_payload(&ManagementVid::new(10)).bytes(),
+            tlv.bytes()
+        );
+    }
+
+    #[test]
+    fn test_congestion_notification_dump() {
+        let cn = CongestionNotification::new(0b0010_1000, 0b0000_1000);
+        let tlv = OrganizationallySpecificTLV::from_payload(&cn);
+        assert_eq!(tlv.bytes(), b"\xfe\x06\x00\x80\xc2\x08\x28\x08".to_vec());
+    }
+
+    #[test]
+    fn test_congestion_notification_load() {
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(
+            b"\xfe\x06\x00\x80\xc2\x08\x28\x08".as_ref(),
+        );
+        let cn = tlv.decode_as::<CongestionNotification>().unwrap();
+        assert!(cn.is_cnpv(3) && cn.is_ready(3));
+        assert!(cn.is_cnpv(5) && !cn.is_ready(5));
+        assert!(!cn.is_cnpv(0) && !cn.is_cnpv(8));
+        assert_eq!(format!("{}", cn), "CNPV 3, 5 [ready: 3]");
+        assert_eq!(
+            format!("{}", cn.to_json()),
+            "{\"cnpv\":[3,5],\"ready\":[3]}"
+        );
+        assert_eq!(
+            format!("{}", CongestionNotification::new(0, 0)),
+            "CNPV none [ready: none]"
+        );
+    }
+}
@@ -0,0 +1,827 @@
+//! IEEE 802.1Qaz Data Center Bridging Exchange (DCBX) TLVs
+//!
+//! DCBX uses LLDP to exchange the Enhanced Transmission Selection (ETS), Priority-based Flow Control (PFC) and
+//! Application Priority configuration of a link between the peers.
+
+use crate::json::Json;
+use crate::tlv::organizationallyspecific_tlv::ieee8021::IEEE_8021_OUI;
+use crate::tlv::organizationallyspecific_tlv::registry::{
+    OrgTlvDecode, OrgTlvPayload, OrgTlvRegistry,
+};
+use crate::tlv::{check_length, TlvError, TlvType};
+
+use std::any::Any;
+use std::fmt::Display;
+
+/// Number of priorities and traffic classes
+pub const PRIORITIES: usize = 8;
+
+/// Register the DCBX TLVs.
+pub(crate) fn register(registry: &mut OrgTlvRegistry) {
+    registry.register_type::<EtsConfiguration>();
+    registry.register_type::<EtsRecommendation>();
+    registry.register_type::<PriorityFlowControl>();
+    registry.register_type::<ApplicationPriority>();
+}
+
+/// Transmission selection algorithm of a traffic class
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransmissionSelectionAlgorithm {
+    /// Strict priority (0)
+    StrictPriority,
+    /// Credit-based shaper (1)
+    CreditBasedShaper,
+    /// Enhanced transmission selection (2)
+    Ets,
+    /// Vendor specific algorithm (255)
+    VendorSpecific,
+    /// Reserved value
+    Reserved(u8),
+}
+
+impl From<u8> for TransmissionSelectionAlgorithm {
+    fn from(value: u8) -> TransmissionSelectionAlgorithm {
+        match value {
+            0 => TransmissionSelectionAlgorithm::StrictPriority,
+            1 => TransmissionSelectionAlgorithm::CreditBasedShaper,
+            2 => TransmissionSelectionAlgorithm::Ets,
+            255 => TransmissionSelectionAlgorithm::VendorSpecific,
+            value => TransmissionSelectionAlgorithm::Reserved(value),
+        }
+    }
+}
+
+impl From<TransmissionSelectionAlgorithm> for u8 {
+    fn from(tsa: TransmissionSelectionAlgorithm) -> u8 {
+        match tsa {
+            TransmissionSelectionAlgorithm::StrictPriority => 0,
+            TransmissionSelectionAlgorithm::CreditBasedShaper => 1,
+            TransmissionSelectionAlgorithm::Ets => 2,
+            TransmissionSelectionAlgorithm::VendorSpecific => 255,
+            TransmissionSelectionAlgorithm::Reserved(value) => value,
+        }
+    }
+}
+
+impl Display for TransmissionSelectionAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransmissionSelectionAlgorithm::StrictPriority => write!(f, "strict"),
+            TransmissionSelectionAlgorithm::CreditBasedShaper => write!(f, "cbs"),
+            TransmissionSelectionAlgorithm::Ets => write!(f, "ets"),
+            TransmissionSelectionAlgorithm::VendorSpecific => write!(f, "vendor"),
+            TransmissionSelectionAlgorithm::Reserved(value) => write!(f, "reserved-{}", value),
+        }
+    }
+}
+
+/// ETS tables shared by the [EtsConfiguration] and [EtsRecommendation] TLVs
+///
+/// # Format:
+///
+/// ```text
+///  0               4               12              20
+/// +-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-...-+-+-+
+/// |   Priority    |      TC       |      TSA      |
+/// |  Assignment   |   Bandwidth   |  Assignment   |
+/// |     Table     |     Table     |     Table     |
+/// +-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-...-+-+-+
+/// ```
+///
+/// The priority assignment table holds the traffic class of each priority in four bits, starting with priority 0
+/// in the upper bits of the first octet. The bandwidth (in percent) and TSA tables hold one octet per traffic class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EtsTables {
+    /// Traffic class, by priority
+    pub priority_tc: [u8; PRIORITIES],
+    /// Bandwidth share in percent, by traffic class
+    pub bandwidth: [u8; PRIORITIES],
+    /// Transmission selection algorithm, by traffic class
+    pub tsa: [TransmissionSelectionAlgorithm; PRIORITIES],
+}
+
+impl EtsTables {
+    /// Constructor
+    pub fn new(
+        priority_tc: [u8; PRIORITIES],
+        bandwidth: [u8; PRIORITIES],
+        tsa: [TransmissionSelectionAlgorithm; PRIORITIES],
+    ) -> EtsTables {
+        EtsTables {
+            priority_tc,
+            bandwidth,
+            tsa,
+        }
+    }
+
+    /// Length of the encoded tables in octets
+    const LENGTH: usize = 20;
+
+    fn from_bytes(bytes: &[u8]) -> EtsTables {
+        let mut tables = EtsTables::new(
+            [0; PRIORITIES],
+            [0; PRIORITIES],
+            [TransmissionSelectionAlgorithm::StrictPriority; PRIORITIES],
+        );
+        for priority in 0..PRIORITIES {
+            let octet = bytes[priority / 2];
+            tables.priority_tc[priority] = if priority % 2 == 0 {
+                octet >> 4
+            } else {
+                octet & 0x0F
+            };
+        }
+        tables.bandwidth.copy_from_slice(&bytes[4..12]);
+        for tc in 0..PRIORITIES {
+            tables.tsa[tc] = bytes[12 + tc].into();
+        }
+        tables
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = self
+            .priority_tc
+            .chunks(2)
+            .map(|pair| (pair[0] & 0x0F) << 4 | (pair[1] & 0x0F))
+            .collect();
+        bytes.extend_from_slice(&self.bandwidth);
+        bytes.extend(self.tsa.iter().map(|&tsa| u8::from(tsa)));
+        bytes
+    }
+
+    fn json_members(&self) -> Vec<(&'static str, Json)> {
+        vec![
+            ("priority_tc", self.priority_tc.to_vec().into()),
+            ("bandwidth", self.bandwidth.to_vec().into()),
+            (
+                "tsa",
+                self.tsa
+                    .iter()
+                    .map(|tsa| tsa.to_string())
+                    .collect::<Vec<String>>()
+                    .into(),
+            ),
+        ]
+    }
+}
+
+/// Join the values with spaces.
+fn join<T: Display>(values: &[T]) -> String {
+    let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+    values.join(" ")
+}
+
+impl Display for EtsTables {
+    /// Write the tables, e.g. `TC map [0 0 0 1 0 0 0 0], bandwidth [50 50 0 0 0 0 0 0], TSA [ets ets ...]`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "TC map [{}], bandwidth [{}], TSA [{}]",
+            join(&self.priority_tc),
+            join(&self.bandwidth),
+            join(&self.tsa)
+        )
+    }
+}
+
+/// ETS Configuration TLV (subtype 9)
+///
+/// Advertises the ETS configuration of the sending port.
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               7               27
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
+/// |             |                 |               |               |W|C|Res|Max  |               |
+/// |     127     |       25        |   00-80-C2    |       9       |i|B|   | TCs |   ETS Tables  |
+/// |             |                 |               |               |l|S|   |     |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
+/// ```
+///
+/// A port sets the willing bit if it accepts the configuration of its peer. Max TCs is the number of traffic
+/// classes supported, a value of 0 indicates 8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EtsConfiguration {
+    /// Whether the port accepts the configuration of its peer
+    pub willing: bool,
+    /// Whether the credit-based shaper algorithm is supported
+    pub cbs: bool,
+    /// Number of traffic classes supported (1 - 8)
+    pub max_tcs: u8,
+    /// The ETS tables
+    pub tables: EtsTables,
+}
+
+impl EtsConfiguration {
+    /// Constructor
+    pub fn new(willing: bool, cbs: bool, max_tcs: u8, tables: EtsTables) -> EtsConfiguration {
+        EtsConfiguration {
+            willing,
+            cbs,
+            max_tcs,
+            tables,
+        }
+    }
+}
+
+impl Display for EtsConfiguration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}{} TCs, {}",
+            if self.willing { "willing, " } else { "" },
+            if self.cbs { "CBS, " } else { "" },
+            self.max_tcs,
+            self.tables
+        )
+    }
+}
+
+impl OrgTlvPayload for EtsConfiguration {
+    fn oui(&self) -> [u8; 3] {
+        IEEE_8021_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        0x09
+    }
+
+    fn name(&self) -> String {
+        String::from("ETS Configuration")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let flags = (self.willing as u8) << 7 | (self.cbs as u8) << 6 | (self.max_tcs & 0x07);
+        let mut value = vec![flags];
+        value.extend(self.tables.bytes());
+        value
+    }
+
+    fn to_json(&self) -> Json {
+        let mut members = vec![
+            ("willing", self.willing.into()),
+            ("cbs", self.cbs.into()),
+            ("max_tcs", self.max_tcs.into()),
+        ];
+        members.extend(self.tables.json_members());
+        Json::object(members)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for EtsConfiguration {
+    const KEYS: &'static [([u8; 3], u8)] = &[(IEEE_8021_OUI, 0x09)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        let length = 1 + EtsTables::LENGTH;
+        check_length(
+            TlvType::OrganizationallySpecific,
+            value.len(),
+            length,
+            length,
+        )?;
+
+        let max_tcs = match value[0] & 0x07 {
+            0 => 8,
+            max_tcs => max_tcs,
+        };
+        Ok(EtsConfiguration::new(
+            value[0] & 0x80 != 0,
+            value[0] & 0x40 != 0,
+            max_tcs,
+            EtsTables::from_bytes(&value[1..]),
+        ))
+    }
+}
+
+/// ETS Recommendation TLV (subtype 0x0A)
+///
+/// Advertises the ETS configuration the sending port recommends to its peer.
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               7               27
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
+/// |             |                 |               |               |               |               |
+/// |     127     |       25        |   00-80-C2    |     0x0A      |   Reserved    |   ETS Tables  |
+/// |             |                 |               |               |               |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EtsRecommendation {
+    /// The recommended ETS tables
+    pub tables: EtsTables,
+}
+
+impl EtsRecommendation {
+    /// Constructor
+    pub fn new(tables: EtsTables) -> EtsRecommendation {
+        EtsRecommendation { tables }
+    }
+}
+
+impl Display for EtsRecommendation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.tables)
+    }
+}
+
+impl OrgTlvPayload for EtsRecommendation {
+    fn oui(&self) -> [u8; 3] {
+        IEEE_8021_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        0x0A
+    }
+
+    fn name(&self) -> String {
+        String::from("ETS Recommendation")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let mut value = vec![0];
+        value.extend(self.tables.bytes());
+        value
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(self.tables.json_members())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for EtsRecommendation {
+    const KEYS: &'static [([u8; 3], u8)] = &[(IEEE_8021_OUI, 0x0A)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        let length = 1 + EtsTables::LENGTH;
+        check_length(
+            TlvType::OrganizationallySpecific,
+            value.len(),
+            length,
+            length,
+        )?;
+        Ok(EtsRecommendation::new(EtsTables::from_bytes(&value[1..])))
+    }
+}
+
+/// Priority-based Flow Control Configuration TLV (subtype 0x0B)
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               7               8
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |             |                 |               |               |W|M|Res| PFC |     PFC       |
+/// |     127     |        6        |   00-80-C2    |     0x0B      |i|B|   | cap |    Enable     |
+/// |             |                 |               |               |l|C|   |     |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// The MACsec bypass capability (MBC) bit is set if the port cannot bypass MACsec processing when MACsec is
+/// disabled. PFC cap is the number of priorities on which PFC can be enabled at the same time. Bit n of the enable
+/// bitmap enables PFC on priority n.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriorityFlowControl {
+    /// Whether the port accepts the configuration of its peer
+    pub willing: bool,
+    /// MACsec bypass capability
+    pub mbc: bool,
+    /// Number of priorities PFC can be enabled on at the same time (0 - 8)
+    pub capability: u8,
+    /// Priorities PFC is enabled on, by bit
+    pub enabled: u8,
+}
+
+impl PriorityFlowControl {
+    /// Constructor
+    pub fn new(willing: bool, mbc: bool, capability: u8, enabled: u8) -> PriorityFlowControl {
+        PriorityFlowControl {
+            willing,
+            mbc,
+            capability,
+            enabled,
+        }
+    }
+
+    /// Check whether PFC is enabled on `priority`.
+    pub fn is_enabled(&self, priority: u8) -> bool {
+        (priority as usize) < PRIORITIES && self.enabled & (1 << priority) != 0
+    }
+}
+
+impl Display for PriorityFlowControl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let enabled: Vec<u8> = (0..PRIORITIES as u8)
+            .filter(|&priority| self.is_enabled(priority))
+            .collect();
+        write!(
+            f,
+            "{}{}capability {}, enabled on [{}]",
+            if self.willing { "willing, " } else { "" },
+            if self.mbc { "MBC, " } else { "" },
+            self.capability,
+            join(&enabled)
+        )
+    }
+}
+
+impl OrgTlvPayload for PriorityFlowControl {
+    fn oui(&self) -> [u8; 3] {
+        IEEE_8021_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        0x0B
+    }
+
+    fn name(&self) -> String {
+        String::from("PFC Configuration")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let flags = (self.willing as u8) << 7 | (self.mbc as u8) << 6 | (self.capability & 0x0F);
+        vec![flags, self.enabled]
+    }
+
+    fn to_json(&self) -> Json {
+        let enabled: Vec<u8> = (0..PRIORITIES as u8)
+            .filter(|&priority| self.is_enabled(priority))
+            .collect();
+        Json::object(vec![
+            ("willing", self.willing.into()),
+            ("mbc", self.mbc.into()),
+            ("capability", self.capability.into()),
+            ("enabled", enabled.into()),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for PriorityFlowControl {
+    const KEYS: &'static [([u8; 3], u8)] = &[(IEEE_8021_OUI, 0x0B)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 2, 2)?;
+        Ok(PriorityFlowControl::new(
+            value[0] & 0x80 != 0,
+            value[0] & 0x40 != 0,
+            value[0] & 0x0F,
+            value[1],
+        ))
+    }
+}
+
+/// Protocol selector of an [ApplicationPriority] entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApplicationSelector {
+    /// The protocol is an Ethertype (1)
+    Ethertype,
+    /// The protocol is a well-known port over TCP or SCTP (2)
+    TcpSctp,
+    /// The protocol is a well-known port over UDP or DCCP (3)
+    UdpDccp,
+    /// The protocol is a well-known port over TCP, SCTP, UDP or DCCP (4)
+    TcpSctpUdpDccp,
+    /// The protocol is a DSCP value (5)
+    Dscp,
+    /// Reserved value
+    Reserved(u8),
+}
+
+impl From<u8> for ApplicationSelector {
+    fn from(value: u8) -> ApplicationSelector {
+        match value {
+            1 => ApplicationSelector::Ethertype,
+            2 => ApplicationSelector::TcpSctp,
+            3 => ApplicationSelector::UdpDccp,
+            4 => ApplicationSelector::TcpSctpUdpDccp,
+            5 => ApplicationSelector::Dscp,
+            value => ApplicationSelector::Reserved(value),
+        }
+    }
+}
+
+impl From<ApplicationSelector> for u8 {
+    fn from(selector: ApplicationSelector) -> u8 {
+        match selector {
+            ApplicationSelector::Ethertype => 1,
+            ApplicationSelector::TcpSctp => 2,
+            ApplicationSelector::UdpDccp => 3,
+            ApplicationSelector::TcpSctpUdpDccp => 4,
+            ApplicationSelector::Dscp => 5,
+            ApplicationSelector::Reserved(value) => value,
+        }
+    }
+}
+
+impl Display for ApplicationSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApplicationSelector::Ethertype => write!(f, "ethertype"),
+            ApplicationSelector::TcpSctp => write!(f, "tcp"),
+            ApplicationSelector::UdpDccp => write!(f, "udp"),
+            ApplicationSelector::TcpSctpUdpDccp => write!(f, "tcp+udp"),
+            ApplicationSelector::Dscp => write!(f, "dscp"),
+            ApplicationSelector::Reserved(value) => write!(f, "selector-{}", value),
+        }
+    }
+}
+
+/// Entry of the [ApplicationPriority] table
+///
+/// ```text
+///  0               1               3
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |Prio |Res| Sel |          Protocol ID          |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ApplicationPriorityEntry {
+    /// The priority assigned to the application (0 - 7)
+    pub priority: u8,
+    /// How to interpret the protocol ID
+    pub selector: ApplicationSelector,
+    /// The protocol ID, e.g. the Ethertype 0x8915 or the UDP port 4791 for RoCE
+    pub protocol: u16,
+}
+
+impl ApplicationPriorityEntry {
+    /// Constructor
+    pub fn new(
+        priority: u8,
+        selector: ApplicationSelector,
+        protocol: u16,
+    ) -> ApplicationPriorityEntry {
+        ApplicationPriorityEntry {
+            priority,
+            selector,
+            protocol,
+        }
+    }
+}
+
+impl Display for ApplicationPriorityEntry {
+    /// Write the entry, e.g. `udp 4791 -> 3` or `ethertype 0x8915 -> 3`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.selector {
+            ApplicationSelector::Ethertype => write!(
+                f,
+                "{} {:#06x} -> {}",
+                self.selector, self.protocol, self.priority
+            ),
+            _ => write!(
+                f,
+                "{} {} -> {}",
+                self.selector, self.protocol, self.priority
+            ),
+        }
+    }
+}
+
+/// Application Priority TLV (subtype 0x0C)
+///
+/// Advertises the priorities assigned to applications, identified by Ethertype, well-known port or DSCP value.
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               7
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+
+/// |             |                 |               |               |               |  Application  |
+/// |     127     |    5 + 3n       |   00-80-C2    |     0x0C      |   Reserved    |   Priority    |
+/// |             |                 |               |               |               |     Table     |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+
+/// ```
+///
+/// The table consists of n entries of 3 octets each, see [ApplicationPriorityEntry].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApplicationPriority {
+    /// The application priority table
+    pub entries: Vec<ApplicationPriorityEntry>,
+}
+
+impl ApplicationPriority {
+    /// Constructor
+    pub fn new(entries: Vec<ApplicationPriorityEntry>) -> ApplicationPriority {
+        ApplicationPriority { entries }
+    }
+}
+
+impl Display for ApplicationPriority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.entries.is_empty() {
+            return write!(f, "none");
+        }
+        let entries: Vec<String> = self.entries.iter().map(|entry| entry.to_string()).collect();
+        write!(f, "{}", entries.join(", "))
+    }
+}
+
+impl OrgTlvPayload for ApplicationPriority {
+    fn oui(&self) -> [u8; 3] {
+        IEEE_8021_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        0x0C
+    }
+
+    fn name(&self) -> String {
+        String::from("Application Priority")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let mut value = vec![0];
+        for entry in &self.entries {
+            value.push((entry.priority & 0x07) << 5 | (u8::from(entry.selector) & 0x07));
+            value.extend_from_slice(&entry.protocol.to_be_bytes());
+        }
+        value
+    }
+
+    fn to_json(&self) -> Json {
+        let entries: Vec<Json> = self
+            .entries
+            .iter()
+            .map(|entry| {
+                Json::object(vec![
+                    ("priority", entry.priority.into()),
+                    ("selector", entry.selector.to_string().into()),
+                    ("protocol", entry.protocol.into()),
+                ])
+            })
+            .collect();
+        Json::object(vec![("entries", Json::Array(entries))])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for ApplicationPriority {
+    const KEYS: &'static [([u8; 3], u8)] = &[(IEEE_8021_OUI, 0x0C)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 1, 507)?;
+        if !(value.len() - 1).is_multiple_of(3) {
+            return Err(TlvError::InvalidValue(format!(
+                "application priority table of {} octets, expected a multiple of 3",
+                value.len() - 1
+            )));
+        }
+
+        let entries = value[1..]
+            .chunks(3)
+            .map(|entry| {
+                ApplicationPriorityEntry::new(
+                    entry[0] >> 5,
+                    (entry[0] & 0x07).into(),
+                    u16::from_be_bytes([entry[1], entry[2]]),
+                )
+            })
+            .collect();
+        Ok(ApplicationPriority::new(entries))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+    use TransmissionSelectionAlgorithm::{Ets, StrictPriority};
+
+    const ETS_CONFIGURATION: &[u8] = b"\xfe\x19\x00\x80\xc2\x09\x83\
+        \x00\x01\x00\x00\
+        \x32\x32\x00\x00\x00\x00\x00\x00\
+        \x02\x02\x00\x00\x00\x00\x00\x00";
+
+    fn tables() -> EtsTables {
+        EtsTables::new(
+            [0, 0, 0, 1, 0, 0, 0, 0],
+            [50, 50, 0, 0, 0, 0, 0, 0],
+            [
+                Ets,
+                Ets,
+                StrictPriority,
+                StrictPriority,
+                StrictPriority,
+                StrictPriority,
+                StrictPriority,
+                StrictPriority,
+            ],
+        )
+    }
+
+    #[test]
+    fn test_ets_configuration_dump() {
+        let tlv = OrganizationallySpecificTLV::from_payload(&EtsConfiguration::new(
+            true,
+            false,
+            3,
+            tables(),
+        ));
+        assert_eq!(tlv.bytes(), ETS_CONFIGURATION.to_vec());
+    }
+
+    #[test]
+    fn test_ets_configuration_load() {
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(ETS_CONFIGURATION);
+        let ets = tlv.decode_as::<EtsConfiguration>().unwrap();
+        assert_eq!(ets, EtsConfiguration::new(true, false, 3, tables()));
+        assert_eq!(
+            format!("{}", ets),
+            "willing, 3 TCs, TC map [0 0 0 1 0 0 0 0], bandwidth [50 50 0 0 0 0 0 0], \
+             TSA [ets ets strict strict strict strict strict strict]"
+        );
+    }
+
+    #[test]
+    fn test_ets_configuration_max_tcs() {
+        let mut bytes = ETS_CONFIGURATION.to_vec();
+        bytes[6] = 0x40;
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(&bytes);
+        let ets = tlv.decode_as::<EtsConfiguration>().unwrap();
+        assert!(!ets.willing && ets.cbs);
+        assert_eq!(ets.max_tcs, 8);
+    }
+
+    #[test]
+    fn test_ets_recommendation() {
+        let mut bytes = ETS_CONFIGURATION.to_vec();
+        bytes[5] = 0x0A;
+        bytes[6] = 0x00;
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(&bytes);
+        assert_eq!(
+            tlv.decode_as::<EtsRecommendation>(),
+            Some(EtsRecommendation::new(tables()))
+        );
+        assert_eq!(
+            OrganizationallySpecificTLV::from_payload(&EtsRecommendation::new(tables())).bytes(),
+            bytes
+        );
+    }
+
+    #[test]
+    fn test_pfc() {
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(
+            b"\xfe\x06\x00\x80\xc2\x0b\x88\x08".as_ref(),
+        );
+        let pfc = tlv.decode_as::<PriorityFlowControl>().unwrap();
+        assert_eq!(pfc, PriorityFlowControl::new(true, false, 8, 0x08));
+        assert!(pfc.is_enabled(3) && !pfc.is_enabled(4));
+        assert_eq!(
+            format!("{}", tlv),
+            "OrganizationallySpecificTLV(\"0080C2\", 11, \
+             \"PFC Configuration: willing, capability 8, enabled on [3]\")"
+        );
+        assert_eq!(
+            OrganizationallySpecificTLV::from_payload(&pfc).bytes(),
+            tlv.bytes()
+        );
+    }
+
+    #[test]
+    fn test_application_priority() {
+        let bytes = b"\xfe\x0b\x00\x80\xc2\x0c\x00\x63\x12\xb7\x61\x89\x15";
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(bytes.as_ref());
+        let app = tlv.decode_as::<ApplicationPriority>().unwrap();
+        assert_eq!(
+            app.entries,
+            vec![
+                ApplicationPriorityEntry::new(3, ApplicationSelector::UdpDccp, 4791),
+                ApplicationPriorityEntry::new(3, ApplicationSelector::Ethertype, 0x8915),
+            ]
+        );
+        assert_eq!(format!("{}", app), "udp 4791 -> 3, ethertype 0x8915 -> 3");
+        assert_eq!(
+            format!("{}", app.to_json()),
+            "{\"entries\":[{\"priority\":3,\"selector\":\"udp\",\"protocol\":4791},\
+             {\"priority\":3,\"selector\":\"ethertype\",\"protocol\":35093}]}"
+        );
+        assert_eq!(
+            OrganizationallySpecificTLV::from_payload(&app).bytes(),
+            bytes.to_vec()
+        );
+    }
+
+    #[test]
+    fn test_application_priority_invalid() {
+        let tlv =
+            OrganizationallySpecificTLV::new(IEEE_8021_OUI.to_vec(), 0x0C, vec![0, 0x63, 0x12]);
+        assert!(tlv.decode_as::<ApplicationPriority>().is_none());
+    }
+}
@@ -0,0 +1,618 @@
+//! IEEE 802.1Qbg Edge Virtual Bridging TLVs
+//!
+//! Bridges and stations use the EVB TLV to negotiate reflective relay and the VSI discovery timers, and the CDCP TLV
+//! to set up S-channels on the link.
+
+use crate::json::Json;
+use crate::tlv::organizationallyspecific_tlv::ieee8021::IEEE_8021_OUI;
+use crate::tlv::organizationallyspecific_tlv::registry::{
+    OrgTlvDecode, OrgTlvPayload, OrgTlvRegistry,
+};
+use crate::tlv::{check_length, TlvError, TlvType};
+
+use std::any::Any;
+use std::fmt::Display;
+
+/// Maximum number of SCID/SVID pairs in a CDCP TLV
+pub const MAX_SCHANNELS: usize = 167;
+
+/// Register the EVB TLVs.
+pub(crate) fn register(registry: &mut OrgTlvRegistry) {
+    registry.register_type::<Evb>();
+    registry.register_type::<Cdcp>();
+}
+
+/// Role of the sender of an EVB TLV
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvbMode {
+    /// EVB is not supported (0)
+    NotSupported,
+    /// EVB Bridge (1)
+    Bridge,
+    /// EVB Station (2)
+    Station,
+}
+
+impl Display for EvbMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvbMode::NotSupported => write!(f, "not supported"),
+            EvbMode::Bridge => write!(f, "bridge"),
+            EvbMode::Station => write!(f, "station"),
+        }
+    }
+}
+
+/// EVB TLV (subtype 0x0D)
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               7               8
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |             |                 |               |               |    Bridge     |    Station    |
+/// |     127     |        9        |   00-80-C2    |     0x0D      |    Status     |    Status     |
+/// |             |                 |               |               |               |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+///
+///  8               9               10              11
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |  R  |   RTE   |Mod|R|   RWD   |Res|R|   RKA   |
+/// |     |         |e  |O|         |   |O|         |
+/// |     |         |   |L|         |   |L|         |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// # Status:
+///
+/// |  Bit  |     Bridge Status                |     Station Status                  |
+/// | ----- | -------------------------------- | ----------------------------------- |
+/// |  0-1  | RRCTR (bit 0), RRCAP (bit 1)     | RRSTAT (reflective relay status)    |
+/// |   2   | BGID (bridge group ID support)   | RRREQ (reflective relay request)    |
+/// |   3   | reserved                         | SGID (station group ID support)     |
+///
+/// R is the maximum number of ECP retries and RTE the exponent of the ECP retransmission timer. RWD and RKA are the
+/// exponents of the VDP resource wait delay and keep alive timers, the ROL bits indicate that the remote value is
+/// in use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Evb {
+    /// Bridge supports VSI group IDs
+    pub bgid: bool,
+    /// Bridge supports reflective relay
+    pub rrcap: bool,
+    /// Bridge has enabled reflective relay
+    pub rrctr: bool,
+    /// Station supports VSI group IDs
+    pub sgid: bool,
+    /// Station requests reflective relay
+    pub rrreq: bool,
+    /// Reflective relay status of the station (0 - 3)
+    pub rrstat: u8,
+    /// Maximum number of ECP retries (0 - 7)
+    pub r: u8,
+    /// ECP retransmission timer exponent (0 - 31)
+    pub rte: u8,
+    /// Role of the sender
+    pub mode: EvbMode,
+    /// The remote resource wait delay is in use
+    pub rol_rwd: bool,
+    /// Resource wait delay exponent (0 - 31)
+    pub rwd: u8,
+    /// The remote keep alive timer is in use
+    pub rol_rka: bool,
+    /// Keep alive timer exponent (0 - 31)
+    pub rka: u8,
+}
+
+impl Evb {
+    /// Constructor
+    ///
+    /// The status and ROL bits start out cleared.
+    pub fn new(mode: EvbMode, rrstat: u8, r: u8, rte: u8, rwd: u8, rka: u8) -> Evb {
+        Evb {
+            bgid: false,
+            rrcap: false,
+            rrctr: false,
+            sgid: false,
+            rrreq: false,
+            rrstat,
+            r,
+            rte,
+            mode,
+            rol_rwd: false,
+            rwd,
+            rol_rka: false,
+            rka,
+        }
+    }
+
+    /// Validating constructor
+    ///
+    /// Fails if RRSTAT exceeds 2 bits, R exceeds 3 bits or one of the timer exponents exceeds 5 bits.
+    pub fn try_new(
+        mode: EvbMode,
+        rrstat: u8,
+        r: u8,
+        rte: u8,
+        rwd: u8,
+        rka: u8,
+    ) -> Result<Evb, TlvError> {
+        if rrstat > 0x03 {
+            return Err(TlvError::InvalidValue(format!("RRSTAT {}", rrstat)));
+        }
+        if r > 0x07 {
+            return Err(TlvError::InvalidValue(format!("ECP retries {}", r)));
+        }
+        if let Some((name, exponent)) = [("RTE", rte), ("RWD", rwd), ("RKA", rka)]
+            .iter()
+            .find(|(_, exponent)| *exponent > 0x1F)
+        {
+            return Err(TlvError::InvalidValue(format!(
+                "{} exponent {}",
+                name, exponent
+            )));
+        }
+        Ok(Evb::new(mode, rrstat, r, rte, rwd, rka))
+    }
+}
+
+impl Display for Evb {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let flags = |flags: &[(bool, &str)]| -> String {
+            let set: Vec<&str> = flags
+                .iter()
+                .filter(|(set, _)| *set)
+                .map(|(_, name)| *name)
+                .collect();
+            set.join(" ")
+        };
+
+        write!(
+            f,
+            "{}, bridge [{}], station [{}], RRSTAT {}, R {}, RTE {}, RWD {}{}, RKA {}{}",
+            self.mode,
+            flags(&[
+                (self.bgid, "BGID"),
+                (self.rrcap, "RRCAP"),
+                (self.rrctr, "RRCTR")
+            ]),
+            flags(&[(self.sgid, "SGID"), (self.rrreq, "RRREQ")]),
+            self.rrstat,
+            self.r,
+            self.rte,
+            self.rwd,
+            if self.rol_rwd { " (remote)" } else { "" },
+            self.rka,
+            if self.rol_rka { " (remote)" } else { "" }
+        )
+    }
+}
+
+impl OrgTlvPayload for Evb {
+    fn oui(&self) -> [u8; 3] {
+        IEEE_8021_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        0x0D
+    }
+
+    fn name(&self) -> String {
+        String::from("EVB")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let mode = match self.mode {
+            EvbMode::NotSupported => 0,
+            EvbMode::Bridge => 1,
+            EvbMode::Station => 2,
+        };
+        vec![
+            (self.bgid as u8) << 2 | (self.rrcap as u8) << 1 | self.rrctr as u8,
+            (self.sgid as u8) << 3 | (self.rrreq as u8) << 2 | (self.rrstat & 0x03),
+            (self.r & 0x07) << 5 | (self.rte & 0x1F),
+            mode << 6 | (self.rol_rwd as u8) << 5 | (self.rwd & 0x1F),
+            (self.rol_rka as u8) << 5 | (self.rka & 0x1F),
+        ]
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("mode", self.mode.to_string().into()),
+            ("bgid", self.bgid.into()),
+            ("rrcap", self.rrcap.into()),
+            ("rrctr", self.rrctr.into()),
+            ("sgid", self.sgid.into()),
+            ("rrreq", self.rrreq.into()),
+            ("rrstat", self.rrstat.into()),
+            ("r", self.r.into()),
+            ("rte", self.rte.into()),
+            ("rol_rwd", self.rol_rwd.into()),
+            ("rwd", self.rwd.into()),
+            ("rol_rka", self.rol_rka.into()),
+            ("rka", self.rka.into()),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for Evb {
+    const KEYS: &'static [([u8; 3], u8)] = &[(IEEE_8021_OUI, 0x0D)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 5, 5)?;
+
+        let mode = match value[3] >> 6 {
+            0 => EvbMode::NotSupported,
+            1 => EvbMode::Bridge,
+            2 => EvbMode::Station,
+            mode => {
+                return Err(TlvError::InvalidValue(format!(
+                    "reserved EVB mode {}",
+                    mode
+                )))
+            }
+        };
+
+        Ok(Evb {
+            bgid: value[0] & 0x04 != 0,
+            rrcap: value[0] & 0x02 != 0,
+            rrctr: value[0] & 0x01 != 0,
+            sgid: value[1] & 0x08 != 0,
+            rrreq: value[1] & 0x04 != 0,
+            rrstat: value[1] & 0x03,
+            r: value[2] >> 5,
+            rte: value[2] & 0x1F,
+            mode,
+            rol_rwd: value[3] & 0x20 != 0,
+            rwd: value[3] & 0x1F,
+            rol_rka: value[4] & 0x20 != 0,
+            rka: value[4] & 0x1F,
+        })
+    }
+}
+
+/// Role of the sender of a CDCP TLV
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CdcpRole {
+    /// S-channel capable bridge (0)
+    Bridge,
+    /// Station (1)
+    Station,
+}
+
+impl Display for CdcpRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CdcpRole::Bridge => write!(f, "bridge"),
+            CdcpRole::Station => write!(f, "station"),
+        }
+    }
+}
+
+/// S-channel, identified by its S-channel ID (SCID) and the S-VLAN ID (SVID) carrying it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SChannel {
+    /// S-channel ID (1 - 4095)
+    pub scid: u16,
+    /// S-VLAN ID, 0 if requested by a station and not assigned yet
+    pub svid: u16,
+}
+
+impl SChannel {
+    /// Constructor
+    pub fn new(scid: u16, svid: u16) -> SChannel {
+        SChannel { scid, svid }
+    }
+}
+
+/// CDCP TLV (S-Channel Discovery and Configuration Protocol, subtype 0x0E)
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               10
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-...
+/// |             |                 |               |               |R|Res|S|  Res  |ChnCap|  SCID/SVID
+/// |     127     |    8 + 3n       |   00-80-C2    |     0x0E      |o|   |C|       |      |    pairs
+/// |             |                 |               |               |l|   |o|       |      |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-...
+/// ```
+///
+/// The first four octets hold the role bit, the SComp bit (an S-VLAN component is present) and the 12 bit number of
+/// S-channels supported (ChnCap). They are followed by n pairs of 12 bit SCID and 12 bit SVID, the first of which
+/// describes the default S-channel (SCID 1).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cdcp {
+    /// Role of the sender
+    pub role: CdcpRole,
+    /// Whether an S-VLAN component is present
+    pub scomp: bool,
+    /// Number of S-channels supported (0 - 4095)
+    pub channel_capacity: u16,
+    /// Configured or requested S-channels
+    pub channels: Vec<SChannel>,
+}
+
+impl Cdcp {
+    /// Constructor
+    pub fn new(
+        role: CdcpRole,
+        scomp: bool,
+        channel_capacity: u16,
+        channels: Vec<SChannel>,
+    ) -> Cdcp {
+        Cdcp {
+            role,
+            scomp,
+            channel_capacity,
+            channels,
+        }
+    }
+
+    /// Validating constructor
+    ///
+    /// Fails if the TLV holds more than [MAX_SCHANNELS] S-channels or the values exceed their 12 bit fields.
+    pub fn try_new(
+        role: CdcpRole,
+        scomp: bool,
+        channel_capacity: u16,
+        channels: Vec<SChannel>,
+    ) -> Result<Cdcp, TlvError> {
+        check_length(
+            TlvType::OrganizationallySpecific,
+            4 + 3 * channels.len(),
+            4,
+            4 + 3 * MAX_SCHANNELS,
+        )?;
+        if channel_capacity > 0x0FFF {
+            return Err(TlvError::InvalidValue(format!(
+                "S-channel capacity {}",
+                channel_capacity
+            )));
+        }
+        if let Some(channel) = channels
+            .iter()
+            .find(|channel| channel.scid == 0 || channel.scid > 0x0FFF || channel.svid > 0x0FFF)
+        {
+            return Err(TlvError::InvalidValue(format!(
+                "S-channel SCID {} SVID {}",
+                channel.scid, channel.svid
+            )));
+        }
+        Ok(Cdcp::new(role, scomp, channel_capacity, channels))
+    }
+}
+
+impl Display for Cdcp {
+    /// Write the TLV, e.g. `station, 4 channels supported, SCID/SVID [1/1 2/0]`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let channels: Vec<String> = self
+            .channels
+            .iter()
+            .map(|channel| format!("{}/{}", channel.scid, channel.svid))
+            .collect();
+        write!(
+            f,
+            "{}{}, {} channels supported, SCID/SVID [{}]",
+            self.role,
+            if self.scomp {
+                " with S-VLAN component"
+            } else {
+                ""
+            },
+            self.channel_capacity,
+            channels.join(" ")
+        )
+    }
+}
+
+impl OrgTlvPayload for Cdcp {
+    fn oui(&self) -> [u8; 3] {
+        IEEE_8021_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        0x0E
+    }
+
+    fn name(&self) -> String {
+        String::from("CDCP")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let header = ((self.role == CdcpRole::Station) as u32) << 31
+            | (self.scomp as u32) << 27
+            | (self.channel_capacity as u32 & 0x0FFF);
+        let mut value = header.to_be_bytes().to_vec();
+        for channel in &self.channels {
+            let pair = (channel.scid as u32 & 0x0FFF) << 12 | (channel.svid as u32 & 0x0FFF);
+            value.extend_from_slice(&pair.to_be_bytes()[1..]);
+        }
+        value
+    }
+
+    fn to_json(&self) -> Json {
+        let channels: Vec<Json> = self
+            .channels
+            .iter()
+            .map(|channel| {
+                Json::object(vec![
+                    ("scid", channel.scid.into()),
+                    ("svid", channel.svid.into()),
+                ])
+            })
+            .collect();
+        Json::object(vec![
+            ("role", self.role.to_string().into()),
+            ("scomp", self.scomp.into()),
+            ("channel_capacity", self.channel_capacity.into()),
+            ("channels", Json::Array(channels)),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for Cdcp {
+    const KEYS: &'static [([u8; 3], u8)] = &[(IEEE_8021_OUI, 0x0E)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(
+            TlvType::OrganizationallySpecific,
+            value.len(),
+            4,
+            4 + 3 * MAX_SCHANNELS,
+        )?;
+        if !(value.len() - 4).is_multiple_of(3) {
+            return Err(TlvError::InvalidValue(format!(
+                "SCID/SVID pairs of {} octets, expected a multiple of 3",
+                value.len() - 4
+            )));
+        }
+
+        let header = u32::from_be_bytes([value[0], value[1], value[2], value[3]]);
+        let role = if header & 0x8000_0000 != 0 {
+            CdcpRole::Station
+        } else {
+            CdcpRole::Bridge
+        };
+        let channels = value[4..]
+            .chunks(3)
+            .map(|pair| {
+                let pair = u32::from_be_bytes([0, pair[0], pair[1], pair[2]]);
+                SChannel::new((pair >> 12) as u16, (pair & 0x0FFF) as u16)
+            })
+            .collect();
+
+        Cdcp::try_new(
+            role,
+            header & 0x0800_0000 != 0,
+            (header & 0x0FFF) as u16,
+            channels,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+
+    const EVB: &[u8] = b"\xfe\x09\x00\x80\xc2\x0d\x07\x00\x74\x54\x34";
+
+    fn evb() -> Evb {
+        Evb {
+            bgid: true,
+            rrcap: true,
+            rrctr: true,
+            rol_rka: true,
+            ..Evb::new(EvbMode::Bridge, 0, 3, 20, 20, 20)
+        }
+    }
+
+    #[test]
+    fn test_evb_dump() {
+        assert_eq!(
+            OrganizationallySpecificTLV::from_payload(&evb()).bytes(),
+            EVB.to_vec()
+        );
+    }
+
+    #[test]
+    fn test_evb_load() {
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(EVB);
+        let evb = tlv.decode_as::<Evb>().unwrap();
+        assert_eq!(evb, self::evb());
+        assert_eq!(
+            format!("{}", evb),
+            "bridge, bridge [BGID RRCAP RRCTR], station [], RRSTAT 0, R 3, RTE 20, RWD 20, RKA 20 (remote)"
+        );
+    }
+
+    #[test]
+    fn test_evb_invalid() {
+        // Reserved mode
+        let tlv = OrganizationallySpecificTLV::new(
+            IEEE_8021_OUI.to_vec(),
+            0x0D,
+            vec![0x07, 0x00, 0x74, 0xd4, 0x34],
+        );
+        assert!(tlv.decode().unwrap().is_err());
+
+        let tlv = OrganizationallySpecificTLV::new(IEEE_8021_OUI.to_vec(), 0x0D, vec![0x07]);
+        assert!(tlv.decode_as::<Evb>().is_none());
+    }
+
+    #[test]
+    fn test_evb_try_new() {
+        assert_eq!(
+            Evb::try_new(EvbMode::Station, 3, 7, 31, 31, 31),
+            Ok(Evb::new(EvbMode::Station, 3, 7, 31, 31, 31))
+        );
+        assert_eq!(
+            Evb::try_new(EvbMode::Station, 4, 0, 0, 0, 0),
+            Err(TlvError::InvalidValue(String::from("RRSTAT 4")))
+        );
+        assert_eq!(
+            Evb::try_new(EvbMode::Station, 0, 8, 0, 0, 0),
+            Err(TlvError::InvalidValue(String::from("ECP retries 8")))
+        );
+        assert_eq!(
+            Evb::try_new(EvbMode::Bridge, 0, 0, 32, 0, 0),
+            Err(TlvError::InvalidValue(String::from("RTE exponent 32")))
+        );
+        assert!(Evb::try_new(EvbMode::Bridge, 0, 0, 0, 32, 0).is_err());
+        assert!(Evb::try_new(EvbMode::Bridge, 0, 0, 0, 0, 32).is_err());
+    }
+
+    #[test]
+    fn test_cdcp() {
+        let bytes = b"\xfe\x0e\x00\x80\xc2\x0e\x80\x00\x00\x04\x00\x10\x01\x00\x20\x00";
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(bytes.as_ref());
+        let cdcp = tlv.decode_as::<Cdcp>().unwrap();
+        assert_eq!(
+            cdcp,
+            Cdcp::new(
+                CdcpRole::Station,
+                false,
+                4,
+                vec![SChannel::new(1, 1), SChannel::new(2, 0)]
+            )
+        );
+        assert_eq!(
+            format!("{}", cdcp),
+            "station, 4 channels supported, SCID/SVID [1/1 2/0]"
+        );
+        assert_eq!(
+            OrganizationallySpecificTLV::from_payload(&cdcp).bytes(),
+            bytes.to_vec()
+        );
+    }
+
+    #[test]
+    fn test_cdcp_invalid() {
+        assert!(Cdcp::try_new(CdcpRole::Bridge, true, 0x1000, vec![]).is_err());
+        assert!(Cdcp::try_new(CdcpRole::Bridge, true, 4, vec![SChannel::new(0, 1)]).is_err());
+        assert!(Cdcp::try_new(
+            CdcpRole::Bridge,
+            true,
+            4,
+            vec![SChannel::new(1, 1); MAX_SCHANNELS + 1]
+        )
+        .is_err());
+
+        // Truncated SCID/SVID pair
+        let tlv = OrganizationallySpecificTLV::new(
+            IEEE_8021_OUI.to_vec(),
+            0x0E,
+            vec![0x80, 0x00, 0x00, 0x04, 0x00, 0x10],
+        );
+        assert!(tlv.decode().unwrap().is_err());
+    }
+}
@@ -0,0 +1,739 @@
+//! IEEE 802.3 Organizationally Specific TLVs (OUI 00-12-0F)
+
+use crate::json::Json;
+use crate::tlv::organizationallyspecific_tlv::registry::{
+    OrgTlvDecode, OrgTlvPayload, OrgTlvRegistry,
+};
+use crate::tlv::{check_length, TlvError, TlvType};
+
+use std::any::Any;
+use std::fmt::Display;
+
+pub mod power;
+
+/// OUI of the IEEE 802.3 working group
+pub const IEEE_8023_OUI: [u8; 3] = [0x00, 0x12, 0x0F];
+
+/// Register the IEEE 802.3 TLVs.
+///
+/// The deprecated 802.3 Link Aggregation TLV (subtype 3) is decoded together with its 802.1 successor, see
+/// [LinkAggregation](crate::tlv::organizationallyspecific_tlv::ieee8021::LinkAggregation).
+pub(crate) fn register(registry: &mut OrgTlvRegistry) {
+    registry.register_organization(IEEE_8023_OUI, "IEEE 802.3");
+    registry.register_type::<MacPhyConfig>();
+    registry.register_type::<MaxFrameSize>();
+    registry.register_type::<EnergyEfficientEthernet>();
+    registry.register_type::<AdditionalEthernetCapabilities>();
+    power::register(registry);
+}
+
+/// Names of the auto-negotiation capabilities (RFC 3636 ifMauAutoNegCapAdvertisedBits), most significant bit first
+const AUTONEG_CAPABILITIES: [&str; 16] = [
+    "other",
+    "10BASE-T",
+    "10BASE-T FD",
+    "100BASE-T4",
+    "100BASE-TX",
+    "100BASE-TX FD",
+    "100BASE-T2",
+    "100BASE-T2 FD",
+    "FDX PAUSE",
+    "FDX APAUSE",
+    "FDX SPAUSE",
+    "FDX BPAUSE",
+    "1000BASE-X",
+    "1000BASE-X FD",
+    "1000BASE-T",
+    "1000BASE-T FD",
+];
+
+/// Names and speeds (in Mb/s) of the MAU types defined by RFC 4836, by type number starting at 1
+const MAU_TYPES: [(&str, u32); 69] = [
+    ("AUI", 10),
+    ("10BASE-5", 10),
+    ("FOIRL", 10),
+    ("10BASE-2", 10),
+    ("10BASE-T", 10),
+    ("10BASE-FP", 10),
+    ("10BASE-FB", 10),
+    ("10BASE-FL", 10),
+    ("10BROAD36", 10),
+    ("10BASE-T half duplex", 10),
+    ("10BASE-T full duplex", 10),
+    ("10BASE-FL half duplex", 10),
+    ("10BASE-FL full duplex", 10),
+    ("100BASE-T4", 100),
+    ("100BASE-TX half duplex", 100),
+    ("100BASE-TX full duplex", 100),
+    ("100BASE-FX half duplex", 100),
+    ("100BASE-FX full duplex", 100),
+    ("100BASE-T2 half duplex", 100),
+    ("100BASE-T2 full duplex", 100),
+    ("1000BASE-X half duplex", 1000),
+    ("1000BASE-X full duplex", 1000),
+    ("1000BASE-LX half duplex", 1000),
+    ("1000BASE-LX full duplex", 1000),
+    ("1000BASE-SX half duplex", 1000),
+    ("1000BASE-SX full duplex", 1000),
+    ("1000BASE-CX half duplex", 1000),
+    ("1000BASE-CX full duplex", 1000),
+    ("1000BASE-T half duplex", 1000),
+    ("1000BASE-T full duplex", 1000),
+    ("10GBASE-X", 10000),
+    ("10GBASE-LX4", 10000),
+    ("10GBASE-R", 10000),
+    ("10GBASE-ER", 10000),
+    ("10GBASE-LR", 10000),
+    ("10GBASE-SR", 10000),
+    ("10GBASE-W", 10000),
+    ("10GBASE-EW", 10000),
+    ("10GBASE-LW", 10000),
+    ("10GBASE-SW", 10000),
+    ("10GBASE-CX4", 10000),
+    ("2BASE-TL", 2),
+    ("10PASS-TS", 10),
+    ("100BASE-BX10D", 100),
+    ("100BASE-BX10U", 100),
+    ("100BASE-LX10", 100),
+    ("1000BASE-BX10D", 1000),
+    ("1000BASE-BX10U", 1000),
+    ("1000BASE-LX10", 1000),
+    ("1000BASE-PX10D", 1000),
+    ("1000BASE-PX10U", 1000),
+    ("1000BASE-PX20D", 1000),
+    ("1000BASE-PX20U", 1000),
+    ("10GBASE-T", 10000),
+    ("10GBASE-LRM", 10000),
+    ("1000BASE-KX", 1000),
+    ("10GBASE-KX4", 10000),
+    ("10GBASE-KR", 10000),
+    ("10G1GBASE-PRX-D1", 10000),
+    ("10G1GBASE-PRX-D2", 10000),
+    ("10G1GBASE-PRX-D3", 10000),
+    ("10G1GBASE-PRX-U1", 10000),
+    ("10G1GBASE-PRX-U2", 10000),
+    ("10G1GBASE-PRX-U3", 10000),
+    ("10GBASE-PR-D1", 10000),
+    ("10GBASE-PR-D2", 10000),
+    ("10GBASE-PR-D3", 10000),
+    ("10GBASE-PR-U1", 10000),
+    ("10GBASE-PR-U3", 10000),
+];
+
+/// Duplex mode of a MAU
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Duplex {
+    Half,
+    Full,
+}
+
+/// Operational MAU type, as defined by RFC 4836 (dot3MauType)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MauType(pub u16);
+
+impl MauType {
+    /// Get the RFC 4836 name of the MAU type, e.g. `1000BASE-T full duplex`.
+    pub fn name(&self) -> Option<&'static str> {
+        self.entry().map(|(name, _)| name)
+    }
+
+    /// Get the speed of the MAU type in Mb/s.
+    pub fn speed(&self) -> Option<u32> {
+        self.entry().map(|(_, speed)| speed)
+    }
+
+    /// Get the duplex mode of the MAU type.
+    ///
+    /// MAU types from 10GBASE-X (31) on only operate in full duplex. `None` if the duplex mode is unknown.
+    pub fn duplex(&self) -> Option<Duplex> {
+        let name = self.name()?;
+        if name.ends_with("half duplex") {
+            Some(Duplex::Half)
+        } else if name.ends_with("full duplex") || self.0 >= 31 {
+            Some(Duplex::Full)
+        } else {
+            None
+        }
+    }
+
+    fn entry(&self) -> Option<(&'static str, u32)> {
+        (self.0 as usize)
+            .checked_sub(1)
+            .and_then(|index| MAU_TYPES.get(index))
+            .copied()
+    }
+}
+
+impl Display for MauType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{}", name),
+            None if self.0 == 0 => write!(f, "unknown"),
+            None => write!(f, "MAU type {}", self.0),
+        }
+    }
+}
+
+/// MAC/PHY Configuration/Status TLV (subtype 1)
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               7               9               11
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |             |                 |               |               |   Auto-neg    |      PMD      |  Operational  |
+/// |     127     |        9        |   00-12-0F    |       1       |    Support/   |   Auto-neg    |   MAU Type    |
+/// |             |                 |               |               |    Status     |  Capability   |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// Bit 0 of the auto-negotiation octet indicates support, bit 1 whether auto-negotiation is enabled. The PMD
+/// capability bitmap lists the advertised capabilities (RFC 3636), the most significant bit being `other`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MacPhyConfig {
+    /// Auto-negotiation is supported
+    pub autoneg_supported: bool,
+    /// Auto-negotiation is enabled
+    pub autoneg_enabled: bool,
+    /// Advertised auto-negotiation capabilities
+    pub advertised: u16,
+    /// Operational MAU type
+    pub mau_type: MauType,
+}
+
+impl MacPhyConfig {
+    /// Constructor
+    pub fn new(
+        autoneg_supported: bool,
+        autoneg_enabled: bool,
+        advertised: u16,
+        mau_type: u16,
+    ) -> MacPhyConfig {
+        MacPhyConfig {
+            autoneg_supported,
+            autoneg_enabled,
+            advertised,
+            mau_type: MauType(mau_type),
+        }
+    }
+
+    /// Get the names of the advertised auto-negotiation capabilities.
+    pub fn advertised_capabilities(&self) -> Vec<&'static str> {
+        AUTONEG_CAPABILITIES
+            .iter()
+            .enumerate()
+            .filter(|(bit, _)| self.advertised & (0x8000 >> bit) != 0)
+            .map(|(_, name)| *name)
+            .collect()
+    }
+}
+
+impl Display for MacPhyConfig {
+    /// Write the TLV, e.g. `autoneg supported, enabled [1000BASE-T FD], MAU 1000BASE-T full duplex`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.autoneg_supported {
+            write!(
+                f,
+                "autoneg supported, {} [{}]",
+                if self.autoneg_enabled {
+                    "enabled"
+                } else {
+                    "disabled"
+                },
+                self.advertised_capabilities().join(", ")
+            )?;
+        } else {
+            write!(f, "autoneg not supported")?;
+        }
+        write!(f, ", MAU {}", self.mau_type)
+    }
+}
+
+impl OrgTlvPayload for MacPhyConfig {
+    fn oui(&self) -> [u8; 3] {
+        IEEE_8023_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        1
+    }
+
+    fn name(&self) -> String {
+        String::from("MAC/PHY Configuration/Status")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let mut value = vec![(self.autoneg_supported as u8) | (self.autoneg_enabled as u8) << 1];
+        value.extend_from_slice(&self.advertised.to_be_bytes());
+        value.extend_from_slice(&self.mau_type.0.to_be_bytes());
+        value
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("autoneg_supported", self.autoneg_supported.into()),
+            ("autoneg_enabled", self.autoneg_enabled.into()),
+            ("advertised", self.advertised_capabilities().into()),
+            ("mau_type", self.mau_type.0.into()),
+            ("mau", self.mau_type.to_string().into()),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for MacPhyConfig {
+    const KEYS: &'static [([u8; 3], u8)] = &[(IEEE_8023_OUI, 1)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 5, 5)?;
+        Ok(MacPhyConfig::new(
+            value[0] & 0x01 != 0,
+            value[0] & 0x02 != 0,
+            u16::from_be_bytes([value[1], value[2]]),
+            u16::from_be_bytes([value[3], value[4]]),
+        ))
+    }
+}
+
+/// Maximum Frame Size TLV (subtype 4)
+///
+/// Advertises the maximum frame size the MAC and PHY of the port support, in octets.
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               8
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |             |                 |               |               |    Maximum    |
+/// |     127     |        6        |   00-12-0F    |       4       |  Frame Size   |
+/// |             |                 |               |               |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaxFrameSize {
+    /// Maximum frame size in octets
+    pub size: u16,
+}
+
+impl MaxFrameSize {
+    /// Constructor
+    pub fn new(size: u16) -> MaxFrameSize {
+        MaxFrameSize { size }
+    }
+}
+
+impl Display for MaxFrameSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.size)
+    }
+}
+
+impl OrgTlvPayload for MaxFrameSize {
+    fn oui(&self) -> [u8; 3] {
+        IEEE_8023_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        4
+    }
+
+    fn name(&self) -> String {
+        String::from("Maximum Frame Size")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        self.size.to_be_bytes().to_vec()
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![("size", self.size.into())])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for MaxFrameSize {
+    const KEYS: &'static [([u8; 3], u8)] = &[(IEEE_8023_OUI, 4)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 2, 2)?;
+        Ok(MaxFrameSize::new(u16::from_be_bytes([value[0], value[1]])))
+    }
+}
+
+/// Energy Efficient Ethernet TLV (subtype 5)
+///
+/// Advertises the wake time (Tw) values, in microseconds, the port uses to negotiate low power idle with the link
+/// partner.
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               8               10              12              14              16
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |             |                 |               |               |   Transmit    |    Receive    |   Fallback    | Echo Transmit | Echo Receive  |
+/// |     127     |       14        |   00-12-0F    |       5       |      Tw       |      Tw       |      Tw       |      Tw       |      Tw       |
+/// |             |                 |               |               |               |               |               |               |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// The echo values reflect the remote system's transmit and receive Tw values as last received by the sender.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnergyEfficientEthernet {
+    /// Wake time the local system can support when transmitting
+    pub transmit_tw: u16,
+    /// Wake time the local system requests from the link partner
+    pub receive_tw: u16,
+    /// Fallback receive wake time
+    pub fallback_tw: u16,
+    /// Echo of the link partner's transmit wake time
+    pub echo_transmit_tw: u16,
+    /// Echo of the link partner's receive wake time
+    pub echo_receive_tw: u16,
+}
+
+impl EnergyEfficientEthernet {
+    /// Constructor
+    pub fn new(
+        transmit_tw: u16,
+        receive_tw: u16,
+        fallback_tw: u16,
+        echo_transmit_tw: u16,
+        echo_receive_tw: u16,
+    ) -> EnergyEfficientEthernet {
+        EnergyEfficientEthernet {
+            transmit_tw,
+            receive_tw,
+            fallback_tw,
+            echo_transmit_tw,
+            echo_receive_tw,
+        }
+    }
+}
+
+impl Display for EnergyEfficientEthernet {
+    /// Write the TLV, e.g. `Tx 16us, Rx 16us, fallback 16us [echo Tx 16us, Rx 16us]`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Tx {}us, Rx {}us, fallback {}us [echo Tx {}us, Rx {}us]",
+            self.transmit_tw,
+            self.receive_tw,
+            self.fallback_tw,
+            self.echo_transmit_tw,
+            self.echo_receive_tw
+        )
+    }
+}
+
+impl OrgTlvPayload for EnergyEfficientEthernet {
+    fn oui(&self) -> [u8; 3] {
+        IEEE_8023_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        5
+    }
+
+    fn name(&self) -> String {
+        String::from("EEE")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        [
+            self.transmit_tw,
+            self.receive_tw,
+            self.fallback_tw,
+            self.echo_transmit_tw,
+            self.echo_receive_tw,
+        ]
+        .iter()
+        .flat_map(|tw| tw.to_be_bytes())
+        .collect()
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("transmit_tw", self.transmit_tw.into()),
+            ("receive_tw", self.receive_tw.into()),
+            ("fallback_tw", self.fallback_tw.into()),
+            ("echo_transmit_tw", self.echo_transmit_tw.into()),
+            ("echo_receive_tw", self.echo_receive_tw.into()),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for EnergyEfficientEthernet {
+    const KEYS: &'static [([u8; 3], u8)] = &[(IEEE_8023_OUI, 5)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 10, 10)?;
+        let tw = |index: usize| u16::from_be_bytes([value[index], value[index + 1]]);
+        Ok(EnergyEfficientEthernet::new(
+            tw(0),
+            tw(2),
+            tw(4),
+            tw(6),
+            tw(8),
+        ))
+    }
+}
+
+/// Additional Ethernet Capabilities TLV (subtype 7)
+///
+/// Advertises support for frame preemption (IEEE 802.3br).
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               8
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |             |                 |               |               |  Additional   |
+/// |     127     |        6        |   00-12-0F    |       7       |   Ethernet    |
+/// |             |                 |               |               | Capabilities  |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// | Bit  | Function                       |
+/// |------|--------------------------------|
+/// | 0    | Preemption supported           |
+/// | 1    | Preemption enabled             |
+/// | 2    | Preemption active              |
+/// | 3-4  | Additional fragment size       |
+/// | 5-15 | Reserved                       |
+///
+/// The minimum size of a non-final fragment is 64 × (1 + additional fragment size) octets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdditionalEthernetCapabilities {
+    /// Preemption is supported
+    pub preemption_supported: bool,
+    /// Preemption is enabled
+    pub preemption_enabled: bool,
+    /// Preemption is active on the link
+    pub preemption_active: bool,
+    /// Additional fragment size (0-3)
+    pub additional_fragment_size: u8,
+}
+
+impl AdditionalEthernetCapabilities {
+    /// Constructor
+    pub fn new(
+        preemption_supported: bool,
+        preemption_enabled: bool,
+        preemption_active: bool,
+        additional_fragment_size: u8,
+    ) -> AdditionalEthernetCapabilities {
+        AdditionalEthernetCapabilities {
+            preemption_supported,
+            preemption_enabled,
+            preemption_active,
+            additional_fragment_size,
+        }
+    }
+
+    /// Get the minimum size of a non-final fragment in octets.
+    pub fn min_fragment_size(&self) -> u16 {
+        64 * (1 + (self.additional_fragment_size & 0x03) as u16)
+    }
+}
+
+impl Display for AdditionalEthernetCapabilities {
+    /// Write the TLV, e.g. `preemption supported, enabled, active, min fragment 128`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.preemption_supported {
+            return write!(f, "preemption not supported");
+        }
+        write!(
+            f,
+            "preemption supported, {}, {}, min fragment {}",
+            if self.preemption_enabled {
+                "enabled"
+            } else {
+                "disabled"
+            },
+            if self.preemption_active {
+                "active"
+            } else {
+                "inactive"
+            },
+            self.min_fragment_size()
+        )
+    }
+}
+
+impl OrgTlvPayload for AdditionalEthernetCapabilities {
+    fn oui(&self) -> [u8; 3] {
+        IEEE_8023_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        7
+    }
+
+    fn name(&self) -> String {
+        String::from("Additional Ethernet Capabilities")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let bits = self.preemption_supported as u16
+            | (self.preemption_enabled as u16) << 1
+            | (self.preemption_active as u16) << 2
+            | ((self.additional_fragment_size & 0x03) as u16) << 3;
+        bits.to_be_bytes().to_vec()
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("preemption_supported", self.preemption_supported.into()),
+            ("preemption_enabled", self.preemption_enabled.into()),
+            ("preemption_active", self.preemption_active.into()),
+            (
+                "additional_fragment_size",
+                self.additional_fragment_size.into(),
+            ),
+            ("min_fragment_size", self.min_fragment_size().into()),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for AdditionalEthernetCapabilities {
+    const KEYS: &'static [([u8; 3], u8)] = &[(IEEE_8023_OUI, 7)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 2, 2)?;
+        let bits = u16::from_be_bytes([value[0], value[1]]);
+        Ok(AdditionalEthernetCapabilities::new(
+            bits & 0x01 != 0,
+            bits & 0x02 != 0,
+            bits & 0x04 != 0,
+            ((bits >> 3) & 0x03) as u8,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+
+    const MAC_PHY: &[u8] = b"\xfe\x09\x00\x12\x0f\x01\x03\x6c\x01\x00\x1e";
+
+    #[test]
+    fn test_mac_phy_dump() {
+        let tlv =
+            OrganizationallySpecificTLV::from_payload(&MacPhyConfig::new(true, true, 0x6c01, 30));
+        assert_eq!(tlv.bytes(), MAC_PHY.to_vec());
+    }
+
+    #[test]
+    fn test_mac_phy_load() {
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(MAC_PHY);
+        let mac_phy = tlv.decode_as::<MacPhyConfig>().unwrap();
+        assert_eq!(mac_phy.mau_type.speed(), Some(1000));
+        assert_eq!(mac_phy.mau_type.duplex(), Some(Duplex::Full));
+        assert_eq!(
+            format!("{}", tlv),
+            "OrganizationallySpecificTLV(\"00120F\", 1, \"MAC/PHY Configuration/Status: autoneg supported, enabled \
+             [10BASE-T, 10BASE-T FD, 100BASE-TX, 100BASE-TX FD, 1000BASE-T FD], MAU 1000BASE-T full duplex\")"
+        );
+    }
+
+    #[test]
+    fn test_mau_type() {
+        assert_eq!(MauType(16).name(), Some("100BASE-TX full duplex"));
+        assert_eq!(MauType(15).duplex(), Some(Duplex::Half));
+        assert_eq!(MauType(5).duplex(), None);
+        assert_eq!(MauType(54).duplex(), Some(Duplex::Full));
+        assert_eq!(MauType(54).speed(), Some(10000));
+        assert_eq!(format!("{}", MauType(0)), "unknown");
+        assert_eq!(format!("{}", MauType(200)), "MAU type 200");
+    }
+
+    #[test]
+    fn test_mac_phy_no_autoneg() {
+        let mac_phy = MacPhyConfig::new(false, false, 0, 16);
+        assert_eq!(
+            format!("{}", mac_phy),
+            "autoneg not supported, MAU 100BASE-TX full duplex"
+        );
+    }
+
+    #[test]
+    fn test_max_frame_size() {
+        let bytes = b"\xfe\x06\x00\x12\x0f\x04\x24\x00";
+        let tlv = OrganizationallySpecificTLV::from_payload(&MaxFrameSize::new(9216));
+        assert_eq!(tlv.bytes(), bytes.to_vec());
+
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(bytes.as_ref());
+        assert_eq!(
+            tlv.decode_as::<MaxFrameSize>(),
+            Some(MaxFrameSize::new(9216))
+        );
+        assert_eq!(
+            format!("{}", tlv),
+            "OrganizationallySpecificTLV(\"00120F\", 4, \"Maximum Frame Size: 9216\")"
+        );
+    }
+
+    #[test]
+    fn test_eee() {
+        let bytes = b"\xfe\x0e\x00\x12\x0f\x05\x00\x10\x00\x10\x00\x10\x00\x11\x00\x12";
+        let eee = EnergyEfficientEthernet::new(16, 16, 16, 17, 18);
+        let tlv = OrganizationallySpecificTLV::from_payload(&eee);
+        assert_eq!(tlv.bytes(), bytes.to_vec());
+
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(bytes.as_ref());
+        assert_eq!(tlv.decode_as::<EnergyEfficientEthernet>(), Some(eee));
+        assert_eq!(
+            format!("{}", eee),
+            "Tx 16us, Rx 16us, fallback 16us [echo Tx 17us, Rx 18us]"
+        );
+    }
+
+    #[test]
+    fn test_additional_ethernet_capabilities() {
+        let bytes = b"\xfe\x06\x00\x12\x0f\x07\x00\x0b";
+        let capabilities = AdditionalEthernetCapabilities::new(true, true, false, 1);
+        let tlv = OrganizationallySpecificTLV::from_payload(&capabilities);
+        assert_eq!(tlv.bytes(), bytes.to_vec());
+
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(bytes.as_ref());
+        assert_eq!(
+            tlv.decode_as::<AdditionalEthernetCapabilities>(),
+            Some(capabilities)
+        );
+        assert_eq!(
+            format!("{}", capabilities),
+            "preemption supported, enabled, inactive, min fragment 128"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                AdditionalEthernetCapabilities::new(false, false, false, 0)
+            ),
+            "preemption not supported"
+        );
+    }
+
+    #[test]
+    fn test_max_frame_size_invalid_length() {
+        let tlv = OrganizationallySpecificTLV::new(b"\x00\x12\x0f".to_vec(), 4, vec![0x24]);
+        assert!(matches!(
+            tlv.decode().unwrap(),
+            Err(TlvError::InvalidLength { length: 1, .. })
+        ));
+    }
+}
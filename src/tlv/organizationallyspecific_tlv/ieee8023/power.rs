@@ -0,0 +1,745 @@
+//! IEEE 802.3 Power via MDI TLV
+//!
+//! The TLV grew with the PoE standards: 802.3af devices send the 3 octet base, 802.3at (Type 1 and 2) devices add the
+//! power type, priority and power values, and 802.3bt (Type 3 and 4) devices add the dual-signature and measurement
+//! fields. The variants are told apart by the length of the TLV.
+
+use crate::json::Json;
+use crate::tlv::organizationallyspecific_tlv::ieee8023::IEEE_8023_OUI;
+use crate::tlv::organizationallyspecific_tlv::registry::{
+    OrgTlvDecode, OrgTlvPayload, OrgTlvRegistry,
+};
+use crate::tlv::{TlvError, TlvType};
+
+use std::any::Any;
+use std::fmt::Display;
+
+/// Length of the 802.3af value
+const BASE_LENGTH: usize = 3;
+/// Length of the value including the 802.3at extension
+const DOT3AT_LENGTH: usize = 8;
+/// Length of the value including the 802.3bt extension
+const DOT3BT_LENGTH: usize = 25;
+
+/// Register the Power via MDI TLV.
+pub(crate) fn register(registry: &mut OrgTlvRegistry) {
+    registry.register_type::<PowerViaMdi>();
+}
+
+/// Format a power value given in units of 0.1 W.
+fn watts(deciwatts: u16) -> String {
+    format!("{}.{} W", deciwatts / 10, deciwatts % 10)
+}
+
+/// Get the name of a two bit power source field, which depends on whether the sender is a PD.
+///
+/// Shared with the LLDP-MED Extended Power via MDI TLV, which uses the same encoding.
+pub(crate) fn source_name(pd: bool, source: u8) -> &'static str {
+    match (pd, source) {
+        (_, 0) => "unknown source",
+        (false, 1) => "primary source",
+        (false, 2) => "backup source",
+        (true, 1) => "PSE source",
+        (true, 2) => "local source",
+        (true, 3) => "PSE and local sources",
+        _ => "reserved source",
+    }
+}
+
+/// Role of the port in powering the link
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PortClass {
+    /// Power sourcing equipment, e.g. a switch port
+    Pse,
+    /// Powered device, e.g. an access point
+    Pd,
+}
+
+impl Display for PortClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PortClass::Pse => write!(f, "PSE"),
+            PortClass::Pd => write!(f, "PD"),
+        }
+    }
+}
+
+/// Pairs used to supply power (pethPsePortPowerPairs, RFC 3621)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerPairs {
+    /// Signal pairs (1)
+    Signal,
+    /// Spare pairs (2)
+    Spare,
+    /// Reserved value
+    Reserved(u8),
+}
+
+impl From<u8> for PowerPairs {
+    fn from(value: u8) -> PowerPairs {
+        match value {
+            1 => PowerPairs::Signal,
+            2 => PowerPairs::Spare,
+            value => PowerPairs::Reserved(value),
+        }
+    }
+}
+
+impl From<PowerPairs> for u8 {
+    fn from(pairs: PowerPairs) -> u8 {
+        match pairs {
+            PowerPairs::Signal => 1,
+            PowerPairs::Spare => 2,
+            PowerPairs::Reserved(value) => value,
+        }
+    }
+}
+
+impl Display for PowerPairs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PowerPairs::Signal => write!(f, "signal pairs"),
+            PowerPairs::Spare => write!(f, "spare pairs"),
+            PowerPairs::Reserved(value) => write!(f, "pairs {}", value),
+        }
+    }
+}
+
+/// Power type of an 802.3at device
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerType {
+    /// Type 2 PSE (0)
+    Type2Pse,
+    /// Type 2 PD (1)
+    Type2Pd,
+    /// Type 1 PSE (2)
+    Type1Pse,
+    /// Type 1 PD (3)
+    Type1Pd,
+}
+
+impl PowerType {
+    /// Check whether the device is a powered device.
+    pub fn is_pd(&self) -> bool {
+        matches!(self, PowerType::Type2Pd | PowerType::Type1Pd)
+    }
+}
+
+impl From<u8> for PowerType {
+    /// Convert the two bit power type field.
+    fn from(value: u8) -> PowerType {
+        match value & 0x03 {
+            0 => PowerType::Type2Pse,
+            1 => PowerType::Type2Pd,
+            2 => PowerType::Type1Pse,
+            _ => PowerType::Type1Pd,
+        }
+    }
+}
+
+impl From<PowerType> for u8 {
+    fn from(power_type: PowerType) -> u8 {
+        match power_type {
+            PowerType::Type2Pse => 0,
+            PowerType::Type2Pd => 1,
+            PowerType::Type1Pse => 2,
+            PowerType::Type1Pd => 3,
+        }
+    }
+}
+
+impl Display for PowerType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PowerType::Type2Pse => write!(f, "Type 2 PSE"),
+            PowerType::Type2Pd => write!(f, "Type 2 PD"),
+            PowerType::Type1Pse => write!(f, "Type 1 PSE"),
+            PowerType::Type1Pd => write!(f, "Type 1 PD"),
+        }
+    }
+}
+
+/// Power priority of a port (pethPsePortPowerPriority, RFC 3621)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerPriority {
+    /// Unknown (0)
+    Unknown,
+    /// Critical (1)
+    Critical,
+    /// High (2)
+    High,
+    /// Low (3)
+    Low,
+}
+
+impl From<u8> for PowerPriority {
+    /// Convert the two bit priority field.
+    fn from(value: u8) -> PowerPriority {
+        match value & 0x03 {
+            0 => PowerPriority::Unknown,
+            1 => PowerPriority::Critical,
+            2 => PowerPriority::High,
+            _ => PowerPriority::Low,
+        }
+    }
+}
+
+impl From<PowerPriority> for u8 {
+    fn from(priority: PowerPriority) -> u8 {
+        match priority {
+            PowerPriority::Unknown => 0,
+            PowerPriority::Critical => 1,
+            PowerPriority::High => 2,
+            PowerPriority::Low => 3,
+        }
+    }
+}
+
+impl Display for PowerPriority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PowerPriority::Unknown => write!(f, "unknown"),
+            PowerPriority::Critical => write!(f, "critical"),
+            PowerPriority::High => write!(f, "high"),
+            PowerPriority::Low => write!(f, "low"),
+        }
+    }
+}
+
+/// 802.3at extension of the Power via MDI TLV
+///
+/// # Format:
+///
+/// ```text
+///  0               1               3               5
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |  Type/Source/ |  PD Requested | PSE Allocated |
+/// |   Priority    |  Power Value  |  Power Value  |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// Bits 7-6 of the first octet hold the power type, bits 5-4 the power source and bits 1-0 the priority. Power values
+/// are given in units of 0.1 W. The 802.3bt extension can only follow this extension, so it is kept here as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dot3atPower {
+    /// Power type
+    pub power_type: PowerType,
+    /// Power source, see [source_name](Dot3atPower::source_name)
+    pub source: u8,
+    /// Power priority
+    pub priority: PowerPriority,
+    /// Power requested by the PD in units of 0.1 W
+    pub requested: u16,
+    /// Power allocated by the PSE in units of 0.1 W
+    pub allocated: u16,
+    /// 802.3bt extension
+    pub dot3bt: Option<Dot3btPower>,
+}
+
+impl Dot3atPower {
+    /// Get the name of the power source, which depends on whether the sender is a PSE or a PD.
+    pub fn source_name(&self) -> &'static str {
+        source_name(self.power_type.is_pd(), self.source)
+    }
+
+    fn encode(&self, value: &mut Vec<u8>) {
+        value.push(
+            u8::from(self.power_type) << 6 | (self.source & 0x03) << 4 | u8::from(self.priority),
+        );
+        value.extend_from_slice(&self.requested.to_be_bytes());
+        value.extend_from_slice(&self.allocated.to_be_bytes());
+        if let Some(dot3bt) = &self.dot3bt {
+            dot3bt.encode(value);
+        }
+    }
+
+    /// Decode the extension, followed by the 802.3bt extension if `value` is long enough.
+    fn decode(value: &[u8]) -> Dot3atPower {
+        Dot3atPower {
+            power_type: PowerType::from(value[0] >> 6),
+            source: (value[0] >> 4) & 0x03,
+            priority: PowerPriority::from(value[0]),
+            requested: u16::from_be_bytes([value[1], value[2]]),
+            allocated: u16::from_be_bytes([value[3], value[4]]),
+            dot3bt: if value.len() == DOT3BT_LENGTH - BASE_LENGTH {
+                Some(Dot3btPower::decode(&value[DOT3AT_LENGTH - BASE_LENGTH..]))
+            } else {
+                None
+            },
+        }
+    }
+
+    fn to_json(self) -> Json {
+        Json::object(vec![
+            ("power_type", self.power_type.to_string().into()),
+            ("source", self.source_name().into()),
+            ("priority", self.priority.to_string().into()),
+            ("requested_power", (self.requested as f64 / 10.0).into()),
+            ("allocated_power", (self.allocated as f64 / 10.0).into()),
+        ])
+    }
+}
+
+impl Display for Dot3atPower {
+    /// Write the extension, e.g. `Type 2 PSE, primary source, priority low, requested 25.5 W, allocated 25.5 W`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}, {}, priority {}, requested {}, allocated {}",
+            self.power_type,
+            self.source_name(),
+            self.priority,
+            watts(self.requested),
+            watts(self.allocated)
+        )?;
+        if let Some(dot3bt) = &self.dot3bt {
+            write!(f, "; {}", dot3bt)?;
+        }
+        Ok(())
+    }
+}
+
+/// 802.3bt extension of the Power via MDI TLV
+///
+/// # Format:
+///
+/// ```text
+///  0               2               4               6               8
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |  PD Requested |  PD Requested | PSE Allocated | PSE Allocated |
+/// |    Power A    |    Power B    |    Power A    |    Power B    |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+///  8               10              11              13              14              17
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |     Power     |    System     |  PSE Maximum  |   Autoclass   |  Power Down   |
+/// |    Status     |     Setup     |   Available   |               |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// The power values of the A and B modes of dual-signature PDs and the maximum power available at the PSE are given
+/// in units of 0.1 W. The power status holds:
+///
+/// | Bits  | Field                                       |
+/// |-------|---------------------------------------------|
+/// | 15-14 | PSE powering status                         |
+/// | 13-12 | PD powered status                           |
+/// | 11-10 | PSE power pairs                             |
+/// | 9-7   | Dual-signature power class, mode A          |
+/// | 6-4   | Dual-signature power class, mode B          |
+/// | 3-0   | Power class (1-8, 15 for dual-signature PD) |
+///
+/// Bits 3-1 of the system setup hold the extended power type, bit 0 the PD load. The autoclass octet holds the PSE
+/// autoclass support (bit 2), autoclass completed (bit 1) and autoclass request (bit 0) flags. The power down field
+/// holds a 6 bit request, 0x1D asking the PSE to remove power, followed by an 18 bit time in seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dot3btPower {
+    /// Power requested by the PD for mode A in units of 0.1 W
+    pub requested_a: u16,
+    /// Power requested by the PD for mode B in units of 0.1 W
+    pub requested_b: u16,
+    /// Power allocated by the PSE for mode A in units of 0.1 W
+    pub allocated_a: u16,
+    /// Power allocated by the PSE for mode B in units of 0.1 W
+    pub allocated_b: u16,
+    /// Power status bitmap
+    pub status: u16,
+    /// System setup bitmap
+    pub system_setup: u8,
+    /// Maximum power available at the PSE in units of 0.1 W
+    pub max_available: u16,
+    /// Autoclass flags
+    pub autoclass: u8,
+    /// Power down request
+    pub power_down_request: u8,
+    /// Power down time in seconds
+    pub power_down_time: u32,
+}
+
+impl Dot3btPower {
+    /// Get the power class (1-8) from the power status, `None` for dual-signature PDs and reserved values.
+    pub fn power_class(&self) -> Option<u8> {
+        match (self.status & 0x0F) as u8 {
+            class @ 1..=8 => Some(class),
+            _ => None,
+        }
+    }
+
+    /// Get the name of the extended power type from the system setup.
+    pub fn power_type(&self) -> &'static str {
+        match (self.system_setup >> 1) & 0x07 {
+            0 => "Type 3 PSE",
+            1 => "Type 4 PSE",
+            2 => "Type 3 single-signature PD",
+            3 => "Type 3 dual-signature PD",
+            4 => "Type 4 single-signature PD",
+            5 => "Type 4 dual-signature PD",
+            _ => "reserved power type",
+        }
+    }
+
+    /// Check whether the PD requests the PSE to remove power.
+    pub fn power_down(&self) -> bool {
+        self.power_down_request == 0x1D
+    }
+
+    fn encode(&self, value: &mut Vec<u8>) {
+        for field in [
+            self.requested_a,
+            self.requested_b,
+            self.allocated_a,
+            self.allocated_b,
+            self.status,
+        ] {
+            value.extend_from_slice(&field.to_be_bytes());
+        }
+        value.push(self.system_setup);
+        value.extend_from_slice(&self.max_available.to_be_bytes());
+        value.push(self.autoclass);
+        let power_down = (self.power_down_request as u32) << 18 | (self.power_down_time & 0x3FFFF);
+        value.extend_from_slice(&power_down.to_be_bytes()[1..]);
+    }
+
+    fn decode(value: &[u8]) -> Dot3btPower {
+        let power_down = u32::from_be_bytes([0, value[14], value[15], value[16]]);
+        Dot3btPower {
+            requested_a: u16::from_be_bytes([value[0], value[1]]),
+            requested_b: u16::from_be_bytes([value[2], value[3]]),
+            allocated_a: u16::from_be_bytes([value[4], value[5]]),
+            allocated_b: u16::from_be_bytes([value[6], value[7]]),
+            status: u16::from_be_bytes([value[8], value[9]]),
+            system_setup: value[10],
+            max_available: u16::from_be_bytes([value[11], value[12]]),
+            autoclass: value[13],
+            power_down_request: (power_down >> 18) as u8,
+            power_down_time: power_down & 0x3FFFF,
+        }
+    }
+
+    fn to_json(self) -> Json {
+        Json::object(vec![
+            ("requested_power_a", (self.requested_a as f64 / 10.0).into()),
+            ("requested_power_b", (self.requested_b as f64 / 10.0).into()),
+            ("allocated_power_a", (self.allocated_a as f64 / 10.0).into()),
+            ("allocated_power_b", (self.allocated_b as f64 / 10.0).into()),
+            ("status", self.status.into()),
+            ("power_class", self.power_class().into()),
+            ("power_type", self.power_type().into()),
+            ("pd_load", (self.system_setup & 0x01 != 0).into()),
+            (
+                "max_available_power",
+                (self.max_available as f64 / 10.0).into(),
+            ),
+            ("autoclass", self.autoclass.into()),
+            ("power_down", self.power_down().into()),
+            ("power_down_time", self.power_down_time.into()),
+        ])
+    }
+}
+
+impl Display for Dot3btPower {
+    /// Write the extension, e.g. `Type 3 PSE, class 6, max available 60.0 W`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.power_type())?;
+        match self.power_class() {
+            Some(class) => write!(f, ", class {}", class)?,
+            None if self.status & 0x0F == 0x0F => write!(
+                f,
+                ", dual-signature, requested A/B {}/{}, allocated A/B {}/{}",
+                watts(self.requested_a),
+                watts(self.requested_b),
+                watts(self.allocated_a),
+                watts(self.allocated_b)
+            )?,
+            None => {}
+        }
+        write!(f, ", max available {}", watts(self.max_available))?;
+        if self.power_down() {
+            write!(f, ", power down for {}s", self.power_down_time)?;
+        }
+        Ok(())
+    }
+}
+
+/// Power via MDI TLV (subtype 2)
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               7               8               9
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...
+/// |             |                 |               |               |   MDI Power   |   PSE Power   |     Power     | 802.3at/bt
+/// |     127     |   7, 12 or 29   |   00-12-0F    |       2       |    Support    |     Pair      |     Class     | extensions
+/// |             |                 |               |               |               |               |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...
+/// ```
+///
+/// MDI power support:
+///
+/// | Bit | Function                                |
+/// |-----|-----------------------------------------|
+/// | 0   | Port class: 1 = PSE, 0 = PD             |
+/// | 1   | PSE MDI power supported                 |
+/// | 2   | PSE MDI power enabled                   |
+/// | 3   | PSE pairs control ability               |
+///
+/// The power class holds the 802.3af class plus one, i.e. 1 for class 0 up to 5 for class 4. See [Dot3atPower] and
+/// [Dot3btPower] for the extensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PowerViaMdi {
+    /// Port class
+    pub port_class: PortClass,
+    /// PSE MDI power is supported
+    pub mdi_supported: bool,
+    /// PSE MDI power is enabled
+    pub mdi_enabled: bool,
+    /// The pairs used for power can be controlled
+    pub pair_control: bool,
+    /// Pairs used to supply power
+    pub power_pairs: PowerPairs,
+    /// Power class field, the 802.3af power class plus one
+    pub power_class: u8,
+    /// 802.3at extension, including the 802.3bt extension
+    pub dot3at: Option<Dot3atPower>,
+}
+
+impl PowerViaMdi {
+    /// Create a TLV without extensions, as sent by 802.3af devices.
+    pub fn new(
+        port_class: PortClass,
+        mdi_supported: bool,
+        mdi_enabled: bool,
+        pair_control: bool,
+        power_pairs: PowerPairs,
+        power_class: u8,
+    ) -> PowerViaMdi {
+        PowerViaMdi {
+            port_class,
+            mdi_supported,
+            mdi_enabled,
+            pair_control,
+            power_pairs,
+            power_class,
+            dot3at: None,
+        }
+    }
+}
+
+impl Display for PowerViaMdi {
+    /// Write the TLV, e.g. `PSE, MDI supported, enabled, signal pairs, class 4; Type 2 PSE, primary source, ...`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.port_class)?;
+        if self.mdi_supported {
+            write!(
+                f,
+                ", MDI supported, {}",
+                if self.mdi_enabled {
+                    "enabled"
+                } else {
+                    "disabled"
+                }
+            )?;
+        } else {
+            write!(f, ", MDI not supported")?;
+        }
+        if self.pair_control {
+            write!(f, ", pair control")?;
+        }
+        write!(f, ", {}", self.power_pairs)?;
+        match self.power_class {
+            class @ 1..=5 => write!(f, ", class {}", class - 1)?,
+            class => write!(f, ", reserved class {}", class)?,
+        }
+        if let Some(dot3at) = &self.dot3at {
+            write!(f, "; {}", dot3at)?;
+        }
+        Ok(())
+    }
+}
+
+impl OrgTlvPayload for PowerViaMdi {
+    fn oui(&self) -> [u8; 3] {
+        IEEE_8023_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        2
+    }
+
+    fn name(&self) -> String {
+        String::from("Power via MDI")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let mut value = vec![
+            (self.port_class == PortClass::Pse) as u8
+                | (self.mdi_supported as u8) << 1
+                | (self.mdi_enabled as u8) << 2
+                | (self.pair_control as u8) << 3,
+            self.power_pairs.into(),
+            self.power_class,
+        ];
+        if let Some(dot3at) = &self.dot3at {
+            dot3at.encode(&mut value);
+        }
+        value
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("port_class", self.port_class.to_string().into()),
+            ("mdi_supported", self.mdi_supported.into()),
+            ("mdi_enabled", self.mdi_enabled.into()),
+            ("pair_control", self.pair_control.into()),
+            ("power_pairs", u8::from(self.power_pairs).into()),
+            ("power_class", self.power_class.into()),
+            ("dot3at", self.dot3at.map(Dot3atPower::to_json).into()),
+            (
+                "dot3bt",
+                self.dot3at
+                    .and_then(|dot3at| dot3at.dot3bt)
+                    .map(Dot3btPower::to_json)
+                    .into(),
+            ),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for PowerViaMdi {
+    const KEYS: &'static [([u8; 3], u8)] = &[(IEEE_8023_OUI, 2)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        if ![BASE_LENGTH, DOT3AT_LENGTH, DOT3BT_LENGTH].contains(&value.len()) {
+            return Err(TlvError::InvalidLength {
+                tlv_type: TlvType::OrganizationallySpecific,
+                length: value.len(),
+                min: BASE_LENGTH,
+                max: DOT3BT_LENGTH,
+            });
+        }
+
+        let mut tlv = PowerViaMdi::new(
+            if value[0] & 0x01 != 0 {
+                PortClass::Pse
+            } else {
+                PortClass::Pd
+            },
+            value[0] & 0x02 != 0,
+            value[0] & 0x04 != 0,
+            value[0] & 0x08 != 0,
+            value[1].into(),
+            value[2],
+        );
+        if value.len() >= DOT3AT_LENGTH {
+            tlv.dot3at = Some(Dot3atPower::decode(&value[BASE_LENGTH..]));
+        }
+        Ok(tlv)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+
+    const DOT3AF: &[u8] = b"\xfe\x07\x00\x12\x0f\x02\x07\x01\x05";
+    const DOT3AT: &[u8] = b"\xfe\x0c\x00\x12\x0f\x02\x07\x01\x05\x13\x00\xff\x00\xff";
+    const DOT3BT: &[u8] = b"\xfe\x1d\x00\x12\x0f\x02\x07\x01\x05\x13\x02\x58\x02\x58\
+        \x00\x00\x00\x00\x00\x00\x00\x00\x40\x06\x00\x02\x58\x00\x00\x00\x00";
+
+    fn dot3at() -> Dot3atPower {
+        Dot3atPower {
+            power_type: PowerType::Type2Pse,
+            source: 1,
+            priority: PowerPriority::Low,
+            requested: 255,
+            allocated: 255,
+            dot3bt: None,
+        }
+    }
+
+    #[test]
+    fn test_dot3af() {
+        let power = PowerViaMdi::new(PortClass::Pse, true, true, false, PowerPairs::Signal, 5);
+        let tlv = OrganizationallySpecificTLV::from_payload(&power);
+        assert_eq!(tlv.bytes(), DOT3AF.to_vec());
+
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(DOT3AF);
+        assert_eq!(tlv.decode_as::<PowerViaMdi>(), Some(power));
+        assert_eq!(
+            format!("{}", tlv),
+            "OrganizationallySpecificTLV(\"00120F\", 2, \"Power via MDI: PSE, MDI supported, enabled, signal pairs, class 4\")"
+        );
+    }
+
+    #[test]
+    fn test_dot3at() {
+        let mut power = PowerViaMdi::new(PortClass::Pse, true, true, false, PowerPairs::Signal, 5);
+        power.dot3at = Some(dot3at());
+        let tlv = OrganizationallySpecificTLV::from_payload(&power);
+        assert_eq!(tlv.bytes(), DOT3AT.to_vec());
+
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(DOT3AT);
+        assert_eq!(tlv.decode_as::<PowerViaMdi>(), Some(power));
+        assert_eq!(
+            format!("{}", power),
+            "PSE, MDI supported, enabled, signal pairs, class 4; Type 2 PSE, primary source, priority low, \
+             requested 25.5 W, allocated 25.5 W"
+        );
+    }
+
+    #[test]
+    fn test_dot3bt() {
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(DOT3BT);
+        let power = tlv.decode_as::<PowerViaMdi>().unwrap();
+        let dot3at = power.dot3at.unwrap();
+        let dot3bt = dot3at.dot3bt.unwrap();
+        assert_eq!(dot3at.requested, 600);
+        assert_eq!(dot3bt.power_class(), Some(6));
+        assert_eq!(dot3bt.power_type(), "Type 3 PSE");
+        assert_eq!(dot3bt.max_available, 600);
+        assert!(!dot3bt.power_down());
+        assert_eq!(
+            OrganizationallySpecificTLV::from_payload(&power).bytes(),
+            DOT3BT.to_vec()
+        );
+        assert_eq!(
+            format!("{}", dot3bt),
+            "Type 3 PSE, class 6, max available 60.0 W"
+        );
+        assert!(format!("{}", power).ends_with("; Type 3 PSE, class 6, max available 60.0 W"));
+    }
+
+    #[test]
+    fn test_power_down() {
+        let mut value = vec![0; 17];
+        value[14..].copy_from_slice(&((0x1D << 18) | 30u32).to_be_bytes()[1..]);
+        let dot3bt = Dot3btPower::decode(&value);
+        assert!(dot3bt.power_down());
+        assert_eq!(dot3bt.power_down_time, 30);
+
+        let mut encoded = Vec::new();
+        dot3bt.encode(&mut encoded);
+        assert_eq!(encoded, value);
+    }
+
+    #[test]
+    fn test_source_name() {
+        let mut power = dot3at();
+        power.power_type = PowerType::Type2Pd;
+        power.source = 3;
+        assert_eq!(power.source_name(), "PSE and local sources");
+    }
+
+    #[test]
+    fn test_invalid_length() {
+        let tlv = OrganizationallySpecificTLV::new(b"\x00\x12\x0f".to_vec(), 2, vec![0; 12]);
+        assert!(matches!(
+            tlv.decode().unwrap(),
+            Err(TlvError::InvalidLength { length: 12, .. })
+        ));
+    }
+}
@@ -0,0 +1,799 @@
+//! Declarative layouts of Organizationally Specific TLVs
+//!
+//! Vendor TLVs without a built-in decoder can be described in a TOML file and registered with
+//! [OrgTlvRegistry::load_layouts](crate::tlv::organizationallyspecific_tlv::registry::OrgTlvRegistry::load_layouts).
+//! Each `[[tlv]]` table describes the value of one (OUI, subtype) pair as a sequence of fields, which are decoded in
+//! order:
+//!
+//! ```toml
+//! [[tlv]]
+//! oui = "00-00-5E"
+//! subtype = 1
+//! name = "Example Status"
+//! organization = "Example Inc."    # optional
+//!
+//! [[tlv.field]]
+//! name = "flags"
+//! type = "bitflags"
+//! size = 1                         # octets, 1, 2 or 4 (default 1)
+//! flags = { 0 = "up", 7 = "tagged" }
+//!
+//! [[tlv.field]]
+//! name = "mode"
+//! type = "enum"
+//! values = { 1 = "pending", 2 = "active" }
+//!
+//! [[tlv.field]]
+//! name = "label"
+//! type = "string"
+//! length_prefix = 1                # or `length = 8` for a fixed length, default: rest of the value
+//!
+//! [[tlv.field]]
+//! name = "entries"
+//! type = "group"
+//! count_prefix = 1                 # or `count = 4`, default: repeated until the end of the value
+//! field = [
+//!     { name = "vlan", type = "u16" },
+//!     { name = "address", type = "ipv4" },
+//! ]
+//! ```
+//!
+//! | Type       | Options                              | Value                                             |
+//! |------------|--------------------------------------|---------------------------------------------------|
+//! | `u8`       |                                      | Unsigned integer, in network byte order           |
+//! | `u16`      |                                      |                                                   |
+//! | `u32`      |                                      |                                                   |
+//! | `bitflags` | `size`, `flags` (bit 0 is the LSB)   | Names of the set bits                             |
+//! | `enum`     | `size`, `values`                     | Name of the value                                 |
+//! | `string`   | `length` or `length_prefix`          | Text, trailing NUL octets are removed             |
+//! | `bytes`    | `length` or `length_prefix`          | Raw octets                                        |
+//! | `mac`      |                                      | MAC address                                       |
+//! | `ipv4`     |                                      | IPv4 address                                      |
+//! | `ipv6`     |                                      | IPv6 address                                      |
+//! | `group`    | `field`, `count` or `count_prefix`   | Repeated group of fields                          |
+
+use crate::json::Json;
+use crate::tlv::organizationallyspecific_tlv::registry::{format_oui, OrgTlvPayload};
+use crate::tlv::{format_hex, format_mac, TlvError};
+use crate::toml::Toml;
+pub use crate::toml::TomlError;
+
+use std::any::Any;
+use std::fmt::Display;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::sync::Arc;
+
+/// Error returned when loading TLV layouts fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayoutError {
+    /// The file is not valid TOML
+    Toml(TomlError),
+    /// A layout is incomplete or uses an unknown type or option, with a description of the problem
+    Invalid(String),
+}
+
+impl Display for LayoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LayoutError::Toml(err) => write!(f, "{}", err),
+            LayoutError::Invalid(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl From<TomlError> for LayoutError {
+    fn from(err: TomlError) -> LayoutError {
+        LayoutError::Toml(err)
+    }
+}
+
+/// Length of a variable sized field, or number of repetitions of a group
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Length {
+    /// Fixed number of octets or repetitions
+    Fixed(usize),
+    /// Given by a prefix of this many octets
+    Prefixed(usize),
+    /// Up to the end of the value
+    Remaining,
+}
+
+/// Type of a [Field]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldType {
+    /// Unsigned integer of 1, 2 or 4 octets
+    Unsigned(usize),
+    /// Flags of 1, 2 or 4 octets, with the names of the bits
+    Bitflags {
+        size: usize,
+        flags: Vec<(u8, String)>,
+    },
+    /// Enumeration of 1, 2 or 4 octets, with the names of the values
+    Enum {
+        size: usize,
+        values: Vec<(u32, String)>,
+    },
+    /// Text
+    String(Length),
+    /// Raw octets
+    Bytes(Length),
+    /// MAC address
+    Mac,
+    /// IPv4 address
+    Ipv4,
+    /// IPv6 address
+    Ipv6,
+    /// Repeated group of fields
+    Group { count: Length, fields: Vec<Field> },
+}
+
+/// Named field of a [TlvLayout]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    /// Name of the field, used in the Display and JSON output
+    pub name: String,
+    /// Type of the field
+    pub field_type: FieldType,
+}
+
+/// Description of the value of an Organizationally Specific TLV
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlvLayout {
+    /// Organizationally unique identifier
+    pub oui: [u8; 3],
+    /// Organizationally defined subtype
+    pub subtype: u8,
+    /// Name of the TLV
+    pub name: String,
+    /// Name of the organization owning the OUI, if given
+    pub organization: Option<String>,
+    /// Fields of the value, in order
+    pub fields: Vec<Field>,
+}
+
+fn invalid<T>(context: &str, message: String) -> Result<T, LayoutError> {
+    Err(LayoutError::Invalid(format!("{}: {}", context, message)))
+}
+
+/// Get the string member `name` of a table.
+fn string(table: &Toml, context: &str, name: &str) -> Result<Option<String>, LayoutError> {
+    match table.get(name) {
+        None => Ok(None),
+        Some(Toml::String(value)) => Ok(Some(value.clone())),
+        Some(_) => invalid(context, format!("{} is not a string", name)),
+    }
+}
+
+/// Get the integer member `name` of a table, which must be in `0..=max`.
+fn integer(table: &Toml, context: &str, name: &str, max: u32) -> Result<Option<u32>, LayoutError> {
+    match table.get(name) {
+        None => Ok(None),
+        Some(Toml::Integer(value)) if (0..=max as i64).contains(value) => Ok(Some(*value as u32)),
+        Some(_) => invalid(
+            context,
+            format!("{} is not an integer in 0 - {}", name, max),
+        ),
+    }
+}
+
+/// Get the table member `name` mapping integers to names, e.g. `{ 1 = "pending", 0x10 = "active" }`.
+fn names(
+    table: &Toml,
+    context: &str,
+    name: &str,
+    max: u32,
+) -> Result<Vec<(u32, String)>, LayoutError> {
+    let members = match table.get(name) {
+        None => return Ok(vec![]),
+        Some(Toml::Table(members)) => members,
+        Some(_) => return invalid(context, format!("{} is not a table", name)),
+    };
+    let mut names = vec![];
+    for (key, value) in members {
+        let number = match key.strip_prefix("0x") {
+            Some(hex) => u32::from_str_radix(hex, 16).ok(),
+            None => key.parse().ok(),
+        };
+        match (number, value) {
+            (Some(number), Toml::String(value)) if number <= max => {
+                names.push((number, value.clone()))
+            }
+            _ => {
+                return invalid(
+                    context,
+                    format!("invalid {} entry {} = {:?}", name, key, value),
+                )
+            }
+        }
+    }
+    Ok(names)
+}
+
+fn size(table: &Toml, context: &str) -> Result<usize, LayoutError> {
+    match integer(table, context, "size", 4)? {
+        None => Ok(1),
+        Some(size @ 1) | Some(size @ 2) | Some(size @ 4) => Ok(size as usize),
+        Some(size) => invalid(context, format!("size {}, expected 1, 2 or 4", size)),
+    }
+}
+
+/// Get the [Length] given by the members `fixed` or `prefix` of a table.
+fn length(table: &Toml, context: &str, fixed: &str, prefix: &str) -> Result<Length, LayoutError> {
+    match (
+        integer(table, context, fixed, 507)?,
+        integer(table, context, prefix, 2)?,
+    ) {
+        (Some(_), Some(_)) => invalid(context, format!("both {} and {} given", fixed, prefix)),
+        (Some(length), None) => Ok(Length::Fixed(length as usize)),
+        (None, Some(octets @ 1)) | (None, Some(octets @ 2)) => {
+            Ok(Length::Prefixed(octets as usize))
+        }
+        (None, Some(octets)) => invalid(context, format!("{} {}, expected 1 or 2", prefix, octets)),
+        (None, None) => Ok(Length::Remaining),
+    }
+}
+
+/// Parse the array member `field` of a table.
+fn fields(table: &Toml, context: &str) -> Result<Vec<Field>, LayoutError> {
+    let fields = match table.get("field") {
+        Some(Toml::Array(fields)) if !fields.is_empty() => fields,
+        _ => return invalid(context, String::from("no fields")),
+    };
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| Field::from_toml(field, &format!("{}, field {}", context, index + 1)))
+        .collect()
+}
+
+impl Field {
+    fn from_toml(table: &Toml, context: &str) -> Result<Field, LayoutError> {
+        if table.as_table().is_none() {
+            return invalid(context, String::from("not a table"));
+        }
+        let name = match string(table, context, "name")? {
+            Some(name) => name,
+            None => return invalid(context, String::from("no name")),
+        };
+        let context = &format!("{} ({})", context, name);
+        let field_type = match string(table, context, "type")?.as_deref() {
+            Some("u8") => FieldType::Unsigned(1),
+            Some("u16") => FieldType::Unsigned(2),
+            Some("u32") => FieldType::Unsigned(4),
+            Some("bitflags") => {
+                let size = size(table, context)?;
+                let flags = names(table, context, "flags", size as u32 * 8 - 1)?;
+                FieldType::Bitflags {
+                    size,
+                    flags: flags
+                        .into_iter()
+                        .map(|(bit, name)| (bit as u8, name))
+                        .collect(),
+                }
+            }
+            Some("enum") => {
+                let size = size(table, context)?;
+                let max = u32::MAX >> (32 - 8 * size);
+                FieldType::Enum {
+                    size,
+                    values: names(table, context, "values", max)?,
+                }
+            }
+            Some("string") => FieldType::String(length(table, context, "length", "length_prefix")?),
+            Some("bytes") => FieldType::Bytes(length(table, context, "length", "length_prefix")?),
+            Some("mac") => FieldType::Mac,
+            Some("ipv4") => FieldType::Ipv4,
+            Some("ipv6") => FieldType::Ipv6,
+            Some("group") => FieldType::Group {
+                count: length(table, context, "count", "count_prefix")?,
+                fields: fields(table, context)?,
+            },
+            Some(other) => return invalid(context, format!("unknown type {:?}", other)),
+            None => return invalid(context, String::from("no type")),
+        };
+        Ok(Field { name, field_type })
+    }
+}
+
+impl TlvLayout {
+    /// Parse the layouts described in a TOML document.
+    pub fn parse_all(text: &str) -> Result<Vec<TlvLayout>, LayoutError> {
+        let toml = Toml::parse(text)?;
+        match toml.get("tlv") {
+            None => Ok(vec![]),
+            Some(Toml::Array(tlvs)) => tlvs
+                .iter()
+                .enumerate()
+                .map(|(index, tlv)| TlvLayout::from_toml(tlv, &format!("tlv {}", index + 1)))
+                .collect(),
+            Some(_) => invalid("tlv", String::from("not an array of tables")),
+        }
+    }
+
+    fn from_toml(table: &Toml, context: &str) -> Result<TlvLayout, LayoutError> {
+        let oui = match string(table, context, "oui")? {
+            Some(oui) => oui,
+            None => return invalid(context, String::from("no OUI")),
+        };
+        let octets: Option<Vec<u8>> = oui
+            .split(['-', ':'])
+            .map(|octet| match octet.len() {
+                1 | 2 if octet.chars().all(|c| c.is_ascii_hexdigit()) => {
+                    u8::from_str_radix(octet, 16).ok()
+                }
+                _ => None,
+            })
+            .collect();
+        let oui = match octets.as_deref() {
+            Some(&[a, b, c]) => [a, b, c],
+            _ => return invalid(context, format!("invalid OUI {:?}", oui)),
+        };
+        let subtype = match integer(table, context, "subtype", 255)? {
+            Some(subtype) => subtype as u8,
+            None => return invalid(context, String::from("no subtype")),
+        };
+        let context = &format!("{} ({} subtype {})", context, format_oui(&oui), subtype);
+        let name = match string(table, context, "name")? {
+            Some(name) => name,
+            None => return invalid(context, String::from("no name")),
+        };
+        Ok(TlvLayout {
+            oui,
+            subtype,
+            name,
+            organization: string(table, context, "organization")?,
+            fields: fields(table, context)?,
+        })
+    }
+}
+
+/// Decoded value of a [Field]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldValue {
+    /// Unsigned integer
+    Unsigned(u32),
+    /// Names of the set bits, `bit N` for bits without a name
+    Flags(Vec<String>),
+    /// Value with its name, if known
+    Enum(u32, Option<String>),
+    /// Text
+    String(String),
+    /// Raw octets
+    Bytes(Vec<u8>),
+    /// MAC or IP address
+    Address(String),
+    /// Repetitions of a group
+    Groups(Vec<Vec<(String, FieldValue)>>),
+}
+
+impl Display for FieldValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldValue::Unsigned(value) => write!(f, "{}", value),
+            FieldValue::Flags(flags) if flags.is_empty() => write!(f, "none"),
+            FieldValue::Flags(flags) => write!(f, "{}", flags.join("|")),
+            FieldValue::Enum(_, Some(name)) => write!(f, "{}", name),
+            FieldValue::Enum(value, None) => write!(f, "{}", value),
+            FieldValue::String(value) | FieldValue::Address(value) => write!(f, "{}", value),
+            FieldValue::Bytes(octets) => write!(f, "{}", format_hex(octets, "")),
+            FieldValue::Groups(groups) => {
+                let groups: Vec<String> =
+                    groups.iter().map(|fields| fields_string(fields)).collect();
+                write!(f, "[{}]", groups.join("; "))
+            }
+        }
+    }
+}
+
+impl FieldValue {
+    fn to_json(&self) -> Json {
+        match self {
+            FieldValue::Unsigned(value) => (*value).into(),
+            FieldValue::Flags(flags) => flags.clone().into(),
+            FieldValue::Enum(_, Some(name)) => name.as_str().into(),
+            FieldValue::Enum(value, None) => (*value).into(),
+            FieldValue::String(value) | FieldValue::Address(value) => value.as_str().into(),
+            FieldValue::Bytes(octets) => Json::hex(octets),
+            FieldValue::Groups(groups) => groups
+                .iter()
+                .map(|fields| fields_json(fields))
+                .collect::<Vec<Json>>()
+                .into(),
+        }
+    }
+}
+
+fn fields_string(fields: &[(String, FieldValue)]) -> String {
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, value))
+        .collect();
+    fields.join(", ")
+}
+
+fn fields_json(fields: &[(String, FieldValue)]) -> Json {
+    Json::Object(
+        fields
+            .iter()
+            .map(|(name, value)| (name.clone(), value.to_json()))
+            .collect(),
+    )
+}
+
+/// Cursor over the octets of a TLV value
+struct Reader<'a> {
+    value: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, length: usize, field: &str) -> Result<&'a [u8], TlvError> {
+        let remaining = self.value.len() - self.position;
+        if length > remaining {
+            return Err(TlvError::InvalidValue(format!(
+                "{} needs {} octets, {} left",
+                field, length, remaining
+            )));
+        }
+        self.position += length;
+        Ok(&self.value[self.position - length..self.position])
+    }
+
+    fn unsigned(&mut self, size: usize, field: &str) -> Result<u32, TlvError> {
+        Ok(self
+            .take(size, field)?
+            .iter()
+            .fold(0, |value, octet| value << 8 | *octet as u32))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position == self.value.len()
+    }
+
+    fn length(&mut self, length: Length, field: &str) -> Result<usize, TlvError> {
+        match length {
+            Length::Fixed(length) => Ok(length),
+            Length::Prefixed(octets) => Ok(self.unsigned(octets, field)? as usize),
+            Length::Remaining => Ok(self.value.len() - self.position),
+        }
+    }
+
+    fn fields(&mut self, fields: &[Field]) -> Result<Vec<(String, FieldValue)>, TlvError> {
+        fields
+            .iter()
+            .map(|field| Ok((field.name.clone(), self.field(field)?)))
+            .collect()
+    }
+
+    fn field(&mut self, field: &Field) -> Result<FieldValue, TlvError> {
+        let name = field.name.as_str();
+        Ok(match &field.field_type {
+            FieldType::Unsigned(size) => FieldValue::Unsigned(self.unsigned(*size, name)?),
+            FieldType::Bitflags { size, flags } => {
+                let value = self.unsigned(*size, name)?;
+                FieldValue::Flags(
+                    (0..*size as u8 * 8)
+                        .filter(|bit| value & 1 << bit != 0)
+                        .map(|bit| match flags.iter().find(|(flag, _)| *flag == bit) {
+                            Some((_, name)) => name.clone(),
+                            None => format!("bit {}", bit),
+                        })
+                        .collect(),
+                )
+            }
+            FieldType::Enum { size, values } => {
+                let value = self.unsigned(*size, name)?;
+                let known = values.iter().find(|(known, _)| *known == value);
+                FieldValue::Enum(value, known.map(|(_, name)| name.clone()))
+            }
+            FieldType::String(length) => {
+                let length = self.length(*length, name)?;
+                let text = String::from_utf8_lossy(self.take(length, name)?);
+                FieldValue::String(text.trim_end_matches('\0').to_string())
+            }
+            FieldType::Bytes(length) => {
+                let length = self.length(*length, name)?;
+                FieldValue::Bytes(self.take(length, name)?.to_vec())
+            }
+            FieldType::Mac => FieldValue::Address(format_mac(self.take(6, name)?)),
+            FieldType::Ipv4 => {
+                let octets = self.take(4, name)?;
+                FieldValue::Address(
+                    Ipv4Addr::new(octets[0], octets[1], octets[2], octets[3]).to_string(),
+                )
+            }
+            FieldType::Ipv6 => {
+                let mut octets = [0; 16];
+                octets.copy_from_slice(self.take(16, name)?);
+                FieldValue::Address(Ipv6Addr::from(octets).to_string())
+            }
+            FieldType::Group { count, fields } => {
+                let mut groups = vec![];
+                match count {
+                    Length::Remaining => {
+                        while !self.is_empty() {
+                            let position = self.position;
+                            groups.push(self.fields(fields)?);
+                            if self.position == position {
+                                return Err(TlvError::InvalidValue(format!(
+                                    "{} consumes no octets",
+                                    name
+                                )));
+                            }
+                        }
+                    }
+                    count => {
+                        for _ in 0..self.length(*count, name)? {
+                            groups.push(self.fields(fields)?);
+                        }
+                    }
+                }
+                FieldValue::Groups(groups)
+            }
+        })
+    }
+}
+
+/// Organizationally Specific TLV decoded according to a [TlvLayout]
+///
+/// The raw value is kept, so that the TLV is encoded exactly as received.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutTlv {
+    /// Layout the value was decoded with
+    pub layout: Arc<TlvLayout>,
+    /// Decoded fields, in order
+    pub fields: Vec<(String, FieldValue)>,
+    /// Raw value
+    pub value: Vec<u8>,
+}
+
+impl LayoutTlv {
+    /// Decode `value` according to `layout`.
+    ///
+    /// Fails if the value is too short for the fields or has octets left over.
+    pub fn decode(layout: Arc<TlvLayout>, value: &[u8]) -> Result<LayoutTlv, TlvError> {
+        let mut reader = Reader { value, position: 0 };
+        let fields = reader.fields(&layout.fields)?;
+        if !reader.is_empty() {
+            return Err(TlvError::InvalidValue(format!(
+                "{} octets left after the last field",
+                value.len() - reader.position
+            )));
+        }
+        Ok(LayoutTlv {
+            layout,
+            fields,
+            value: value.to_vec(),
+        })
+    }
+
+    /// Get the value of the field `name`.
+    pub fn get(&self, name: &str) -> Option<&FieldValue> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value)
+    }
+}
+
+impl Display for LayoutTlv {
+    /// Write the fields, e.g. `flags=up|tagged, mode=active`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", fields_string(&self.fields))
+    }
+}
+
+impl OrgTlvPayload for LayoutTlv {
+    fn oui(&self) -> [u8; 3] {
+        self.layout.oui
+    }
+
+    fn subtype(&self) -> u8 {
+        self.layout.subtype
+    }
+
+    fn name(&self) -> String {
+        self.layout.name.clone()
+    }
+
+    fn value(&self) -> Vec<u8> {
+        self.value.clone()
+    }
+
+    fn to_json(&self) -> Json {
+        fields_json(&self.fields)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::organizationallyspecific_tlv::registry::OrgTlvRegistry;
+
+    const LAYOUTS: &str = r#"
+[[tlv]]
+oui = "AC-DE-48"
+subtype = 1
+name = "Example Status"
+organization = "Example"
+
+[[tlv.field]]
+name = "flags"
+type = "bitflags"
+flags = { 0 = "up", 7 = "tagged" }
+
+[[tlv.field]]
+name = "mode"
+type = "enum"
+size = 2
+values = { 1 = "pending", 2 = "active" }
+
+[[tlv.field]]
+name = "label"
+type = "string"
+length_prefix = 1
+
+[[tlv.field]]
+name = "peers"
+type = "group"
+count_prefix = 1
+field = [
+    { name = "mac", type = "mac" },
+    { name = "address", type = "ipv4" },
+]
+
+[[tlv.field]]
+name = "extra"
+type = "bytes"
+
+[[tlv]]
+oui = "AC:DE:48"
+subtype = 2
+name = "Example Address"
+field = [{ name = "address", type = "ipv6" }, { name = "id", type = "u32" }]
+"#;
+
+    #[test]
+    fn test_decode() {
+        let mut registry = OrgTlvRegistry::new();
+        assert_eq!(registry.load_layouts(LAYOUTS), Ok(2));
+        assert_eq!(registry.organization([0xAC, 0xDE, 0x48]), Some("Example"));
+
+        let value = b"\x81\x00\x02\x03lab\x01\x00\x11\x22\x33\x44\x55\xc0\xa8\x00\x01\xbe\xef";
+        let payload = registry
+            .decode([0xAC, 0xDE, 0x48], 1, value)
+            .unwrap()
+            .unwrap();
+        assert_eq!(payload.name(), "Example Status");
+        assert_eq!(payload.value(), value.to_vec());
+        assert_eq!(
+            format!("{}", payload),
+            "flags=up|tagged, mode=active, label=lab, \
+             peers=[mac=00:11:22:33:44:55, address=192.168.0.1], extra=BEEF"
+        );
+        assert_eq!(
+            format!("{}", payload.to_json()),
+            "{\"flags\":[\"up\",\"tagged\"],\"mode\":\"active\",\"label\":\"lab\",\
+             \"peers\":[{\"mac\":\"00:11:22:33:44:55\",\"address\":\"192.168.0.1\"}],\"extra\":\"beef\"}"
+        );
+
+        let tlv = payload.as_any().downcast_ref::<LayoutTlv>().unwrap();
+        assert_eq!(
+            tlv.get("mode"),
+            Some(&FieldValue::Enum(2, Some(String::from("active"))))
+        );
+
+        let mut value = [0; 20];
+        value[0] = 0xfe;
+        value[15] = 0x01;
+        value[19] = 0x2a;
+        let payload = registry
+            .decode([0xAC, 0xDE, 0x48], 2, &value)
+            .unwrap()
+            .unwrap();
+        assert_eq!(format!("{}", payload), "address=fe00::1, id=42");
+    }
+
+    #[test]
+    fn test_organizationally_specific_tlv() {
+        use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+
+        let mut registry = OrgTlvRegistry::new();
+        registry
+            .load_layouts(
+                "[[tlv]]\noui = \"AC-DE-48\"\nsubtype = 3\nname = \"Example Port\"\n\
+                 field = [{ name = \"port\", type = \"u16\" }]\n",
+            )
+            .unwrap();
+
+        let tlv =
+            OrganizationallySpecificTLV::new(b"\xac\xde\x48".to_vec(), 3, b"\x00\x07".to_vec());
+        assert_eq!(tlv.value_string(&registry), "Example Port: port=7");
+        assert_eq!(
+            format!("{}", tlv.to_json_with(&registry)),
+            "{\"oui\":\"AC-DE-48\",\"subtype\":3,\"organization\":null,\"name\":\"Example Port\",\
+             \"value\":{\"port\":7},\"raw\":\"0007\"}"
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        let mut registry = OrgTlvRegistry::new();
+        registry.load_layouts(LAYOUTS).unwrap();
+
+        // Truncated string
+        assert!(registry
+            .decode([0xAC, 0xDE, 0x48], 1, b"\x00\x00\x01\x05ab")
+            .unwrap()
+            .is_err());
+        // Octets left after the last field
+        assert!(registry
+            .decode([0xAC, 0xDE, 0x48], 2, &[0; 21])
+            .unwrap()
+            .is_err());
+
+        // Repeated group which consumes no octets
+        registry
+            .load_layouts(
+                "[[tlv]]\noui = \"AC-DE-48\"\nsubtype = 4\nname = \"Empty\"\n\
+                 field = [{ name = \"g\", type = \"group\", \
+                 field = [{ name = \"b\", type = \"bytes\", length = 0 }] }]\n",
+            )
+            .unwrap();
+        assert!(matches!(
+            registry.decode([0xAC, 0xDE, 0x48], 4, &[1]).unwrap(),
+            Err(TlvError::InvalidValue(message)) if message == "g consumes no octets"
+        ));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let layout = |field: &str| {
+            TlvLayout::parse_all(&format!(
+                "[[tlv]]\noui = \"AC-DE-48\"\nsubtype = 1\nname = \"x\"\nfield = [{}]\n",
+                field
+            ))
+        };
+        assert!(layout("{ name = \"a\", type = \"u8\" }").is_ok());
+        assert_eq!(
+            layout("{ name = \"a\", type = \"u64\" }"),
+            Err(LayoutError::Invalid(String::from(
+                "tlv 1 (AC-DE-48 subtype 1), field 1 (a): unknown type \"u64\""
+            )))
+        );
+        assert!(layout("{ name = \"a\", type = \"enum\", size = 3 }").is_err());
+        assert!(layout("{ name = \"a\", type = \"bitflags\", flags = { 8 = \"x\" } }").is_err());
+        assert!(
+            layout("{ name = \"a\", type = \"string\", length = 2, length_prefix = 1 }").is_err()
+        );
+        assert!(layout("{ name = \"a\", type = \"group\" }").is_err());
+        assert!(matches!(
+            TlvLayout::parse_all("[[tlv]\n"),
+            Err(LayoutError::Toml(_))
+        ));
+        assert!(TlvLayout::parse_all("[[tlv]]\noui = \"AC-DE\"\nsubtype = 1\n").is_err());
+        let oui = |oui: &str| {
+            TlvLayout::parse_all(&format!(
+                "[[tlv]]\noui = \"{}\"\nsubtype = 1\nname = \"x\"\n\
+                 field = [{{ name = \"a\", type = \"u8\" }}]\n",
+                oui
+            ))
+        };
+        assert!(oui("00-12-0F").is_ok());
+        assert!(oui("0:12:f").is_ok());
+        assert_eq!(
+            oui("ZZ-00-12-0F"),
+            Err(LayoutError::Invalid(String::from(
+                "tlv 1: invalid OUI \"ZZ-00-12-0F\""
+            )))
+        );
+        assert!(oui("00-12-0F-00").is_err());
+        assert!(oui("00-12").is_err());
+        assert!(oui("+0-12-0F").is_err());
+        assert!(oui("000-12-0F").is_err());
+    }
+}
@@ -0,0 +1,866 @@
+//! ANSI/TIA-1057 LLDP Media Endpoint Discovery TLVs (OUI 00-12-BB)
+//!
+//! LLDP-MED lets network connectivity devices provision media endpoints such as IP phones, e.g. with the VLAN and QoS
+//! settings of the voice application.
+
+use crate::json::Json;
+use crate::tlv::organizationallyspecific_tlv::ieee8023::power::{
+    source_name, PortClass, PowerPriority,
+};
+use crate::tlv::organizationallyspecific_tlv::registry::{
+    OrgTlvDecode, OrgTlvPayload, OrgTlvRegistry,
+};
+use crate::tlv::{check_length, TlvError, TlvType};
+
+use std::any::Any;
+use std::fmt::Display;
+
+pub mod location;
+
+/// OUI of the TIA TR-41 committee
+pub const MED_OUI: [u8; 3] = [0x00, 0x12, 0xBB];
+
+/// Maximum length of the value of an [Inventory] TLV in octets
+pub const MAX_INVENTORY_LENGTH: usize = 32;
+
+/// Names of the LLDP-MED capabilities, by bit position
+const CAPABILITY_NAMES: [&str; 6] = [
+    "Capabilities",
+    "Network Policy",
+    "Location Identification",
+    "Extended Power via MDI-PSE",
+    "Extended Power via MDI-PD",
+    "Inventory",
+];
+
+/// Register the LLDP-MED TLVs.
+pub(crate) fn register(registry: &mut OrgTlvRegistry) {
+    registry.register_organization(MED_OUI, "TIA TR-41");
+    registry.register_type::<MedCapabilities>();
+    registry.register_type::<NetworkPolicy>();
+    registry.register_type::<ExtendedPowerViaMdi>();
+    registry.register_type::<Inventory>();
+    location::register(registry);
+}
+
+/// LLDP-MED device type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MedDeviceType {
+    /// Type not defined (0)
+    NotDefined,
+    /// Generic endpoint, class I (1)
+    EndpointClassI,
+    /// Media endpoint, class II (2)
+    EndpointClassII,
+    /// Communication device endpoint, class III (3)
+    EndpointClassIII,
+    /// Network connectivity device, e.g. a switch (4)
+    NetworkConnectivity,
+    /// Reserved value
+    Reserved(u8),
+}
+
+impl From<u8> for MedDeviceType {
+    fn from(value: u8) -> MedDeviceType {
+        match value {
+            0 => MedDeviceType::NotDefined,
+            1 => MedDeviceType::EndpointClassI,
+            2 => MedDeviceType::EndpointClassII,
+            3 => MedDeviceType::EndpointClassIII,
+            4 => MedDeviceType::NetworkConnectivity,
+            value => MedDeviceType::Reserved(value),
+        }
+    }
+}
+
+impl From<MedDeviceType> for u8 {
+    fn from(device_type: MedDeviceType) -> u8 {
+        match device_type {
+            MedDeviceType::NotDefined => 0,
+            MedDeviceType::EndpointClassI => 1,
+            MedDeviceType::EndpointClassII => 2,
+            MedDeviceType::EndpointClassIII => 3,
+            MedDeviceType::NetworkConnectivity => 4,
+            MedDeviceType::Reserved(value) => value,
+        }
+    }
+}
+
+impl Display for MedDeviceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MedDeviceType::NotDefined => write!(f, "type not defined"),
+            MedDeviceType::EndpointClassI => write!(f, "Endpoint Class I"),
+            MedDeviceType::EndpointClassII => write!(f, "Endpoint Class II"),
+            MedDeviceType::EndpointClassIII => write!(f, "Endpoint Class III"),
+            MedDeviceType::NetworkConnectivity => write!(f, "Network Connectivity"),
+            MedDeviceType::Reserved(value) => write!(f, "reserved device type {}", value),
+        }
+    }
+}
+
+/// LLDP-MED Capabilities TLV (subtype 1)
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               8               9
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |             |                 |               |               |   LLDP-MED    |   LLDP-MED    |
+/// |     127     |        7        |   00-12-BB    |       1       | Capabilities  |  Device Type  |
+/// |             |                 |               |               |               |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// | Bit  | Capability                     |
+/// |------|--------------------------------|
+/// | 0    | LLDP-MED Capabilities          |
+/// | 1    | Network Policy                 |
+/// | 2    | Location Identification        |
+/// | 3    | Extended Power via MDI-PSE     |
+/// | 4    | Extended Power via MDI-PD      |
+/// | 5    | Inventory                      |
+/// | 6-15 | Reserved                       |
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MedCapabilities {
+    /// Bitmap of the supported LLDP-MED TLVs
+    pub capabilities: u16,
+    /// Device type of the sender
+    pub device_type: MedDeviceType,
+}
+
+impl MedCapabilities {
+    /// Constructor
+    pub fn new(capabilities: u16, device_type: MedDeviceType) -> MedCapabilities {
+        MedCapabilities {
+            capabilities,
+            device_type,
+        }
+    }
+
+    /// Get the names of the supported capabilities.
+    pub fn capability_names(&self) -> Vec<String> {
+        (0..16)
+            .filter(|bit| self.capabilities & (1 << bit) != 0)
+            .map(|bit| match CAPABILITY_NAMES.get(bit) {
+                Some(name) => name.to_string(),
+                None => format!("Bit {}", bit),
+            })
+            .collect()
+    }
+}
+
+impl Display for MedCapabilities {
+    /// Write the TLV, e.g. `Network Connectivity [Capabilities, Network Policy]`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} [{}]",
+            self.device_type,
+            self.capability_names().join(", ")
+        )
+    }
+}
+
+impl OrgTlvPayload for MedCapabilities {
+    fn oui(&self) -> [u8; 3] {
+        MED_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        1
+    }
+
+    fn name(&self) -> String {
+        String::from("LLDP-MED Capabilities")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let mut value = self.capabilities.to_be_bytes().to_vec();
+        value.push(self.device_type.into());
+        value
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("capabilities", self.capability_names().into()),
+            ("device_type", u8::from(self.device_type).into()),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for MedCapabilities {
+    const KEYS: &'static [([u8; 3], u8)] = &[(MED_OUI, 1)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 3, 3)?;
+        Ok(MedCapabilities::new(
+            u16::from_be_bytes([value[0], value[1]]),
+            value[2].into(),
+        ))
+    }
+}
+
+/// Application type of a [NetworkPolicy]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApplicationType {
+    /// Voice (1)
+    Voice,
+    /// Voice signaling (2)
+    VoiceSignaling,
+    /// Guest voice (3)
+    GuestVoice,
+    /// Guest voice signaling (4)
+    GuestVoiceSignaling,
+    /// Softphone voice (5)
+    SoftphoneVoice,
+    /// Video conferencing (6)
+    VideoConferencing,
+    /// Streaming video (7)
+    StreamingVideo,
+    /// Video signaling (8)
+    VideoSignaling,
+    /// Reserved value
+    Reserved(u8),
+}
+
+impl From<u8> for ApplicationType {
+    fn from(value: u8) -> ApplicationType {
+        match value {
+            1 => ApplicationType::Voice,
+            2 => ApplicationType::VoiceSignaling,
+            3 => ApplicationType::GuestVoice,
+            4 => ApplicationType::GuestVoiceSignaling,
+            5 => ApplicationType::SoftphoneVoice,
+            6 => ApplicationType::VideoConferencing,
+            7 => ApplicationType::StreamingVideo,
+            8 => ApplicationType::VideoSignaling,
+            value => ApplicationType::Reserved(value),
+        }
+    }
+}
+
+impl From<ApplicationType> for u8 {
+    fn from(application: ApplicationType) -> u8 {
+        match application {
+            ApplicationType::Voice => 1,
+            ApplicationType::VoiceSignaling => 2,
+            ApplicationType::GuestVoice => 3,
+            ApplicationType::GuestVoiceSignaling => 4,
+            ApplicationType::SoftphoneVoice => 5,
+            ApplicationType::VideoConferencing => 6,
+            ApplicationType::StreamingVideo => 7,
+            ApplicationType::VideoSignaling => 8,
+            ApplicationType::Reserved(value) => value,
+        }
+    }
+}
+
+impl Display for ApplicationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApplicationType::Voice => write!(f, "Voice"),
+            ApplicationType::VoiceSignaling => write!(f, "Voice Signaling"),
+            ApplicationType::GuestVoice => write!(f, "Guest Voice"),
+            ApplicationType::GuestVoiceSignaling => write!(f, "Guest Voice Signaling"),
+            ApplicationType::SoftphoneVoice => write!(f, "Softphone Voice"),
+            ApplicationType::VideoConferencing => write!(f, "Video Conferencing"),
+            ApplicationType::StreamingVideo => write!(f, "Streaming Video"),
+            ApplicationType::VideoSignaling => write!(f, "Video Signaling"),
+            ApplicationType::Reserved(value) => write!(f, "application type {}", value),
+        }
+    }
+}
+
+/// Network Policy TLV (subtype 2)
+///
+/// Advertises the VLAN and QoS settings of an application. An LLDPDU carries one Network Policy TLV per application.
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               7                               10
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+
+/// |             |                 |               |               |  Application  |U|T|X|   VLAN ID   | L2  |  DSCP |
+/// |     127     |        8        |   00-12-BB    |       2       |     Type      | | | |  (12 bits)  |Prio.| Value |
+/// |             |                 |               |               |               | | | |             |(3 b)|(6 b)  |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+
+/// ```
+///
+/// The unknown policy flag (U) indicates that the sender needs the policy but does not know it, in which case the
+/// remaining fields are ignored. The tagged flag (T) indicates that the application uses a tagged VLAN; untagged
+/// applications use VLAN ID 0 and a priority of 0. X is reserved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetworkPolicy {
+    /// Application the policy applies to
+    pub application: ApplicationType,
+    /// The policy is required but unknown to the sender
+    pub unknown: bool,
+    /// The application uses a tagged VLAN
+    pub tagged: bool,
+    /// VLAN identifier, 0 for the port VLAN
+    pub vid: u16,
+    /// Layer 2 priority (802.1p)
+    pub priority: u8,
+    /// Differentiated services code point
+    pub dscp: u8,
+}
+
+impl NetworkPolicy {
+    /// Constructor
+    ///
+    /// Values exceeding their fields are truncated on encoding, use [NetworkPolicy::try_new] to reject them.
+    pub fn new(
+        application: ApplicationType,
+        unknown: bool,
+        tagged: bool,
+        vid: u16,
+        priority: u8,
+        dscp: u8,
+    ) -> NetworkPolicy {
+        NetworkPolicy {
+            application,
+            unknown,
+            tagged,
+            vid,
+            priority,
+            dscp,
+        }
+    }
+
+    /// Create a Network Policy TLV.
+    ///
+    /// Fails if the VLAN ID exceeds 4095, the priority 7 or the DSCP 63.
+    pub fn try_new(
+        application: ApplicationType,
+        unknown: bool,
+        tagged: bool,
+        vid: u16,
+        priority: u8,
+        dscp: u8,
+    ) -> Result<NetworkPolicy, TlvError> {
+        if vid > 0x0FFF {
+            return Err(TlvError::InvalidValue(format!("VLAN ID {}", vid)));
+        }
+        if priority > 7 {
+            return Err(TlvError::InvalidValue(format!(
+                "layer 2 priority {}",
+                priority
+            )));
+        }
+        if dscp > 0x3F {
+            return Err(TlvError::InvalidValue(format!("DSCP {}", dscp)));
+        }
+        Ok(NetworkPolicy::new(
+            application,
+            unknown,
+            tagged,
+            vid,
+            priority,
+            dscp,
+        ))
+    }
+}
+
+impl Display for NetworkPolicy {
+    /// Write the TLV, e.g. `Voice: VLAN 200 tagged, priority 5, DSCP 46`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.unknown {
+            return write!(f, "{}: unknown policy", self.application);
+        }
+        write!(
+            f,
+            "{}: VLAN {} {}, priority {}, DSCP {}",
+            self.application,
+            self.vid,
+            if self.tagged { "tagged" } else { "untagged" },
+            self.priority,
+            self.dscp
+        )
+    }
+}
+
+impl OrgTlvPayload for NetworkPolicy {
+    fn oui(&self) -> [u8; 3] {
+        MED_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        2
+    }
+
+    fn name(&self) -> String {
+        String::from("Network Policy")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let policy = (self.unknown as u32) << 23
+            | (self.tagged as u32) << 22
+            | ((self.vid & 0x0FFF) as u32) << 9
+            | ((self.priority & 0x07) as u32) << 6
+            | (self.dscp & 0x3F) as u32;
+        let mut value = vec![self.application.into()];
+        value.extend_from_slice(&policy.to_be_bytes()[1..]);
+        value
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("application", self.application.to_string().into()),
+            ("unknown", self.unknown.into()),
+            ("tagged", self.tagged.into()),
+            ("vid", self.vid.into()),
+            ("priority", self.priority.into()),
+            ("dscp", self.dscp.into()),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for NetworkPolicy {
+    const KEYS: &'static [([u8; 3], u8)] = &[(MED_OUI, 2)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 4, 4)?;
+        let policy = u32::from_be_bytes([0, value[1], value[2], value[3]]);
+        Ok(NetworkPolicy::new(
+            value[0].into(),
+            policy & (1 << 23) != 0,
+            policy & (1 << 22) != 0,
+            ((policy >> 9) & 0x0FFF) as u16,
+            ((policy >> 6) & 0x07) as u8,
+            (policy & 0x3F) as u8,
+        ))
+    }
+}
+
+/// Extended Power via MDI TLV (subtype 4)
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               7               9
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |             |                 |               |               |Power|Power|Pow|     Power     |
+/// |     127     |        7        |   00-12-BB    |       4       |Type |Src  |Pri|     Value     |
+/// |             |                 |               |               |(2b) |(2b) |4b |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// The power type is 0 for a PSE and 1 for a PD. The power source is encoded like the one of the 802.3at
+/// [Power via MDI](crate::tlv::organizationallyspecific_tlv::ieee8023::power::Dot3atPower) extension. The power value
+/// is the power a PSE can supply or a PD requires, in units of 0.1 W.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExtendedPowerViaMdi {
+    /// Whether the sender is a PSE or a PD
+    pub power_type: PortClass,
+    /// Power source, see [source_name](ExtendedPowerViaMdi::source_name)
+    pub source: u8,
+    /// Power priority
+    pub priority: PowerPriority,
+    /// Power value in units of 0.1 W
+    pub power: u16,
+}
+
+impl ExtendedPowerViaMdi {
+    /// Constructor
+    pub fn new(
+        power_type: PortClass,
+        source: u8,
+        priority: PowerPriority,
+        power: u16,
+    ) -> ExtendedPowerViaMdi {
+        ExtendedPowerViaMdi {
+            power_type,
+            source,
+            priority,
+            power,
+        }
+    }
+
+    /// Get the name of the power source, which depends on whether the sender is a PSE or a PD.
+    pub fn source_name(&self) -> &'static str {
+        source_name(self.power_type == PortClass::Pd, self.source)
+    }
+}
+
+impl Display for ExtendedPowerViaMdi {
+    /// Write the TLV, e.g. `PD, PSE source, priority high, 12.9 W`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}, {}, priority {}, {}.{} W",
+            self.power_type,
+            self.source_name(),
+            self.priority,
+            self.power / 10,
+            self.power % 10
+        )
+    }
+}
+
+impl OrgTlvPayload for ExtendedPowerViaMdi {
+    fn oui(&self) -> [u8; 3] {
+        MED_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        4
+    }
+
+    fn name(&self) -> String {
+        String::from("Extended Power via MDI")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let power_type = match self.power_type {
+            PortClass::Pse => 0,
+            PortClass::Pd => 1,
+        };
+        let mut value = vec![power_type << 6 | (self.source & 0x03) << 4 | u8::from(self.priority)];
+        value.extend_from_slice(&self.power.to_be_bytes());
+        value
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("power_type", self.power_type.to_string().into()),
+            ("source", self.source_name().into()),
+            ("priority", self.priority.to_string().into()),
+            ("power", (self.power as f64 / 10.0).into()),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for ExtendedPowerViaMdi {
+    const KEYS: &'static [([u8; 3], u8)] = &[(MED_OUI, 4)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 3, 3)?;
+        let power_type = match value[0] >> 6 {
+            0 => PortClass::Pse,
+            1 => PortClass::Pd,
+            power_type => return Err(TlvError::InvalidValue(format!("power type {}", power_type))),
+        };
+        // Priorities above low are reserved
+        let priority = match value[0] & 0x0F {
+            priority @ 0..=3 => PowerPriority::from(priority),
+            _ => PowerPriority::Unknown,
+        };
+
+        Ok(ExtendedPowerViaMdi::new(
+            power_type,
+            (value[0] >> 4) & 0x03,
+            priority,
+            u16::from_be_bytes([value[1], value[2]]),
+        ))
+    }
+}
+
+/// Field of an [Inventory] TLV
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InventoryField {
+    /// Hardware revision (subtype 5)
+    HardwareRevision,
+    /// Firmware revision (subtype 6)
+    FirmwareRevision,
+    /// Software revision (subtype 7)
+    SoftwareRevision,
+    /// Serial number (subtype 8)
+    SerialNumber,
+    /// Manufacturer name (subtype 9)
+    Manufacturer,
+    /// Model name (subtype 10)
+    ModelName,
+    /// Asset ID (subtype 11)
+    AssetId,
+}
+
+impl InventoryField {
+    /// All fields, in subtype order
+    pub const ALL: [InventoryField; 7] = [
+        InventoryField::HardwareRevision,
+        InventoryField::FirmwareRevision,
+        InventoryField::SoftwareRevision,
+        InventoryField::SerialNumber,
+        InventoryField::Manufacturer,
+        InventoryField::ModelName,
+        InventoryField::AssetId,
+    ];
+
+    /// Get the field carried by TLVs of the subtype.
+    pub fn from_subtype(subtype: u8) -> Option<InventoryField> {
+        InventoryField::ALL
+            .get((subtype as usize).checked_sub(5)?)
+            .copied()
+    }
+
+    /// Get the subtype of the TLV carrying the field.
+    pub fn subtype(&self) -> u8 {
+        match self {
+            InventoryField::HardwareRevision => 5,
+            InventoryField::FirmwareRevision => 6,
+            InventoryField::SoftwareRevision => 7,
+            InventoryField::SerialNumber => 8,
+            InventoryField::Manufacturer => 9,
+            InventoryField::ModelName => 10,
+            InventoryField::AssetId => 11,
+        }
+    }
+}
+
+impl Display for InventoryField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InventoryField::HardwareRevision => write!(f, "Hardware Revision"),
+            InventoryField::FirmwareRevision => write!(f, "Firmware Revision"),
+            InventoryField::SoftwareRevision => write!(f, "Software Revision"),
+            InventoryField::SerialNumber => write!(f, "Serial Number"),
+            InventoryField::Manufacturer => write!(f, "Manufacturer Name"),
+            InventoryField::ModelName => write!(f, "Model Name"),
+            InventoryField::AssetId => write!(f, "Asset ID"),
+        }
+    }
+}
+
+/// Inventory TLVs (subtypes 5 - 11)
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+
+/// |             |                 |               |               |   Inventory     |
+/// |     127     |     4 + n       |   00-12-BB    |    5 - 11     |     Value       |
+/// |             |                 |               |               |  (0-32 octets)  |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+
+/// ```
+///
+/// Each TLV carries one [InventoryField] as a string of up to 32 octets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inventory {
+    /// The field carried by the TLV
+    pub field: InventoryField,
+    /// Value of the field
+    pub value: String,
+}
+
+impl Inventory {
+    /// Create an inventory TLV.
+    ///
+    /// Fails if the value is longer than [MAX_INVENTORY_LENGTH] octets.
+    pub fn try_new(field: InventoryField, value: String) -> Result<Inventory, TlvError> {
+        if value.len() > MAX_INVENTORY_LENGTH {
+            return Err(TlvError::InvalidValue(format!(
+                "{} of {} octets",
+                field,
+                value.len()
+            )));
+        }
+        Ok(Inventory { field, value })
+    }
+}
+
+impl Display for Inventory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl OrgTlvPayload for Inventory {
+    fn oui(&self) -> [u8; 3] {
+        MED_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        self.field.subtype()
+    }
+
+    fn name(&self) -> String {
+        self.field.to_string()
+    }
+
+    fn value(&self) -> Vec<u8> {
+        self.value.as_bytes().to_vec()
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![("value", self.value.as_str().into())])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for Inventory {
+    const KEYS: &'static [([u8; 3], u8)] = &[
+        (MED_OUI, 5),
+        (MED_OUI, 6),
+        (MED_OUI, 7),
+        (MED_OUI, 8),
+        (MED_OUI, 9),
+        (MED_OUI, 10),
+        (MED_OUI, 11),
+    ];
+
+    fn from_value(key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(
+            TlvType::OrganizationallySpecific,
+            value.len(),
+            0,
+            MAX_INVENTORY_LENGTH,
+        )?;
+        let field = InventoryField::from_subtype(key.1).ok_or(TlvError::SubtypeMismatch {
+            tlv_type: TlvType::OrganizationallySpecific,
+            subtype: key.1,
+        })?;
+        Ok(Inventory {
+            field,
+            value: String::from_utf8_lossy(value).into_owned(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+
+    const CAPABILITIES: &[u8] = b"\xfe\x07\x00\x12\xbb\x01\x00\x0f\x04";
+    const VOICE_POLICY: &[u8] = b"\xfe\x08\x00\x12\xbb\x02\x01\x41\x91\x6e";
+
+    #[test]
+    fn test_capabilities() {
+        let capabilities = MedCapabilities::new(0x0F, MedDeviceType::NetworkConnectivity);
+        let tlv = OrganizationallySpecificTLV::from_payload(&capabilities);
+        assert_eq!(tlv.bytes(), CAPABILITIES.to_vec());
+
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(CAPABILITIES);
+        assert_eq!(tlv.decode_as::<MedCapabilities>(), Some(capabilities));
+        assert_eq!(
+            format!("{}", tlv),
+            "OrganizationallySpecificTLV(\"0012BB\", 1, \"LLDP-MED Capabilities: Network Connectivity \
+             [Capabilities, Network Policy, Location Identification, Extended Power via MDI-PSE]\")"
+        );
+    }
+
+    #[test]
+    fn test_network_policy() {
+        let policy = NetworkPolicy::new(ApplicationType::Voice, false, true, 200, 5, 46);
+        let tlv = OrganizationallySpecificTLV::from_payload(&policy);
+        assert_eq!(tlv.bytes(), VOICE_POLICY.to_vec());
+
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(VOICE_POLICY);
+        assert_eq!(tlv.decode_as::<NetworkPolicy>(), Some(policy));
+        assert_eq!(
+            format!("{}", tlv),
+            "OrganizationallySpecificTLV(\"0012BB\", 2, \"Network Policy: Voice: VLAN 200 tagged, priority 5, \
+             DSCP 46\")"
+        );
+        assert_eq!(
+            format!("{}", policy.to_json()),
+            "{\"application\":\"Voice\",\"unknown\":false,\"tagged\":true,\"vid\":200,\"priority\":5,\"dscp\":46}"
+        );
+    }
+
+    #[test]
+    fn test_network_policy_try_new() {
+        assert_eq!(
+            NetworkPolicy::try_new(ApplicationType::Voice, false, true, 4095, 7, 63),
+            Ok(NetworkPolicy::new(
+                ApplicationType::Voice,
+                false,
+                true,
+                4095,
+                7,
+                63
+            ))
+        );
+        assert_eq!(
+            NetworkPolicy::try_new(ApplicationType::Voice, false, true, 4096, 5, 46),
+            Err(TlvError::InvalidValue(String::from("VLAN ID 4096")))
+        );
+        assert!(NetworkPolicy::try_new(ApplicationType::Voice, false, true, 200, 8, 46).is_err());
+        assert!(NetworkPolicy::try_new(ApplicationType::Voice, false, true, 200, 5, 64).is_err());
+    }
+
+    #[test]
+    fn test_unknown_policy() {
+        let tlv = OrganizationallySpecificTLV::new(
+            b"\x00\x12\xbb".to_vec(),
+            2,
+            b"\x02\x80\x00\x00".to_vec(),
+        );
+        let policy = tlv.decode_as::<NetworkPolicy>().unwrap();
+        assert!(policy.unknown);
+        assert_eq!(format!("{}", policy), "Voice Signaling: unknown policy");
+    }
+
+    #[test]
+    fn test_invalid_length() {
+        let tlv =
+            OrganizationallySpecificTLV::new(b"\x00\x12\xbb".to_vec(), 1, b"\x00\x0f".to_vec());
+        assert!(matches!(
+            tlv.decode().unwrap(),
+            Err(TlvError::InvalidLength { length: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_extended_power() {
+        let bytes = b"\xfe\x07\x00\x12\xbb\x04\x52\x00\x81";
+        let power = ExtendedPowerViaMdi::new(PortClass::Pd, 1, PowerPriority::High, 129);
+        let tlv = OrganizationallySpecificTLV::from_payload(&power);
+        assert_eq!(tlv.bytes(), bytes.to_vec());
+
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(bytes.as_ref());
+        assert_eq!(tlv.decode_as::<ExtendedPowerViaMdi>(), Some(power));
+        assert_eq!(
+            format!("{}", tlv),
+            "OrganizationallySpecificTLV(\"0012BB\", 4, \"Extended Power via MDI: PD, PSE source, priority high, \
+             12.9 W\")"
+        );
+    }
+
+    #[test]
+    fn test_inventory() {
+        let bytes = b"\xfe\x0c\x00\x12\xbb\x08FCH1234X";
+        let inventory =
+            Inventory::try_new(InventoryField::SerialNumber, String::from("FCH1234X")).unwrap();
+        let tlv = OrganizationallySpecificTLV::from_payload(&inventory);
+        assert_eq!(tlv.bytes(), bytes.to_vec());
+
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(bytes.as_ref());
+        assert_eq!(tlv.decode_as::<Inventory>(), Some(inventory));
+        assert_eq!(
+            format!("{}", tlv),
+            "OrganizationallySpecificTLV(\"0012BB\", 8, \"Serial Number: FCH1234X\")"
+        );
+        assert_eq!(
+            format!("{}", tlv.to_json()),
+            "{\"oui\":\"00-12-BB\",\"subtype\":8,\"organization\":\"TIA TR-41\",\"name\":\"Serial Number\",\
+             \"value\":{\"value\":\"FCH1234X\"},\"raw\":\"4643483132333458\"}"
+        );
+    }
+
+    #[test]
+    fn test_inventory_fields() {
+        for field in InventoryField::ALL {
+            assert_eq!(InventoryField::from_subtype(field.subtype()), Some(field));
+        }
+        assert_eq!(InventoryField::from_subtype(12), None);
+        assert!(Inventory::try_new(InventoryField::AssetId, "x".repeat(33)).is_err());
+    }
+}
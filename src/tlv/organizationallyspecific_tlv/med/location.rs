@@ -0,0 +1,753 @@
+//! LLDP-MED Location Identification TLV
+//!
+//! The location of the port is advertised in one of three formats: coordinate-based LCI (RFC 6225), civic address
+//! LCI (RFC 4776) or an Emergency Location Identification Number (ELIN) used by emergency call services.
+
+use crate::json::Json;
+use crate::tlv::organizationallyspecific_tlv::med::MED_OUI;
+use crate::tlv::organizationallyspecific_tlv::registry::{
+    OrgTlvDecode, OrgTlvPayload, OrgTlvRegistry,
+};
+use crate::tlv::{check_length, TlvError, TlvType};
+
+use std::any::Any;
+use std::fmt::Display;
+
+/// Length of a coordinate-based LCI in octets
+pub const COORDINATE_LENGTH: usize = 16;
+
+/// Number of fractional bits of latitude and longitude
+const DEGREE_FRACTION_BITS: u32 = 25;
+/// Number of fractional bits of the altitude
+const ALTITUDE_FRACTION_BITS: u32 = 8;
+
+/// Abbreviations of the civic address types (RFC 4776, section 3.4), by CAtype
+const CA_TYPES: [(u8, &str); 35] = [
+    (0, "language"),
+    (1, "A1"),
+    (2, "A2"),
+    (3, "A3"),
+    (4, "A4"),
+    (5, "A5"),
+    (6, "A6"),
+    (16, "PRD"),
+    (17, "POD"),
+    (18, "STS"),
+    (19, "HNO"),
+    (20, "HNS"),
+    (21, "LMK"),
+    (22, "LOC"),
+    (23, "NAM"),
+    (24, "PC"),
+    (25, "BLD"),
+    (26, "UNIT"),
+    (27, "FLR"),
+    (28, "ROOM"),
+    (29, "PLC"),
+    (30, "PCN"),
+    (31, "POBOX"),
+    (32, "ADDCODE"),
+    (33, "SEAT"),
+    (34, "RD"),
+    (35, "RDSEC"),
+    (36, "RDBR"),
+    (37, "RDSUBBR"),
+    (38, "PRM"),
+    (39, "POM"),
+    (40, "PN"),
+    (41, "PNS"),
+    (42, "PNB"),
+    (128, "script"),
+];
+
+/// Register the Location Identification TLV.
+pub(crate) fn register(registry: &mut OrgTlvRegistry) {
+    registry.register_type::<LocationIdentification>();
+}
+
+/// Sign extend the lowest `bits` bits of `value`.
+fn sign_extend(value: u128, bits: u32) -> i64 {
+    ((value as i64) << (64 - bits)) >> (64 - bits)
+}
+
+/// Convert `value` to a two's complement fixed-point number of `bits` bits with `fraction` fractional bits.
+fn fixed_point(value: f64, bits: u32, fraction: u32) -> u128 {
+    let fixed = (value * (1u64 << fraction) as f64).round() as i64;
+    (fixed as u128) & ((1u128 << bits) - 1)
+}
+
+/// Type of the altitude of a [CoordinateLocation]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AltitudeType {
+    /// No altitude given (0)
+    Unknown,
+    /// Altitude in meters (1)
+    Meters,
+    /// Altitude in floors (2)
+    Floors,
+    /// Reserved value
+    Reserved(u8),
+}
+
+impl From<u8> for AltitudeType {
+    fn from(value: u8) -> AltitudeType {
+        match value {
+            0 => AltitudeType::Unknown,
+            1 => AltitudeType::Meters,
+            2 => AltitudeType::Floors,
+            value => AltitudeType::Reserved(value),
+        }
+    }
+}
+
+impl From<AltitudeType> for u8 {
+    fn from(altitude_type: AltitudeType) -> u8 {
+        match altitude_type {
+            AltitudeType::Unknown => 0,
+            AltitudeType::Meters => 1,
+            AltitudeType::Floors => 2,
+            AltitudeType::Reserved(value) => value,
+        }
+    }
+}
+
+/// Geodetic datum of a [CoordinateLocation]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Datum {
+    /// WGS 84 (1)
+    Wgs84,
+    /// NAD83 with NAVD88 vertical datum (2)
+    Nad83Navd88,
+    /// NAD83 with Mean Lower Low Water vertical datum (3)
+    Nad83Mllw,
+    /// Reserved value
+    Reserved(u8),
+}
+
+impl From<u8> for Datum {
+    fn from(value: u8) -> Datum {
+        match value {
+            1 => Datum::Wgs84,
+            2 => Datum::Nad83Navd88,
+            3 => Datum::Nad83Mllw,
+            value => Datum::Reserved(value),
+        }
+    }
+}
+
+impl From<Datum> for u8 {
+    fn from(datum: Datum) -> u8 {
+        match datum {
+            Datum::Wgs84 => 1,
+            Datum::Nad83Navd88 => 2,
+            Datum::Nad83Mllw => 3,
+            Datum::Reserved(value) => value,
+        }
+    }
+}
+
+impl Display for Datum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Datum::Wgs84 => write!(f, "WGS84"),
+            Datum::Nad83Navd88 => write!(f, "NAD83/NAVD88"),
+            Datum::Nad83Mllw => write!(f, "NAD83/MLLW"),
+            Datum::Reserved(value) => write!(f, "datum {}", value),
+        }
+    }
+}
+
+/// Coordinate-based location configuration information (RFC 6225)
+///
+/// # Format:
+///
+/// ```text
+///  0                   1                   2                   3
+///  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |   LaRes   |                     Latitude                      |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |Lat|   LoRes   |                   Longitude                   |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// | Longitude |  AT   |  AltRes   |          Altitude             |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |         Altitude          |Ver| Res |Datum|
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// Latitude and longitude are 34 bit two's complement fixed-point numbers of degrees with 25 fractional bits, the
+/// altitude a 30 bit two's complement fixed-point number with 8 fractional bits. The resolutions give the number of
+/// valid bits of the respective value. Senders following RFC 3825 put the datum into the whole last octet, which
+/// decodes to the same datum with version 0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoordinateLocation {
+    /// Number of valid bits of the latitude
+    pub latitude_resolution: u8,
+    /// Latitude in degrees, positive north of the equator
+    pub latitude: f64,
+    /// Number of valid bits of the longitude
+    pub longitude_resolution: u8,
+    /// Longitude in degrees, positive east of the prime meridian
+    pub longitude: f64,
+    /// Unit of the altitude
+    pub altitude_type: AltitudeType,
+    /// Number of valid bits of the altitude
+    pub altitude_resolution: u8,
+    /// Altitude in meters or floors
+    pub altitude: f64,
+    /// Version of the LCI format (RFC 6225)
+    pub version: u8,
+    /// Geodetic datum
+    pub datum: Datum,
+}
+
+impl CoordinateLocation {
+    /// Create a location with full resolution.
+    ///
+    /// Fails if the latitude or longitude are out of range, or if the altitude does not fit into its 30 bit field
+    /// (about ±2,097,152 meters or floors).
+    pub fn try_new(
+        latitude: f64,
+        longitude: f64,
+        altitude_type: AltitudeType,
+        altitude: f64,
+        datum: Datum,
+    ) -> Result<CoordinateLocation, TlvError> {
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(TlvError::InvalidValue(format!("latitude {}", latitude)));
+        }
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(TlvError::InvalidValue(format!("longitude {}", longitude)));
+        }
+        let fixed = (altitude * (1u64 << ALTITUDE_FRACTION_BITS) as f64).round();
+        let limit = (1u64 << 29) as f64;
+        if !(-limit..limit).contains(&fixed) {
+            return Err(TlvError::InvalidValue(format!("altitude {}", altitude)));
+        }
+
+        Ok(CoordinateLocation {
+            latitude_resolution: 34,
+            latitude,
+            longitude_resolution: 34,
+            longitude,
+            altitude_type,
+            altitude_resolution: 30,
+            altitude,
+            version: 0,
+            datum,
+        })
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let lci = ((self.latitude_resolution & 0x3F) as u128) << 122
+            | fixed_point(self.latitude, 34, DEGREE_FRACTION_BITS) << 88
+            | ((self.longitude_resolution & 0x3F) as u128) << 82
+            | fixed_point(self.longitude, 34, DEGREE_FRACTION_BITS) << 48
+            | ((u8::from(self.altitude_type) & 0x0F) as u128) << 44
+            | ((self.altitude_resolution & 0x3F) as u128) << 38
+            | fixed_point(self.altitude, 30, ALTITUDE_FRACTION_BITS) << 8
+            | ((self.version & 0x03) as u128) << 6
+            | (u8::from(self.datum) & 0x07) as u128;
+        lci.to_be_bytes().to_vec()
+    }
+
+    fn decode(value: &[u8]) -> CoordinateLocation {
+        let mut octets = [0; COORDINATE_LENGTH];
+        octets.copy_from_slice(value);
+        let lci = u128::from_be_bytes(octets);
+        let degrees = |offset: u32| {
+            sign_extend(lci >> offset, 34) as f64 / (1u64 << DEGREE_FRACTION_BITS) as f64
+        };
+
+        CoordinateLocation {
+            latitude_resolution: (lci >> 122) as u8 & 0x3F,
+            latitude: degrees(88),
+            longitude_resolution: (lci >> 82) as u8 & 0x3F,
+            longitude: degrees(48),
+            altitude_type: ((lci >> 44) as u8 & 0x0F).into(),
+            altitude_resolution: (lci >> 38) as u8 & 0x3F,
+            altitude: sign_extend(lci >> 8, 30) as f64 / (1u64 << ALTITUDE_FRACTION_BITS) as f64,
+            version: (lci >> 6) as u8 & 0x03,
+            datum: (lci as u8 & 0x07).into(),
+        }
+    }
+
+    fn to_json(self) -> Json {
+        Json::object(vec![
+            ("latitude", self.latitude.into()),
+            ("latitude_resolution", self.latitude_resolution.into()),
+            ("longitude", self.longitude.into()),
+            ("longitude_resolution", self.longitude_resolution.into()),
+            ("altitude_type", u8::from(self.altitude_type).into()),
+            ("altitude", self.altitude.into()),
+            ("altitude_resolution", self.altitude_resolution.into()),
+            ("datum", self.datum.to_string().into()),
+        ])
+    }
+}
+
+impl Display for CoordinateLocation {
+    /// Write the location, e.g. `52.516275, 13.377704, 34 m (WGS84)`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.6}, {:.6}", self.latitude, self.longitude)?;
+        match self.altitude_type {
+            AltitudeType::Unknown => {}
+            AltitudeType::Meters => write!(f, ", {} m", self.altitude)?,
+            AltitudeType::Floors => write!(f, ", floor {}", self.altitude)?,
+            AltitudeType::Reserved(value) => {
+                write!(f, ", altitude {} (type {})", self.altitude, value)?
+            }
+        }
+        write!(f, " ({})", self.datum)
+    }
+}
+
+/// Civic address element, a CAtype and its value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CivicAddressElement {
+    /// Civic address type, e.g. 3 for the city
+    pub ca_type: u8,
+    /// Value of the element
+    pub value: String,
+}
+
+impl CivicAddressElement {
+    /// Constructor
+    pub fn new(ca_type: u8, value: String) -> CivicAddressElement {
+        CivicAddressElement { ca_type, value }
+    }
+
+    /// Get the RFC 4776 abbreviation of the CAtype, e.g. `A3` for the city.
+    pub fn type_name(&self) -> Option<&'static str> {
+        CA_TYPES
+            .iter()
+            .find(|(ca_type, _)| *ca_type == self.ca_type)
+            .map(|(_, name)| *name)
+    }
+}
+
+impl Display for CivicAddressElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.type_name() {
+            Some(name) => write!(f, "{}={}", name, self.value),
+            None => write!(f, "CAtype {}={}", self.ca_type, self.value),
+        }
+    }
+}
+
+/// Civic address location configuration information (RFC 4776)
+///
+/// # Format:
+///
+/// ```text
+///  0               1               2               4               5               6
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...
+/// |      LCI      |     What      |    Country    |    CAtype     |   CAlength    |  CAvalue ...
+/// |    Length     |               |     Code      |               |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...
+/// ```
+///
+/// The LCI length counts the octets following it. What tells which location is described: 0 for the DHCP server,
+/// 1 for the network element closest to the client and 2 for the client itself. The two letter ISO 3166 country code
+/// is followed by any number of CAtype/CAlength/CAvalue elements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CivicLocation {
+    /// Which location is described
+    pub what: u8,
+    /// ISO 3166 country code, e.g. `DE`
+    pub country: String,
+    /// Address elements
+    pub elements: Vec<CivicAddressElement>,
+}
+
+impl CivicLocation {
+    /// Create a civic address describing the location of the client.
+    ///
+    /// Fails if the country code does not consist of two letters or the address does not fit into the LCI.
+    pub fn try_new(
+        country: String,
+        elements: Vec<CivicAddressElement>,
+    ) -> Result<CivicLocation, TlvError> {
+        if country.len() != 2 || !country.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(TlvError::InvalidValue(format!(
+                "country code {:?}",
+                country
+            )));
+        }
+        let length: usize = 3 + elements
+            .iter()
+            .map(|element| 2 + element.value.len())
+            .sum::<usize>();
+        if let Some(element) = elements.iter().find(|element| element.value.len() > 255) {
+            return Err(TlvError::InvalidValue(format!(
+                "civic address element of {} octets",
+                element.value.len()
+            )));
+        }
+        if length > 255 {
+            return Err(TlvError::InvalidValue(format!(
+                "civic address of {} octets",
+                length
+            )));
+        }
+
+        Ok(CivicLocation {
+            what: 2,
+            country,
+            elements,
+        })
+    }
+
+    /// Get the value of the first element of the CAtype.
+    pub fn element(&self, ca_type: u8) -> Option<&str> {
+        self.elements
+            .iter()
+            .find(|element| element.ca_type == ca_type)
+            .map(|element| element.value.as_str())
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut lci = vec![self.what];
+        lci.extend_from_slice(self.country.as_bytes());
+        for element in &self.elements {
+            lci.push(element.ca_type);
+            lci.push(element.value.len() as u8);
+            lci.extend_from_slice(element.value.as_bytes());
+        }
+        lci.insert(0, lci.len() as u8);
+        lci
+    }
+
+    fn decode(value: &[u8]) -> Result<CivicLocation, TlvError> {
+        let invalid = || TlvError::InvalidValue(String::from("truncated civic address"));
+        if value.len() < 4 || value[0] as usize != value.len() - 1 {
+            return Err(invalid());
+        }
+
+        let mut elements = Vec::new();
+        let mut rest = &value[4..];
+        while !rest.is_empty() {
+            if rest.len() < 2 || rest.len() < 2 + rest[1] as usize {
+                return Err(invalid());
+            }
+            let (element, tail) = rest[2..].split_at(rest[1] as usize);
+            elements.push(CivicAddressElement::new(
+                rest[0],
+                String::from_utf8_lossy(element).into_owned(),
+            ));
+            rest = tail;
+        }
+
+        Ok(CivicLocation {
+            what: value[1],
+            country: String::from_utf8_lossy(&value[2..4]).into_owned(),
+            elements,
+        })
+    }
+
+    fn to_json(&self) -> Json {
+        let elements: Vec<Json> = self
+            .elements
+            .iter()
+            .map(|element| {
+                Json::object(vec![
+                    ("ca_type", element.ca_type.into()),
+                    ("name", element.type_name().into()),
+                    ("value", element.value.as_str().into()),
+                ])
+            })
+            .collect();
+        Json::object(vec![
+            ("what", self.what.into()),
+            ("country", self.country.as_str().into()),
+            ("elements", elements.into()),
+        ])
+    }
+}
+
+impl Display for CivicLocation {
+    /// Write the address, e.g. `DE: A1=Berlin, A3=Berlin, RD=Platz der Republik, HNO=1`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let elements: Vec<String> = self.elements.iter().map(|e| e.to_string()).collect();
+        write!(f, "{}: {}", self.country, elements.join(", "))
+    }
+}
+
+/// Location Identification TLV (subtype 3)
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               7
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+
+/// |             |                 |               |               |   Location    |  Location   |
+/// |     127     |     5 + n       |   00-12-BB    |       3       |  Data Format  |     ID      |
+/// |             |                 |               |               |               |  (n octets) |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+
+/// ```
+///
+/// | Format | Location ID                                  |
+/// |--------|----------------------------------------------|
+/// | 1      | Coordinate-based LCI, see [CoordinateLocation] |
+/// | 2      | Civic address LCI, see [CivicLocation]       |
+/// | 3      | ECS ELIN, 10 - 25 numerical digits           |
+#[derive(Debug, Clone, PartialEq)]
+pub enum LocationIdentification {
+    /// Coordinate-based location (format 1)
+    Coordinate(CoordinateLocation),
+    /// Civic address (format 2)
+    Civic(CivicLocation),
+    /// Emergency Location Identification Number (format 3)
+    Elin(String),
+}
+
+impl LocationIdentification {
+    /// Create an ELIN location.
+    ///
+    /// Fails unless the ELIN consists of 10 to 25 digits.
+    pub fn try_elin(elin: String) -> Result<LocationIdentification, TlvError> {
+        if !(10..=25).contains(&elin.len()) || !elin.chars().all(|c| c.is_ascii_digit()) {
+            return Err(TlvError::InvalidValue(format!("ELIN {:?}", elin)));
+        }
+        Ok(LocationIdentification::Elin(elin))
+    }
+
+    /// Get the location data format.
+    pub fn format(&self) -> u8 {
+        match self {
+            LocationIdentification::Coordinate(_) => 1,
+            LocationIdentification::Civic(_) => 2,
+            LocationIdentification::Elin(_) => 3,
+        }
+    }
+}
+
+impl Display for LocationIdentification {
+    /// Write the location prefixed by its format, e.g. `ELIN 5551234567`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LocationIdentification::Coordinate(location) => write!(f, "coordinates {}", location),
+            LocationIdentification::Civic(location) => write!(f, "civic address {}", location),
+            LocationIdentification::Elin(elin) => write!(f, "ELIN {}", elin),
+        }
+    }
+}
+
+impl OrgTlvPayload for LocationIdentification {
+    fn oui(&self) -> [u8; 3] {
+        MED_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        3
+    }
+
+    fn name(&self) -> String {
+        String::from("Location Identification")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let mut value = vec![self.format()];
+        match self {
+            LocationIdentification::Coordinate(location) => value.extend(location.encode()),
+            LocationIdentification::Civic(location) => value.extend(location.encode()),
+            LocationIdentification::Elin(elin) => value.extend_from_slice(elin.as_bytes()),
+        }
+        value
+    }
+
+    fn to_json(&self) -> Json {
+        let location = match self {
+            LocationIdentification::Coordinate(location) => location.to_json(),
+            LocationIdentification::Civic(location) => location.to_json(),
+            LocationIdentification::Elin(elin) => elin.as_str().into(),
+        };
+        Json::object(vec![
+            ("format", self.format().into()),
+            ("location", location),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for LocationIdentification {
+    const KEYS: &'static [([u8; 3], u8)] = &[(MED_OUI, 3)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 1, 507)?;
+        match value[0] {
+            1 => {
+                check_length(
+                    TlvType::OrganizationallySpecific,
+                    value.len(),
+                    1 + COORDINATE_LENGTH,
+                    1 + COORDINATE_LENGTH,
+                )?;
+                Ok(LocationIdentification::Coordinate(
+                    CoordinateLocation::decode(&value[1..]),
+                ))
+            }
+            2 => Ok(LocationIdentification::Civic(CivicLocation::decode(
+                &value[1..],
+            )?)),
+            3 => {
+                LocationIdentification::try_elin(String::from_utf8_lossy(&value[1..]).into_owned())
+            }
+            format => Err(TlvError::InvalidValue(format!(
+                "location data format {}",
+                format
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+
+    const ELIN: &[u8] = b"\xfe\x0f\x00\x12\xbb\x03\x03\x35\x35\x35\x31\x32\x33\x34\x35\x36\x37";
+    const CIVIC: &[u8] = b"\xfe\x17\x00\x12\xbb\x03\x02\x11\x02DE\x03\x06Berlin\x13\x011\x1b\x012";
+
+    #[test]
+    fn test_elin() {
+        let location = LocationIdentification::try_elin(String::from("5551234567")).unwrap();
+        let tlv = OrganizationallySpecificTLV::from_payload(&location);
+        assert_eq!(tlv.bytes(), ELIN.to_vec());
+
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(ELIN);
+        assert_eq!(tlv.decode_as::<LocationIdentification>(), Some(location));
+        assert_eq!(
+            format!("{}", tlv),
+            "OrganizationallySpecificTLV(\"0012BB\", 3, \"Location Identification: ELIN 5551234567\")"
+        );
+        assert!(LocationIdentification::try_elin(String::from("911")).is_err());
+    }
+
+    #[test]
+    fn test_civic() {
+        let location = CivicLocation::try_new(
+            String::from("DE"),
+            vec![
+                CivicAddressElement::new(3, String::from("Berlin")),
+                CivicAddressElement::new(19, String::from("1")),
+                CivicAddressElement::new(27, String::from("2")),
+            ],
+        )
+        .unwrap();
+        let tlv = OrganizationallySpecificTLV::from_payload(&LocationIdentification::Civic(
+            location.clone(),
+        ));
+        assert_eq!(tlv.bytes(), CIVIC.to_vec());
+
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(CIVIC);
+        assert_eq!(
+            tlv.decode_as::<LocationIdentification>(),
+            Some(LocationIdentification::Civic(location.clone()))
+        );
+        assert_eq!(location.element(27), Some("2"));
+        assert_eq!(
+            format!("{}", tlv),
+            "OrganizationallySpecificTLV(\"0012BB\", 3, \"Location Identification: civic address DE: A3=Berlin, \
+             HNO=1, FLR=2\")"
+        );
+        assert!(CivicLocation::try_new(String::from("DEU"), vec![]).is_err());
+    }
+
+    #[test]
+    fn test_civic_truncated() {
+        let tlv = OrganizationallySpecificTLV::new(
+            b"\x00\x12\xbb".to_vec(),
+            3,
+            b"\x02\x07\x02DE\x03\x06Ber".to_vec(),
+        );
+        assert!(matches!(
+            tlv.decode().unwrap(),
+            Err(TlvError::InvalidValue(_))
+        ));
+    }
+
+    #[test]
+    fn test_coordinate() {
+        let value = b"\x01\x8b\xbc\x49\x36\x0d\x89\x2e\x6e\x2e\xc3\x17\x80\x00\x34\x10\x01";
+        let tlv = OrganizationallySpecificTLV::new(b"\x00\x12\xbb".to_vec(), 3, value.to_vec());
+        let location = match tlv.decode_as::<LocationIdentification>().unwrap() {
+            LocationIdentification::Coordinate(location) => location,
+            other => panic!("unexpected location {:?}", other),
+        };
+        assert_eq!(location.latitude_resolution, 34);
+        assert_eq!(location.altitude_resolution, 30);
+        assert!((location.latitude - -33.8570095).abs() < 1e-6);
+        assert!((location.longitude - 151.2152005).abs() < 1e-6);
+        assert_eq!(location.altitude_type, AltitudeType::Meters);
+        assert_eq!(location.altitude, 52.0625);
+        assert_eq!(location.datum, Datum::Wgs84);
+        assert_eq!(
+            format!("{}", location),
+            "-33.857010, 151.215201, 52.0625 m (WGS84)"
+        );
+        assert_eq!(
+            OrganizationallySpecificTLV::from_payload(&LocationIdentification::Coordinate(
+                location
+            ))
+            .value,
+            value.to_vec()
+        );
+    }
+
+    #[test]
+    fn test_coordinate_roundtrip() {
+        let location = CoordinateLocation::try_new(
+            52.516275,
+            13.377704,
+            AltitudeType::Floors,
+            3.0,
+            Datum::Wgs84,
+        )
+        .unwrap();
+        let payload = LocationIdentification::Coordinate(location);
+        let tlv = OrganizationallySpecificTLV::from_payload(&payload);
+        let decoded = match tlv.decode_as::<LocationIdentification>().unwrap() {
+            LocationIdentification::Coordinate(location) => location,
+            other => panic!("unexpected location {:?}", other),
+        };
+        assert!((decoded.latitude - 52.516275).abs() < 1e-7);
+        assert!((decoded.longitude - 13.377704).abs() < 1e-7);
+        assert_eq!(
+            format!("{}", decoded),
+            "52.516275, 13.377704, floor 3 (WGS84)"
+        );
+        assert!(
+            CoordinateLocation::try_new(91.0, 0.0, AltitudeType::Unknown, 0.0, Datum::Wgs84)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_coordinate_altitude_range() {
+        let location = |altitude| {
+            CoordinateLocation::try_new(0.0, 0.0, AltitudeType::Meters, altitude, Datum::Wgs84)
+        };
+
+        for altitude in [-2_097_152.0, 2_097_151.99] {
+            let payload = LocationIdentification::Coordinate(location(altitude).unwrap());
+            let tlv = OrganizationallySpecificTLV::from_payload(&payload);
+            match tlv.decode_as::<LocationIdentification>().unwrap() {
+                LocationIdentification::Coordinate(decoded) => {
+                    assert!((decoded.altitude - altitude).abs() < 0.01)
+                }
+                other => panic!("unexpected location {:?}", other),
+            }
+        }
+
+        assert_eq!(
+            location(2_097_152.0),
+            Err(TlvError::InvalidValue(String::from("altitude 2097152")))
+        );
+        assert!(location(-2_097_153.0).is_err());
+        assert!(location(f64::NAN).is_err());
+    }
+}
@@ -0,0 +1,812 @@
+//! PROFINET Organizationally Specific TLVs (OUI 00-0E-CF)
+//!
+//! PROFINET IO devices (IEC 61158-6-10) use LLDP for topology discovery. Besides the neighborhood they advertise the
+//! timing information needed for isochronous real-time communication, the status of their real-time classes and the
+//! media redundancy (MRP) and time synchronization (PTCP) state of the port.
+
+use crate::json::Json;
+use crate::tlv::organizationallyspecific_tlv::registry::{
+    OrgTlvDecode, OrgTlvPayload, OrgTlvRegistry,
+};
+use crate::tlv::{check_length, format_mac, TlvError, TlvType};
+
+use std::any::Any;
+use std::fmt::Display;
+
+/// OUI of the PROFIBUS & PROFINET International user organization
+pub const PROFINET_OUI: [u8; 3] = [0x00, 0x0E, 0xCF];
+
+/// Register the PROFINET TLVs.
+pub(crate) fn register(registry: &mut OrgTlvRegistry) {
+    registry.register_organization(PROFINET_OUI, "PROFINET");
+    registry.register_type::<PnioDelay>();
+    registry.register_type::<PnioPortStatus>();
+    registry.register_type::<PnioMrpPortStatus>();
+    registry.register_type::<PnioChassisMac>();
+    registry.register_type::<PnioPtcpStatus>();
+}
+
+/// Format a UUID in its usual 8-4-4-4-12 form.
+fn uuid(octets: &[u8; 16]) -> String {
+    let hex: String = octets
+        .iter()
+        .map(|octet| format!("{:02x}", octet))
+        .collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+fn u32_at(value: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes([
+        value[offset],
+        value[offset + 1],
+        value[offset + 2],
+        value[offset + 3],
+    ])
+}
+
+fn uuid_at(value: &[u8], offset: usize) -> [u8; 16] {
+    let mut uuid = [0; 16];
+    uuid.copy_from_slice(&value[offset..offset + 16]);
+    uuid
+}
+
+/// Delay TLV (LLDP_PNIO_DELAY, subtype 1)
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               10              14
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-...-+-+-+
+/// |             |                 |               |               |    Port RX    |    Port RX    |
+/// |     127     |       24        |   00-0E-CF    |       1       |  Delay Local  | Delay Remote  |
+/// |             |                 |               |               |               |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-...-+-+-+
+///  14              18              22              26
+/// +-+-+-+-...-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-...-+-+-+
+/// |    Port TX    |    Port TX    |  Line Delay   |
+/// |  Delay Local  | Delay Remote  |     Local     |
+/// +-+-+-+-...-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-...-+-+-+
+/// ```
+///
+/// All delays are given in nanoseconds, 0 meaning unknown. The remote delays echo the values last received from the
+/// neighbor. Bit 31 of the line delay is the format indicator: if set, the remaining bits hold the cable delay
+/// instead of the measured line delay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PnioDelay {
+    /// Receive delay of the local port
+    pub rx_delay_local: u32,
+    /// Receive delay of the remote port
+    pub rx_delay_remote: u32,
+    /// Transmit delay of the local port
+    pub tx_delay_local: u32,
+    /// Transmit delay of the remote port
+    pub tx_delay_remote: u32,
+    /// Line delay, including the format indicator
+    pub line_delay: u32,
+}
+
+impl PnioDelay {
+    /// Constructor
+    pub fn new(
+        rx_delay_local: u32,
+        rx_delay_remote: u32,
+        tx_delay_local: u32,
+        tx_delay_remote: u32,
+        line_delay: u32,
+    ) -> PnioDelay {
+        PnioDelay {
+            rx_delay_local,
+            rx_delay_remote,
+            tx_delay_local,
+            tx_delay_remote,
+            line_delay,
+        }
+    }
+
+    /// Check whether the line delay field holds the cable delay rather than the measured line delay.
+    pub fn is_cable_delay(&self) -> bool {
+        self.line_delay & 0x8000_0000 != 0
+    }
+
+    /// Get the line or cable delay in nanoseconds.
+    pub fn line_delay_ns(&self) -> u32 {
+        self.line_delay & 0x7FFF_FFFF
+    }
+}
+
+impl Display for PnioDelay {
+    /// Write the TLV, e.g. `RX 320/320 ns, TX 80/80 ns, line 50 ns`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RX {}/{} ns, TX {}/{} ns, {} {} ns",
+            self.rx_delay_local,
+            self.rx_delay_remote,
+            self.tx_delay_local,
+            self.tx_delay_remote,
+            if self.is_cable_delay() {
+                "cable"
+            } else {
+                "line"
+            },
+            self.line_delay_ns()
+        )
+    }
+}
+
+impl OrgTlvPayload for PnioDelay {
+    fn oui(&self) -> [u8; 3] {
+        PROFINET_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        1
+    }
+
+    fn name(&self) -> String {
+        String::from("PROFINET Delay")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        [
+            self.rx_delay_local,
+            self.rx_delay_remote,
+            self.tx_delay_local,
+            self.tx_delay_remote,
+            self.line_delay,
+        ]
+        .iter()
+        .flat_map(|delay| delay.to_be_bytes())
+        .collect()
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("rx_delay_local", self.rx_delay_local.into()),
+            ("rx_delay_remote", self.rx_delay_remote.into()),
+            ("tx_delay_local", self.tx_delay_local.into()),
+            ("tx_delay_remote", self.tx_delay_remote.into()),
+            ("line_delay", self.line_delay_ns().into()),
+            ("cable_delay", self.is_cable_delay().into()),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for PnioDelay {
+    const KEYS: &'static [([u8; 3], u8)] = &[(PROFINET_OUI, 1)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 20, 20)?;
+        Ok(PnioDelay::new(
+            u32_at(value, 0),
+            u32_at(value, 4),
+            u32_at(value, 8),
+            u32_at(value, 12),
+            u32_at(value, 16),
+        ))
+    }
+}
+
+/// State of a real-time class on a port
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RtClassState {
+    /// Off (0)
+    Off,
+    /// Up (2)
+    Up,
+    /// Running, RTClass3 only (4)
+    Run,
+    /// Reserved value
+    Reserved(u8),
+}
+
+impl From<u8> for RtClassState {
+    fn from(value: u8) -> RtClassState {
+        match value {
+            0 => RtClassState::Off,
+            2 => RtClassState::Up,
+            4 => RtClassState::Run,
+            value => RtClassState::Reserved(value),
+        }
+    }
+}
+
+impl Display for RtClassState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RtClassState::Off => write!(f, "OFF"),
+            RtClassState::Up => write!(f, "UP"),
+            RtClassState::Run => write!(f, "RUN"),
+            RtClassState::Reserved(value) => write!(f, "reserved {}", value),
+        }
+    }
+}
+
+/// Port Status TLV (LLDP_PNIO_PORTSTATUS, subtype 2)
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               8               10
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |             |                 |               |               |   RTClass2    |   RTClass3    |
+/// |     127     |        8        |   00-0E-CF    |       2       |  Port Status  |  Port Status  |
+/// |             |                 |               |               |               |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+///
+/// The lowest three bits of each status hold the [RtClassState]. The RTClass3 status additionally indicates whether
+/// fragmentation is enabled (bit 12) and whether the shortened one octet preamble is used (bit 13).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PnioPortStatus {
+    /// RTClass2 port status
+    pub rtclass2: u16,
+    /// RTClass3 port status
+    pub rtclass3: u16,
+}
+
+impl PnioPortStatus {
+    /// Constructor
+    pub fn new(rtclass2: u16, rtclass3: u16) -> PnioPortStatus {
+        PnioPortStatus { rtclass2, rtclass3 }
+    }
+
+    /// Get the state of RTClass2.
+    pub fn rtclass2_state(&self) -> RtClassState {
+        ((self.rtclass2 & 0x07) as u8).into()
+    }
+
+    /// Get the state of RTClass3.
+    pub fn rtclass3_state(&self) -> RtClassState {
+        ((self.rtclass3 & 0x07) as u8).into()
+    }
+
+    /// Check whether RTClass3 uses fragmentation.
+    pub fn fragmentation(&self) -> bool {
+        self.rtclass3 & 0x1000 != 0
+    }
+
+    /// Check whether RTClass3 uses the shortened preamble.
+    pub fn short_preamble(&self) -> bool {
+        self.rtclass3 & 0x2000 != 0
+    }
+}
+
+impl Display for PnioPortStatus {
+    /// Write the TLV, e.g. `RTClass2 OFF, RTClass3 RUN [fragmentation]`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RTClass2 {}, RTClass3 {}",
+            self.rtclass2_state(),
+            self.rtclass3_state()
+        )?;
+        let mut flags = Vec::new();
+        if self.fragmentation() {
+            flags.push("fragmentation");
+        }
+        if self.short_preamble() {
+            flags.push("short preamble");
+        }
+        if !flags.is_empty() {
+            write!(f, " [{}]", flags.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl OrgTlvPayload for PnioPortStatus {
+    fn oui(&self) -> [u8; 3] {
+        PROFINET_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        2
+    }
+
+    fn name(&self) -> String {
+        String::from("PROFINET Port Status")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let mut value = self.rtclass2.to_be_bytes().to_vec();
+        value.extend_from_slice(&self.rtclass3.to_be_bytes());
+        value
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("rtclass2", self.rtclass2_state().to_string().into()),
+            ("rtclass3", self.rtclass3_state().to_string().into()),
+            ("fragmentation", self.fragmentation().into()),
+            ("short_preamble", self.short_preamble().into()),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for PnioPortStatus {
+    const KEYS: &'static [([u8; 3], u8)] = &[(PROFINET_OUI, 2)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 4, 4)?;
+        Ok(PnioPortStatus::new(
+            u16::from_be_bytes([value[0], value[1]]),
+            u16::from_be_bytes([value[2], value[3]]),
+        ))
+    }
+}
+
+/// Status of the MRP ring port
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MrrtPortStatus {
+    /// MRRT is off (0)
+    Off,
+    /// MRRT is configured (1)
+    Configured,
+    /// MRRT is up (2)
+    Up,
+    /// Reserved value
+    Reserved(u16),
+}
+
+impl From<u16> for MrrtPortStatus {
+    fn from(value: u16) -> MrrtPortStatus {
+        match value {
+            0 => MrrtPortStatus::Off,
+            1 => MrrtPortStatus::Configured,
+            2 => MrrtPortStatus::Up,
+            value => MrrtPortStatus::Reserved(value),
+        }
+    }
+}
+
+impl From<MrrtPortStatus> for u16 {
+    fn from(status: MrrtPortStatus) -> u16 {
+        match status {
+            MrrtPortStatus::Off => 0,
+            MrrtPortStatus::Configured => 1,
+            MrrtPortStatus::Up => 2,
+            MrrtPortStatus::Reserved(value) => value,
+        }
+    }
+}
+
+impl Display for MrrtPortStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MrrtPortStatus::Off => write!(f, "OFF"),
+            MrrtPortStatus::Configured => write!(f, "configured"),
+            MrrtPortStatus::Up => write!(f, "UP"),
+            MrrtPortStatus::Reserved(value) => write!(f, "reserved {}", value),
+        }
+    }
+}
+
+/// MRP Port Status TLV (LLDP_PNIO_MRPPORTSTATUS, subtype 4)
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               22              24
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+
+/// |             |                 |               |               |  MRP Domain   |     MRRT      |
+/// |     127     |       22        |   00-0E-CF    |       4       |     UUID      |  Port Status  |
+/// |             |                 |               |               |               |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PnioMrpPortStatus {
+    /// UUID of the MRP domain the port belongs to
+    pub domain_uuid: [u8; 16],
+    /// Status of the media redundancy real-time port
+    pub status: MrrtPortStatus,
+}
+
+impl PnioMrpPortStatus {
+    /// Constructor
+    pub fn new(domain_uuid: [u8; 16], status: MrrtPortStatus) -> PnioMrpPortStatus {
+        PnioMrpPortStatus {
+            domain_uuid,
+            status,
+        }
+    }
+}
+
+impl Display for PnioMrpPortStatus {
+    /// Write the TLV, e.g. `domain ffffffff-ffff-ffff-ffff-ffffffffffff, MRRT OFF`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "domain {}, MRRT {}",
+            uuid(&self.domain_uuid),
+            self.status
+        )
+    }
+}
+
+impl OrgTlvPayload for PnioMrpPortStatus {
+    fn oui(&self) -> [u8; 3] {
+        PROFINET_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        4
+    }
+
+    fn name(&self) -> String {
+        String::from("PROFINET MRP Port Status")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let mut value = self.domain_uuid.to_vec();
+        value.extend_from_slice(&u16::from(self.status).to_be_bytes());
+        value
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("domain_uuid", uuid(&self.domain_uuid).into()),
+            ("status", self.status.to_string().into()),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for PnioMrpPortStatus {
+    const KEYS: &'static [([u8; 3], u8)] = &[(PROFINET_OUI, 4)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 18, 18)?;
+        Ok(PnioMrpPortStatus::new(
+            uuid_at(value, 0),
+            u16::from_be_bytes([value[16], value[17]]).into(),
+        ))
+    }
+}
+
+/// Chassis MAC TLV (LLDP_PNIO_CHASSIS_MAC, subtype 5)
+///
+/// Advertises the MAC address of the device's interface, as the chassis ID of PROFINET devices holds the name of
+/// station instead.
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               12
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+
+/// |             |                 |               |               |   Chassis     |
+/// |     127     |       10        |   00-0E-CF    |       5       |     MAC       |
+/// |             |                 |               |               |               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PnioChassisMac {
+    /// MAC address of the interface
+    pub mac: [u8; 6],
+}
+
+impl PnioChassisMac {
+    /// Constructor
+    pub fn new(mac: [u8; 6]) -> PnioChassisMac {
+        PnioChassisMac { mac }
+    }
+}
+
+impl Display for PnioChassisMac {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format_mac(&self.mac))
+    }
+}
+
+impl OrgTlvPayload for PnioChassisMac {
+    fn oui(&self) -> [u8; 3] {
+        PROFINET_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        5
+    }
+
+    fn name(&self) -> String {
+        String::from("PROFINET Chassis MAC")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        self.mac.to_vec()
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![("mac", format_mac(&self.mac).into())])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for PnioChassisMac {
+    const KEYS: &'static [([u8; 3], u8)] = &[(PROFINET_OUI, 5)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 6, 6)?;
+        let mut mac = [0; 6];
+        mac.copy_from_slice(value);
+        Ok(PnioChassisMac::new(mac))
+    }
+}
+
+/// PTCP Status TLV (LLDP_PNIO_PTCPSTATUS, subtype 6)
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               5               6               12              28
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-...-+-+-+
+/// |             |                 |               |               | PTCP Master   |     PTCP      |
+/// |     127     |       58        |   00-0E-CF    |       6       |    Source     |   Subdomain   |
+/// |             |                 |               |               |   Address     |     UUID      |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+-+-+-+-...-+-+-+
+///  28              44              48              52              56              60
+/// +-+-+-+-...-+-+-+-+-+-+-...-+-+-+-+-+-+-...-+-+-+-+-+-+-...-+-+-+-+-+-+-...-+-+-+
+/// |    IRData     |   Length of   |  Red Period   | Orange Period | Green Period  |
+/// |     UUID      |    Period     |     Begin     |     Begin     |     Begin     |
+/// +-+-+-+-...-+-+-+-+-+-+-...-+-+-+-+-+-+-...-+-+-+-+-+-+-...-+-+-+-+-+-+-...-+-+-+
+/// ```
+///
+/// Bit 31 of the period length and the period begin offsets indicates whether the value is valid, the remaining bits
+/// hold the value in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PnioPtcpStatus {
+    /// MAC address of the PTCP sync master
+    pub master: [u8; 6],
+    /// UUID of the PTCP subdomain
+    pub subdomain_uuid: [u8; 16],
+    /// UUID of the isochronous real-time configuration
+    pub irdata_uuid: [u8; 16],
+    /// Length of the send clock period, including the valid bit
+    pub period_length: u32,
+    /// Begin of the red (RTClass3) phase, including the valid bit
+    pub red_period_begin: u32,
+    /// Begin of the orange (RTClass2) phase, including the valid bit
+    pub orange_period_begin: u32,
+    /// Begin of the green (non real-time) phase, including the valid bit
+    pub green_period_begin: u32,
+}
+
+impl PnioPtcpStatus {
+    /// Get the length of the send clock period in nanoseconds, `None` if not valid.
+    pub fn period_length_ns(&self) -> Option<u32> {
+        PnioPtcpStatus::valid(self.period_length)
+    }
+
+    /// Get the begin offsets of the red, orange and green phases in nanoseconds, `None` where not valid.
+    pub fn period_begins_ns(&self) -> [Option<u32>; 3] {
+        [
+            PnioPtcpStatus::valid(self.red_period_begin),
+            PnioPtcpStatus::valid(self.orange_period_begin),
+            PnioPtcpStatus::valid(self.green_period_begin),
+        ]
+    }
+
+    fn valid(value: u32) -> Option<u32> {
+        if value & 0x8000_0000 != 0 {
+            Some(value & 0x7FFF_FFFF)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for PnioPtcpStatus {
+    /// Write the TLV, e.g. `master 00:0e:cf:00:00:01, subdomain ..., period 1000000 ns`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "master {}, subdomain {}, IRData {}",
+            format_mac(&self.master),
+            uuid(&self.subdomain_uuid),
+            uuid(&self.irdata_uuid)
+        )?;
+        if let Some(length) = self.period_length_ns() {
+            write!(f, ", period {} ns", length)?;
+        }
+        for (name, begin) in ["red", "orange", "green"]
+            .iter()
+            .zip(self.period_begins_ns().iter())
+        {
+            if let Some(begin) = begin {
+                write!(f, ", {} {} ns", name, begin)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl OrgTlvPayload for PnioPtcpStatus {
+    fn oui(&self) -> [u8; 3] {
+        PROFINET_OUI
+    }
+
+    fn subtype(&self) -> u8 {
+        6
+    }
+
+    fn name(&self) -> String {
+        String::from("PROFINET PTCP Status")
+    }
+
+    fn value(&self) -> Vec<u8> {
+        let mut value = self.master.to_vec();
+        value.extend_from_slice(&self.subdomain_uuid);
+        value.extend_from_slice(&self.irdata_uuid);
+        for field in [
+            self.period_length,
+            self.red_period_begin,
+            self.orange_period_begin,
+            self.green_period_begin,
+        ] {
+            value.extend_from_slice(&field.to_be_bytes());
+        }
+        value
+    }
+
+    fn to_json(&self) -> Json {
+        let [red, orange, green] = self.period_begins_ns();
+        Json::object(vec![
+            ("master", format_mac(&self.master).into()),
+            ("subdomain_uuid", uuid(&self.subdomain_uuid).into()),
+            ("irdata_uuid", uuid(&self.irdata_uuid).into()),
+            ("period_length", self.period_length_ns().into()),
+            ("red_period_begin", red.into()),
+            ("orange_period_begin", orange.into()),
+            ("green_period_begin", green.into()),
+        ])
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl OrgTlvDecode for PnioPtcpStatus {
+    const KEYS: &'static [([u8; 3], u8)] = &[(PROFINET_OUI, 6)];
+
+    fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+        check_length(TlvType::OrganizationallySpecific, value.len(), 54, 54)?;
+        let mut master = [0; 6];
+        master.copy_from_slice(&value[..6]);
+        Ok(PnioPtcpStatus {
+            master,
+            subdomain_uuid: uuid_at(value, 6),
+            irdata_uuid: uuid_at(value, 22),
+            period_length: u32_at(value, 38),
+            red_period_begin: u32_at(value, 42),
+            orange_period_begin: u32_at(value, 46),
+            green_period_begin: u32_at(value, 50),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV;
+
+    #[test]
+    fn test_delay() {
+        let bytes = b"\xfe\x18\x00\x0e\xcf\x01\x00\x00\x01\x40\x00\x00\x01\x40\x00\x00\x00\x50\x00\x00\x00\x50\
+            \x00\x00\x00\x32";
+        let delay = PnioDelay::new(320, 320, 80, 80, 50);
+        let tlv = OrganizationallySpecificTLV::from_payload(&delay);
+        assert_eq!(tlv.bytes(), bytes.to_vec());
+
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(bytes.as_ref());
+        assert_eq!(tlv.decode_as::<PnioDelay>(), Some(delay));
+        assert_eq!(
+            format!("{}", tlv),
+            "OrganizationallySpecificTLV(\"000ECF\", 1, \"PROFINET Delay: RX 320/320 ns, TX 80/80 ns, line 50 ns\")"
+        );
+
+        let cable = PnioDelay::new(0, 0, 0, 0, 0x8000_0064);
+        assert!(cable.is_cable_delay());
+        assert_eq!(cable.line_delay_ns(), 100);
+    }
+
+    #[test]
+    fn test_port_status() {
+        let bytes = b"\xfe\x08\x00\x0e\xcf\x02\x00\x00\x10\x04";
+        let status = PnioPortStatus::new(0, 0x1004);
+        let tlv = OrganizationallySpecificTLV::from_payload(&status);
+        assert_eq!(tlv.bytes(), bytes.to_vec());
+
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(bytes.as_ref());
+        let status = tlv.decode_as::<PnioPortStatus>().unwrap();
+        assert_eq!(status.rtclass2_state(), RtClassState::Off);
+        assert_eq!(status.rtclass3_state(), RtClassState::Run);
+        assert_eq!(
+            format!("{}", status),
+            "RTClass2 OFF, RTClass3 RUN [fragmentation]"
+        );
+    }
+
+    #[test]
+    fn test_mrp_port_status() {
+        let status = PnioMrpPortStatus::new([0xFF; 16], MrrtPortStatus::Off);
+        let tlv = OrganizationallySpecificTLV::from_payload(&status);
+        assert_eq!(tlv.value.len(), 18);
+
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(&tlv.bytes()[..]);
+        assert_eq!(tlv.decode_as::<PnioMrpPortStatus>(), Some(status));
+        assert_eq!(
+            format!("{}", status),
+            "domain ffffffff-ffff-ffff-ffff-ffffffffffff, MRRT OFF"
+        );
+    }
+
+    #[test]
+    fn test_chassis_mac() {
+        let bytes = b"\xfe\x0a\x00\x0e\xcf\x05\x00\x0e\xcf\x12\x34\x56";
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(bytes.as_ref());
+        let chassis_mac = tlv.decode_as::<PnioChassisMac>().unwrap();
+        assert_eq!(chassis_mac.mac, [0x00, 0x0e, 0xcf, 0x12, 0x34, 0x56]);
+        assert_eq!(
+            format!("{}", tlv),
+            "OrganizationallySpecificTLV(\"000ECF\", 5, \"PROFINET Chassis MAC: 00:0e:cf:12:34:56\")"
+        );
+        assert_eq!(
+            OrganizationallySpecificTLV::from_payload(&chassis_mac).bytes(),
+            bytes.to_vec()
+        );
+    }
+
+    #[test]
+    fn test_ptcp_status() {
+        let status = PnioPtcpStatus {
+            master: [0x00, 0x0e, 0xcf, 0x00, 0x00, 0x01],
+            subdomain_uuid: [0x11; 16],
+            irdata_uuid: [0; 16],
+            period_length: 0x8000_0000 | 1_000_000,
+            red_period_begin: 0x8000_0000,
+            orange_period_begin: 0,
+            green_period_begin: 0x8000_0000 | 500_000,
+        };
+        let tlv = OrganizationallySpecificTLV::from_payload(&status);
+        assert_eq!(tlv.value.len(), 54);
+
+        let tlv = OrganizationallySpecificTLV::new_from_bytes(&tlv.bytes()[..]);
+        assert_eq!(tlv.decode_as::<PnioPtcpStatus>(), Some(status));
+        assert_eq!(status.period_begins_ns(), [Some(0), None, Some(500_000)]);
+        assert_eq!(
+            format!("{}", status),
+            "master 00:0e:cf:00:00:01, subdomain 11111111-1111-1111-1111-111111111111, \
+             IRData 00000000-0000-0000-0000-000000000000, period 1000000 ns, red 0 ns, green 500000 ns"
+        );
+    }
+
+    #[test]
+    fn test_invalid_length() {
+        let tlv = OrganizationallySpecificTLV::new(b"\x00\x0e\xcf".to_vec(), 5, vec![0; 4]);
+        assert!(matches!(
+            tlv.decode().unwrap(),
+            Err(TlvError::InvalidLength { length: 4, .. })
+        ));
+    }
+}
@@ -0,0 +1,372 @@
+use crate::json::Json;
+use crate::tlv::organizationallyspecific_tlv::layout::{LayoutError, LayoutTlv, TlvLayout};
+use crate::tlv::organizationallyspecific_tlv::{
+    cee, cisco, fabric_attach, ieee8021, ieee8023, med, profinet,
+};
+use crate::tlv::TlvError;
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::sync::{Arc, OnceLock, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// Decoded value of an Organizationally Specific TLV
+///
+/// Implemented by the typed representations of vendor TLVs. The `Display` implementation should give a short,
+/// human readable description of the value, e.g. `100` for a Port VLAN ID, as it is shown next to
+/// [name](OrgTlvPayload::name) in the neighbor view.
+pub trait OrgTlvPayload: Debug + Display + Send + Sync {
+    /// Organizationally unique identifier
+    fn oui(&self) -> [u8; 3];
+    /// Organizationally defined subtype
+    fn subtype(&self) -> u8;
+    /// Name of the TLV, e.g. "Port VLAN ID"
+    fn name(&self) -> String;
+    /// Encode the organizationally defined information (the TLV value following the subtype)
+    fn value(&self) -> Vec<u8>;
+    /// Get a machine readable representation of the decoded value
+    fn to_json(&self) -> Json;
+    /// Allow downcasting to the concrete type
+    fn as_any(&self) -> &dyn Any;
+}
+
+/// A typed Organizationally Specific TLV value with a fixed set of (OUI, subtype) pairs
+///
+/// Types implementing this trait can be registered with [OrgTlvRegistry::register_type] and decoded directly with
+/// [OrganizationallySpecificTLV::decode_as](crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV::decode_as).
+pub trait OrgTlvDecode: OrgTlvPayload + Sized + 'static {
+    /// The (OUI, subtype) pairs the type is decoded from
+    const KEYS: &'static [([u8; 3], u8)];
+
+    /// Decode the organizationally defined information of a TLV with one of the [KEYS](OrgTlvDecode::KEYS).
+    ///
+    /// The `key` identifies the pair the value was received with.
+    fn from_value(key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError>;
+}
+
+/// Decoder for the value of an Organizationally Specific TLV
+pub type OrgTlvDecoder =
+    Arc<dyn Fn(&[u8]) -> Result<Box<dyn OrgTlvPayload>, TlvError> + Send + Sync>;
+
+/// Decoder for the subtypes of an OUI without a decoder of their own, called with the subtype and value
+pub type OrgTlvFallbackDecoder =
+    Arc<dyn Fn(u8, &[u8]) -> Result<Box<dyn OrgTlvPayload>, TlvError> + Send + Sync>;
+
+/// Maps (OUI, subtype) pairs to decoders
+///
+/// Encoding is left to the decoded values, see [OrgTlvPayload::value].
+///
+/// The process-wide registry used by
+/// [OrganizationallySpecificTLV](crate::tlv::organizationallyspecific_tlv::OrganizationallySpecificTLV) is available
+/// through [registry] and [registry_mut]. It starts out with the built-in TLVs (see [OrgTlvRegistry::with_builtins]).
+/// Applications and other crates add their own vendor TLVs to it, e.g.:
+///
+/// ```
+/// # use std::any::Any;
+/// # use std::fmt::{self, Display};
+/// # use lldp_rs::json::Json;
+/// # use lldp_rs::tlv::organizationallyspecific_tlv::registry::{
+/// #     registry, registry_mut, OrgTlvDecode, OrgTlvPayload,
+/// # };
+/// # use lldp_rs::tlv::TlvError;
+/// # #[derive(Debug)]
+/// # struct MyVendorTlv(u8);
+/// # impl Display for MyVendorTlv {
+/// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// #         write!(f, "{}", self.0)
+/// #     }
+/// # }
+/// # impl OrgTlvPayload for MyVendorTlv {
+/// #     fn oui(&self) -> [u8; 3] { [0xAC, 0xDE, 0x48] }
+/// #     fn subtype(&self) -> u8 { 1 }
+/// #     fn name(&self) -> String { String::from("My Vendor TLV") }
+/// #     fn value(&self) -> Vec<u8> { vec![self.0] }
+/// #     fn to_json(&self) -> Json { Json::Int(self.0 as i64) }
+/// #     fn as_any(&self) -> &dyn Any { self }
+/// # }
+/// # impl OrgTlvDecode for MyVendorTlv {
+/// #     const KEYS: &'static [([u8; 3], u8)] = &[([0xAC, 0xDE, 0x48], 1)];
+/// #     fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+/// #         match value {
+/// #             [octet] => Ok(MyVendorTlv(*octet)),
+/// #             _ => Err(TlvError::InvalidValue(String::from("expected one octet"))),
+/// #         }
+/// #     }
+/// # }
+/// registry_mut().register_type::<MyVendorTlv>();
+/// assert!(registry().contains([0xAC, 0xDE, 0x48], 1));
+/// ```
+#[derive(Clone, Default)]
+pub struct OrgTlvRegistry {
+    decoders: HashMap<([u8; 3], u8), OrgTlvDecoder>,
+    fallbacks: HashMap<[u8; 3], OrgTlvFallbackDecoder>,
+    organizations: HashMap<[u8; 3], String>,
+}
+
+impl Debug for OrgTlvRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut keys: Vec<&([u8; 3], u8)> = self.decoders.keys().collect();
+        keys.sort();
+        let mut fallbacks: Vec<&[u8; 3]> = self.fallbacks.keys().collect();
+        fallbacks.sort();
+        f.debug_struct("OrgTlvRegistry")
+            .field("decoders", &keys)
+            .field("fallbacks", &fallbacks)
+            .field("organizations", &self.organizations)
+            .finish()
+    }
+}
+
+impl OrgTlvRegistry {
+    /// Create an empty registry.
+    pub fn new() -> OrgTlvRegistry {
+        OrgTlvRegistry::default()
+    }
+
+    /// Create a registry holding the decoders for all TLVs supported by this crate.
+    pub fn with_builtins() -> OrgTlvRegistry {
+        let mut registry = OrgTlvRegistry::new();
+        ieee8021::register(&mut registry);
+        ieee8023::register(&mut registry);
+        med::register(&mut registry);
+        profinet::register(&mut registry);
+        cisco::register(&mut registry);
+        fabric_attach::register(&mut registry);
+        cee::register(&mut registry);
+        registry
+    }
+
+    /// Register `decoder` for TLVs with the given `oui` and `subtype`.
+    ///
+    /// Replaces a decoder registered earlier for the same pair.
+    pub fn register<F>(&mut self, oui: [u8; 3], subtype: u8, decoder: F)
+    where
+        F: Fn(&[u8]) -> Result<Box<dyn OrgTlvPayload>, TlvError> + Send + Sync + 'static,
+    {
+        self.decoders.insert((oui, subtype), Arc::new(decoder));
+    }
+
+    /// Register `decoder` for the subtypes of `oui` which have no decoder of their own.
+    ///
+    /// Replaces a fallback decoder registered earlier for the same OUI.
+    pub fn register_fallback<F>(&mut self, oui: [u8; 3], decoder: F)
+    where
+        F: Fn(u8, &[u8]) -> Result<Box<dyn OrgTlvPayload>, TlvError> + Send + Sync + 'static,
+    {
+        self.fallbacks.insert(oui, Arc::new(decoder));
+    }
+
+    /// Register the type `T` for all of its (OUI, subtype) pairs.
+    pub fn register_type<T: OrgTlvDecode>(&mut self) {
+        for &key in T::KEYS {
+            self.register(key.0, key.1, move |value| {
+                T::from_value(key, value).map(|tlv| Box::new(tlv) as Box<dyn OrgTlvPayload>)
+            });
+        }
+    }
+
+    /// Register a decoder for the TLV described by `layout`.
+    ///
+    /// Also registers the organization, if the layout names it.
+    pub fn register_layout(&mut self, layout: TlvLayout) {
+        if let Some(organization) = &layout.organization {
+            self.register_organization(layout.oui, organization);
+        }
+        let (oui, subtype) = (layout.oui, layout.subtype);
+        let layout = Arc::new(layout);
+        self.register(oui, subtype, move |value| {
+            LayoutTlv::decode(layout.clone(), value)
+                .map(|tlv| Box::new(tlv) as Box<dyn OrgTlvPayload>)
+        });
+    }
+
+    /// Register the TLV layouts described in a TOML document, see [layout](crate::tlv::organizationallyspecific_tlv::layout).
+    ///
+    /// The layouts replace decoders registered earlier for the same pairs, including the built-in ones. Nothing is
+    /// registered if the document is invalid. Returns the number of registered layouts.
+    pub fn load_layouts(&mut self, text: &str) -> Result<usize, LayoutError> {
+        let layouts = TlvLayout::parse_all(text)?;
+        let count = layouts.len();
+        for layout in layouts {
+            self.register_layout(layout);
+        }
+        Ok(count)
+    }
+
+    /// Register the name of the organization owning `oui`.
+    pub fn register_organization(&mut self, oui: [u8; 3], name: &str) {
+        self.organizations.insert(oui, name.to_string());
+    }
+
+    /// Get the name of the organization owning `oui`, if known.
+    pub fn organization(&self, oui: [u8; 3]) -> Option<&str> {
+        self.organizations.get(&oui).map(String::as_str)
+    }
+
+    /// Check whether a decoder is registered for the pair, or a fallback decoder for the OUI.
+    pub fn contains(&self, oui: [u8; 3], subtype: u8) -> bool {
+        self.decoders.contains_key(&(oui, subtype)) || self.fallbacks.contains_key(&oui)
+    }
+
+    /// Decode `value` using the decoder registered for the pair, or else the fallback decoder of the OUI.
+    ///
+    /// Returns `None` if there is no such decoder.
+    pub fn decode(
+        &self,
+        oui: [u8; 3],
+        subtype: u8,
+        value: &[u8],
+    ) -> Option<Result<Box<dyn OrgTlvPayload>, TlvError>> {
+        match self.decoders.get(&(oui, subtype)) {
+            Some(decoder) => Some(decoder(value)),
+            None => self
+                .fallbacks
+                .get(&oui)
+                .map(|decoder| decoder(subtype, value)),
+        }
+    }
+}
+
+fn global() -> &'static RwLock<OrgTlvRegistry> {
+    static REGISTRY: OnceLock<RwLock<OrgTlvRegistry>> = OnceLock::new();
+    REGISTRY.get_or_init(|| RwLock::new(OrgTlvRegistry::with_builtins()))
+}
+
+/// Get read access to the process-wide registry.
+pub fn registry() -> RwLockReadGuard<'static, OrgTlvRegistry> {
+    global().read().unwrap_or_else(|err| err.into_inner())
+}
+
+/// Get write access to the process-wide registry, e.g. to register additional decoders.
+pub fn registry_mut() -> RwLockWriteGuard<'static, OrgTlvRegistry> {
+    global().write().unwrap_or_else(|err| err.into_inner())
+}
+
+/// Format an OUI in the usual notation, e.g. `00-80-C2`.
+pub fn format_oui(oui: &[u8]) -> String {
+    let octets: Vec<String> = oui.iter().map(|octet| format!("{:02X}", octet)).collect();
+    octets.join("-")
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Test payload for the private OUI AC-DE-48
+    #[derive(Debug, Clone, PartialEq)]
+    pub(crate) struct Answer(pub(crate) u8);
+
+    impl Display for Answer {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl OrgTlvPayload for Answer {
+        fn oui(&self) -> [u8; 3] {
+            [0xAC, 0xDE, 0x48]
+        }
+        fn subtype(&self) -> u8 {
+            42
+        }
+        fn name(&self) -> String {
+            String::from("Answer")
+        }
+        fn value(&self) -> Vec<u8> {
+            vec![self.0]
+        }
+        fn to_json(&self) -> Json {
+            Json::object(vec![("answer", self.0.into())])
+        }
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    impl OrgTlvDecode for Answer {
+        const KEYS: &'static [([u8; 3], u8)] = &[([0xAC, 0xDE, 0x48], 42)];
+
+        fn from_value(_key: ([u8; 3], u8), value: &[u8]) -> Result<Self, TlvError> {
+            match value {
+                [answer] => Ok(Answer(*answer)),
+                _ => Err(TlvError::InvalidLength {
+                    tlv_type: crate::tlv::TlvType::OrganizationallySpecific,
+                    length: value.len(),
+                    min: 1,
+                    max: 1,
+                }),
+            }
+        }
+    }
+
+    #[test]
+    fn test_register_type() {
+        let mut registry = OrgTlvRegistry::new();
+        assert!(registry.decode([0xAC, 0xDE, 0x48], 42, &[42]).is_none());
+
+        registry.register_type::<Answer>();
+        assert!(registry.contains([0xAC, 0xDE, 0x48], 42));
+
+        let payload = registry
+            .decode([0xAC, 0xDE, 0x48], 42, &[42])
+            .unwrap()
+            .unwrap();
+        assert_eq!(payload.as_any().downcast_ref::<Answer>(), Some(&Answer(42)));
+        assert!(registry
+            .decode([0xAC, 0xDE, 0x48], 42, &[1, 2])
+            .unwrap()
+            .is_err());
+    }
+
+    #[test]
+    fn test_register_closure() {
+        let mut registry = OrgTlvRegistry::new();
+        registry.register([0xAC, 0xDE, 0x48], 7, |value| {
+            Ok(Box::new(Answer(value.len() as u8)) as Box<dyn OrgTlvPayload>)
+        });
+
+        let payload = registry
+            .decode([0xAC, 0xDE, 0x48], 7, &[0; 3])
+            .unwrap()
+            .unwrap();
+        assert_eq!(format!("{}", payload), "3");
+    }
+
+    #[test]
+    fn test_register_fallback() {
+        let mut registry = OrgTlvRegistry::new();
+        registry.register_type::<Answer>();
+        registry.register_fallback([0xAC, 0xDE, 0x48], |subtype, _| {
+            Ok(Box::new(Answer(subtype)) as Box<dyn OrgTlvPayload>)
+        });
+        assert!(registry.contains([0xAC, 0xDE, 0x48], 7));
+        assert!(!registry.contains([0xAC, 0xDE, 0x49], 7));
+
+        // Subtypes with a decoder of their own do not fall back
+        let payload = registry
+            .decode([0xAC, 0xDE, 0x48], 42, &[1])
+            .unwrap()
+            .unwrap();
+        assert_eq!(format!("{}", payload), "1");
+
+        let payload = registry
+            .decode([0xAC, 0xDE, 0x48], 7, &[1])
+            .unwrap()
+            .unwrap();
+        assert_eq!(format!("{}", payload), "7");
+        assert!(registry.decode([0xAC, 0xDE, 0x49], 7, &[1]).is_none());
+    }
+
+    #[test]
+    fn test_organization() {
+        let mut registry = OrgTlvRegistry::new();
+        registry.register_organization([0xAC, 0xDE, 0x48], "Example");
+        assert_eq!(registry.organization([0xAC, 0xDE, 0x48]), Some("Example"));
+        assert_eq!(registry.organization([0x00, 0x00, 0x00]), None);
+    }
+
+    #[test]
+    fn test_format_oui() {
+        assert_eq!(format_oui(&[0x00, 0x80, 0xC2]), "00-80-C2");
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use crate::tlv::TlvType;
+use crate::tlv::{check_length, TlvError, TlvType};
 
 /// Port Description TLV
 ///
@@ -11,15 +11,17 @@ use crate::tlv::TlvType;
 ///
 /// # TLV Format:
 ///
-///      0                   1                   2
-///      0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3
-///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
-///     |             |                 |                           |
-///     |      4      |      Length     |     Port Description      |
-///     |             |                 |                           |
-///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
+/// ```text
+///  0                   1                   2
+///  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
+/// |             |                 |                           |
+/// |      4      |      Length     |     Port Description      |
+/// |             |                 |                           |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
 ///
-///                                             0 - 255 byte
+///                                         0 - 255 byte
+/// ```
 #[derive(Debug, Clone)]
 pub struct PortDescriptionTLV {
     /// The type of the TLV
@@ -47,6 +49,14 @@ impl PortDescriptionTLV {
         }
     }
 
+    /// Validating constructor
+    ///
+    /// Like [PortDescriptionTLV::new], but checks that the description is at most 255 octets long.
+    pub fn try_new(value: String) -> Result<PortDescriptionTLV, TlvError> {
+        check_length(TlvType::PortDescription, value.len(), 0, 255)?;
+        Ok(PortDescriptionTLV::new(value))
+    }
+
     /// Create a TLV instance from raw bytes.
     ///
     /// Panics if the provided TLV contains errors (e.g. has the wrong type).
@@ -80,6 +90,11 @@ impl PortDescriptionTLV {
         self.value.len()
     }
 
+    /// Check whether the TLV value is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Return the byte representation of the TLV.
     pub fn bytes(&self) -> Vec<u8> {
         // TODO: Implement
@@ -150,4 +165,18 @@ mod tests {
         let (tlv, _) = set_up();
         assert_eq!(format!("{}", tlv), "PortDescriptionTLV(\"Unittest\")");
     }
+
+    #[test]
+    fn test_try_new() {
+        assert!(PortDescriptionTLV::try_new("x".repeat(255)).is_ok());
+        assert_eq!(
+            PortDescriptionTLV::try_new("x".repeat(300)).unwrap_err(),
+            TlvError::InvalidLength {
+                tlv_type: TlvType::PortDescription,
+                length: 300,
+                min: 0,
+                max: 255
+            }
+        );
+    }
 }
@@ -1,10 +1,13 @@
-use crate::tlv::TlvType;
-use bytes::BufMut;
+use crate::tlv::{check_length, TlvError, TlvType};
 
 use std::convert::{TryFrom, TryInto};
 use std::fmt::Display;
 use std::net::IpAddr;
 
+pub mod agentcircuitid;
+
+use agentcircuitid::AgentCircuitId;
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum PortIdSubtype {
     InterfaceAlias = 1,
@@ -37,6 +40,7 @@ impl TryFrom<u8> for PortIdSubtype {
 pub enum PortIdValue {
     Mac(Vec<u8>),
     IpAddress(IpAddr),
+    CircuitId(AgentCircuitId),
     Other(String),
 }
 
@@ -49,14 +53,16 @@ pub enum PortIdValue {
 ///
 /// # TLV Format:
 ///
-///         0               1               2               3
-///        +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
-///        |             |                 |               |               |
-///        |      2      |      Length     |    Subtype    |    Port ID    |
-///        |             |                 |               |               |
-///        +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
+/// ```text
+///     0               1               2               3
+///    +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
+///    |             |                 |               |               |
+///    |      2      |      Length     |    Subtype    |    Port ID    |
+///    |             |                 |               |               |
+///    +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
 ///
-///                                                            1 - 255 byte
+///                                                        1 - 255 byte
+/// ```
 ///
 /// # Subtypes
 ///
@@ -71,12 +77,12 @@ pub enum PortIdValue {
 /// | 3       | MAC Address       | 02:04:df:88:a2:b4          |
 /// | 4       | Network Address   | 134.96.86.110              |
 /// | 5       | Interface Name    | eth0                       |
-/// | 6       | Agent Circuit ID  |                            |
+/// | 6       | Agent Circuit ID  | circuit-id=Gi0/1:100       |
 /// | 7       | Locally Assigned  | Frank's Computer           |
 /// | 8 - 255 | Reserved          |                            |
 ///
-/// With the exception of subtypes 3 (MAC Address) and 4 (Network Address) the subtype is a string
-/// as far as the LLDP agent is concerned. A distinction between these types is only made by a human observer.
+/// With the exception of subtypes 3 (MAC Address), 4 (Network Address) and 6 (Agent Circuit ID) the subtype is a
+/// string as far as the LLDP agent is concerned. A distinction between these types is only made by a human observer.
 ///
 /// ## MAC Address Subtype:
 ///
@@ -100,14 +106,21 @@ pub enum PortIdValue {
 /// |   IPv4   |             1 |
 /// |   IPv6   |             2 |
 ///
-///     Examples (Address -> Bytes -> Prefixed Bytes):
-///         134.96.86.110  ->  b"\x86\x60\x56\x6E"  -> b"\x01\x86\x60\x56\x6E"
+/// ```text
+/// Examples (Address -> Bytes -> Prefixed Bytes):
+///     134.96.86.110  ->  b"\x86\x60\x56\x6E"  -> b"\x01\x86\x60\x56\x6E"
 ///
-///         20db::1        ->  b"\x20\xdb\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01"
-///                        ->  b"\x02\x20\xdb\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01"
+///     20db::1        ->  b"\x20\xdb\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01"
+///                    ->  b"\x02\x20\xdb\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01"
+/// ```
 ///
 /// The full list of registered protocol families is available at:
 /// <https://www.iana.org/assignments/address-family-numbers/address-family-numbers.xhtml>
+///
+/// ## Agent Circuit ID Subtype:
+///
+/// Agent Circuit IDs are often binary, encoded as DHCP relay agent sub-options (RFC 3046). They are decoded into an
+/// [AgentCircuitId], which re-encodes to exactly the received bytes.
 #[derive(Debug, Clone)]
 pub struct PortIdTLV {
     /// The type of the TLV
@@ -119,6 +132,7 @@ pub struct PortIdTLV {
     /// The type of the value field depends on the subtype
     /// * Mac Address -> `PortIdValue::Mac(Vec<u8>)`,
     /// * Network Address -> `PortIdValue::IpAddress(IpAddr)`,
+    /// * Agent Circuit ID -> `PortIdValue::CircuitId(AgentCircuitId)` (or `PortIdValue::Other(String)`)
     /// * Otherwise -> `PortIdValue::Other(String)`
     pub value: PortIdValue,
 }
@@ -139,6 +153,7 @@ impl Display for PortIdTLV {
             }
             PortIdValue::Other(s) => s.clone(),
             PortIdValue::IpAddress(addr) => addr.to_string(),
+            PortIdValue::CircuitId(circuit_id) => circuit_id.to_string(),
         };
 
         write!(f, "PortIdTLV({}, \"{}\")", self.subtype as u8, value)
@@ -151,6 +166,7 @@ impl PortIdTLV {
     /// The type of the id argument depends on the subtype
     /// * Mac Address -> `PortIdValue::Mac(Vec<u8>)`,
     /// * Network Address -> `PortIdValue::IpAddress(IpAddr)`,
+    /// * Agent Circuit ID -> `PortIdValue::CircuitId(AgentCircuitId)` (or `PortIdValue::Other(String)`)
     /// * Otherwise -> `PortIdValue::Other(String)`
     pub fn new(subtype: PortIdSubtype, id: PortIdValue) -> PortIdTLV {
         // TODO: Implement
@@ -161,6 +177,35 @@ impl PortIdTLV {
         }
     }
 
+    /// Validating constructor
+    ///
+    /// Like [PortIdTLV::new], but checks that `id` matches the subtype, that MAC addresses are 6 octets long and
+    /// that other IDs are 1 - 255 octets long.
+    pub fn try_new(subtype: PortIdSubtype, id: PortIdValue) -> Result<PortIdTLV, TlvError> {
+        match (&subtype, &id) {
+            (PortIdSubtype::MacAddress, PortIdValue::Mac(mac)) => {
+                check_length(TlvType::PortId, mac.len(), 6, 6)?
+            }
+            (PortIdSubtype::NetworkAddress, PortIdValue::IpAddress(_)) => (),
+            (PortIdSubtype::CircuitId, PortIdValue::CircuitId(circuit_id)) => {
+                check_length(TlvType::PortId, circuit_id.len(), 1, 255)?
+            }
+            (PortIdSubtype::MacAddress, _)
+            | (PortIdSubtype::NetworkAddress, _)
+            | (_, PortIdValue::Mac(_))
+            | (_, PortIdValue::IpAddress(_))
+            | (_, PortIdValue::CircuitId(_)) => {
+                return Err(TlvError::SubtypeMismatch {
+                    tlv_type: TlvType::PortId,
+                    subtype: subtype as u8,
+                })
+            }
+            (_, PortIdValue::Other(s)) => check_length(TlvType::PortId, s.len(), 1, 255)?,
+        }
+
+        Ok(PortIdTLV::new(subtype, id))
+    }
+
     /// Create a TLV instance from raw bytes.
     ///
     /// Panics if the provided TLV contains errors (e.g. has the wrong type).
@@ -200,6 +245,9 @@ impl PortIdTLV {
                 }
                 _ => panic!("Expected IP Address specifier"),
             },
+            PortIdSubtype::CircuitId => {
+                PortIdValue::CircuitId(AgentCircuitId::from_bytes(&bytes[3..]))
+            }
             _ => match String::from_utf8(bytes[3..].to_vec()) {
                 Ok(value) => PortIdValue::Other(value),
                 Err(_) => panic!("Invlaid value for PortId::Other type "),
@@ -214,11 +262,17 @@ impl PortIdTLV {
         1 + match &self.value {
             PortIdValue::Mac(_) => 6,
             PortIdValue::Other(s) => s.len(),
+            PortIdValue::CircuitId(circuit_id) => circuit_id.len(),
             PortIdValue::IpAddress(IpAddr::V4(_)) => 4 + 1,
             PortIdValue::IpAddress(IpAddr::V6(_)) => 16 + 1,
         }
     }
 
+    /// Check whether the TLV value is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Return the byte representation of the TLV.
     pub fn bytes(&self) -> Vec<u8> {
         let mut type_field = (self.tlv_type as u8) << 1;
@@ -240,6 +294,7 @@ impl PortIdTLV {
         let value_field = match &self.value {
             PortIdValue::Mac(addr) => addr.clone(),
             PortIdValue::Other(value) => value.as_bytes().to_vec(),
+            PortIdValue::CircuitId(circuit_id) => circuit_id.bytes(),
             PortIdValue::IpAddress(IpAddr::V4(address)) => address.octets().to_vec(),
             PortIdValue::IpAddress(IpAddr::V6(address)) => address.octets().to_vec(),
         };
@@ -261,6 +316,7 @@ impl PortIdTLV {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bytes::BufMut;
     use std::net::{Ipv4Addr, Ipv6Addr};
     fn set_up() -> (PortIdTLV, String, PortIdSubtype) {
         let s: String = String::from("Bla bla bla, Mr.Freeman.");
@@ -454,4 +510,66 @@ mod tests {
 
         assert_eq!(format!("{}", tlv), "PortIdTLV(4, \"127.0.0.1\")")
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_try_new() {
+        assert!(PortIdTLV::try_new(
+            PortIdSubtype::InterfaceName,
+            PortIdValue::Other(String::from("Gi1/0/12"))
+        )
+        .is_ok());
+        assert_eq!(
+            PortIdTLV::try_new(
+                PortIdSubtype::NetworkAddress,
+                PortIdValue::Mac(b"\x02\x04\xdf\x88\xa2\xb4".to_vec())
+            )
+            .unwrap_err(),
+            TlvError::SubtypeMismatch {
+                tlv_type: TlvType::PortId,
+                subtype: 4
+            }
+        );
+        assert_eq!(
+            PortIdTLV::try_new(PortIdSubtype::Local, PortIdValue::Other("x".repeat(256)))
+                .unwrap_err(),
+            TlvError::InvalidLength {
+                tlv_type: TlvType::PortId,
+                length: 256,
+                min: 1,
+                max: 255
+            }
+        );
+    }
+
+    #[test]
+    fn test_load_circuit_id() {
+        let bytes = b"\x04\x11\x06\x01\x09Gi0/1:100\x02\x03\x00\x11\x22";
+        let tlv = PortIdTLV::new_from_bytes(bytes.as_ref());
+        assert_eq!(tlv.subtype, PortIdSubtype::CircuitId);
+        match &tlv.value {
+            PortIdValue::CircuitId(circuit_id) => {
+                assert_eq!(circuit_id.circuit_id(), Some(b"Gi0/1:100".as_ref()));
+                assert_eq!(circuit_id.remote_id(), Some(b"\x00\x11\x22".as_ref()));
+            }
+            v => panic!("Expected CIRCUITID, got {:?}", v),
+        };
+        assert_eq!(tlv.bytes(), bytes.to_vec());
+        assert_eq!(
+            format!("{}", tlv),
+            "PortIdTLV(6, \"circuit-id=Gi0/1:100, remote-id=0x001122\")"
+        );
+    }
+
+    #[test]
+    fn test_try_new_circuit_id() {
+        let circuit_id = AgentCircuitId::from_bytes(b"\x01\x02\xab\xcd".as_ref());
+        assert!(PortIdTLV::try_new(
+            PortIdSubtype::CircuitId,
+            PortIdValue::CircuitId(circuit_id.clone())
+        )
+        .is_ok());
+        assert!(
+            PortIdTLV::try_new(PortIdSubtype::Local, PortIdValue::CircuitId(circuit_id)).is_err()
+        );
+    }
+}
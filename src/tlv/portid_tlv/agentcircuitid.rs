@@ -0,0 +1,218 @@
+use std::fmt::Display;
+
+/// Sub-option code of the Agent Circuit ID (RFC 3046)
+pub const CIRCUIT_ID: u8 = 1;
+/// Sub-option code of the Agent Remote ID (RFC 3046)
+pub const REMOTE_ID: u8 = 2;
+
+/// A single DHCP relay agent sub-option
+///
+/// ```text
+///  0               1               2
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+
+/// |     Code      |    Length     |         Value         |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+
+///
+///                                 |<--- 0 - 255 byte ---->|
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RelayAgentSubOption {
+    /// The sub-option code, e.g. [CIRCUIT_ID] or [REMOTE_ID]
+    pub code: u8,
+    /// The sub-option value, usually opaque binary data
+    pub value: Vec<u8>,
+}
+
+impl Display for RelayAgentSubOption {
+    /// Write a printable representation of the sub-option, e.g. `circuit-id=Gi0/1:100`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.code {
+            CIRCUIT_ID => write!(f, "circuit-id={}", printable(&self.value)),
+            REMOTE_ID => write!(f, "remote-id={}", printable(&self.value)),
+            code => write!(f, "suboption-{}={}", code, printable(&self.value)),
+        }
+    }
+}
+
+/// Agent Circuit ID port identifier
+///
+/// Port IDs of subtype 6 (Agent Circuit ID) carry the information a DHCP relay agent inserts into the Relay Agent
+/// Information option (RFC 3046). Broadband and DHCP relay equipment encodes them as a sequence of sub-options, each
+/// consisting of a code, a length and a value, most commonly an Agent Circuit ID (code 1) and an Agent Remote ID
+/// (code 2).
+///
+/// A value is decoded into sub-options if it consists of a well-formed sequence of sub-options containing a circuit or
+/// remote ID. Other values, e.g. plain interface names, are kept as they are.
+///
+/// Either way, encoding a decoded value yields exactly the original bytes.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AgentCircuitId {
+    /// RFC 3046 sub-options, in their original order
+    SubOptions(Vec<RelayAgentSubOption>),
+    /// Value which is not structured as sub-options
+    Raw(Vec<u8>),
+}
+
+impl Display for AgentCircuitId {
+    /// Write a printable representation of the circuit ID.
+    ///
+    /// Sub-options are written as comma separated `name=value` pairs. Textual values are written as they are,
+    /// binary values in hex with a `0x` prefix.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AgentCircuitId::SubOptions(suboptions) => {
+                let suboptions: Vec<String> = suboptions
+                    .iter()
+                    .map(|suboption| suboption.to_string())
+                    .collect();
+                write!(f, "{}", suboptions.join(", "))
+            }
+            AgentCircuitId::Raw(value) => write!(f, "{}", printable(value)),
+        }
+    }
+}
+
+impl AgentCircuitId {
+    /// Decode an Agent Circuit ID from the port ID value.
+    pub fn from_bytes(bytes: &[u8]) -> AgentCircuitId {
+        let mut suboptions = Vec::new();
+        let mut index = 0;
+
+        while index < bytes.len() {
+            if index + 2 > bytes.len() || index + 2 + bytes[index + 1] as usize > bytes.len() {
+                return AgentCircuitId::Raw(bytes.to_vec());
+            }
+
+            let length = bytes[index + 1] as usize;
+            suboptions.push(RelayAgentSubOption {
+                code: bytes[index],
+                value: bytes[index + 2..index + 2 + length].to_vec(),
+            });
+            index += 2 + length;
+        }
+
+        let identified = suboptions
+            .iter()
+            .any(|suboption| suboption.code == CIRCUIT_ID || suboption.code == REMOTE_ID);
+
+        if identified {
+            AgentCircuitId::SubOptions(suboptions)
+        } else {
+            AgentCircuitId::Raw(bytes.to_vec())
+        }
+    }
+
+    /// Return the byte representation of the circuit ID, as carried in the port ID value.
+    pub fn bytes(&self) -> Vec<u8> {
+        match self {
+            AgentCircuitId::SubOptions(suboptions) => {
+                let mut result = Vec::new();
+                for suboption in suboptions {
+                    result.push(suboption.code);
+                    result.push(suboption.value.len() as u8);
+                    result.extend_from_slice(&suboption.value);
+                }
+                result
+            }
+            AgentCircuitId::Raw(value) => value.clone(),
+        }
+    }
+
+    /// Return the length of the encoded circuit ID
+    pub fn len(&self) -> usize {
+        match self {
+            AgentCircuitId::SubOptions(suboptions) => suboptions
+                .iter()
+                .map(|suboption| 2 + suboption.value.len())
+                .sum(),
+            AgentCircuitId::Raw(value) => value.len(),
+        }
+    }
+
+    /// Return `true` if the encoded circuit ID is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the value of the first sub-option with the given `code`
+    pub fn suboption(&self, code: u8) -> Option<&[u8]> {
+        match self {
+            AgentCircuitId::SubOptions(suboptions) => suboptions
+                .iter()
+                .find(|suboption| suboption.code == code)
+                .map(|suboption| suboption.value.as_slice()),
+            AgentCircuitId::Raw(_) => None,
+        }
+    }
+
+    /// Get the Agent Circuit ID sub-option, if present
+    pub fn circuit_id(&self) -> Option<&[u8]> {
+        self.suboption(CIRCUIT_ID)
+    }
+
+    /// Get the Agent Remote ID sub-option, if present
+    pub fn remote_id(&self) -> Option<&[u8]> {
+        self.suboption(REMOTE_ID)
+    }
+}
+
+/// Render `value` as text if it is printable ASCII, in hex otherwise.
+fn printable(value: &[u8]) -> String {
+    if !value.is_empty() && value.iter().all(|c| (0x20..0x7f).contains(c)) {
+        return String::from_utf8_lossy(value).into_owned();
+    }
+
+    let mut result = String::from("0x");
+    for octet in value {
+        result.push_str(&format!("{:02x}", octet));
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_suboptions() {
+        let circuit_id =
+            AgentCircuitId::from_bytes(b"\x01\x09Gi0/1:100\x02\x03\x00\x11\x22".as_ref());
+        assert_eq!(circuit_id.circuit_id(), Some(b"Gi0/1:100".as_ref()));
+        assert_eq!(circuit_id.remote_id(), Some(b"\x00\x11\x22".as_ref()));
+        assert_eq!(circuit_id.len(), 16);
+    }
+
+    #[test]
+    fn test_load_raw() {
+        let circuit_id = AgentCircuitId::from_bytes(b"eth0".as_ref());
+        assert_eq!(circuit_id, AgentCircuitId::Raw(b"eth0".to_vec()));
+        assert_eq!(circuit_id.circuit_id(), None);
+
+        // Well-formed, but without circuit or remote ID
+        let circuit_id = AgentCircuitId::from_bytes(b"\x09\x01\x00".as_ref());
+        assert_eq!(circuit_id, AgentCircuitId::Raw(b"\x09\x01\x00".to_vec()));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for bytes in [
+            b"\x01\x09Gi0/1:100\x02\x03\x00\x11\x22".as_ref(),
+            b"\x02\x00\x01\x02\xff\xfe\x09\x00".as_ref(),
+            b"\x01\x05abc".as_ref(),
+            b"eth0".as_ref(),
+        ] {
+            assert_eq!(AgentCircuitId::from_bytes(bytes).bytes(), bytes.to_vec());
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let circuit_id =
+            AgentCircuitId::from_bytes(b"\x01\x09Gi0/1:100\x02\x03\x00\x11\x22".as_ref());
+        assert_eq!(
+            format!("{}", circuit_id),
+            "circuit-id=Gi0/1:100, remote-id=0x001122"
+        );
+        assert_eq!(format!("{}", AgentCircuitId::Raw(b"eth0".to_vec())), "eth0");
+    }
+}
@@ -1,4 +1,4 @@
-use crate::tlv::TlvType;
+use crate::tlv::{TlvError, TlvType};
 use std::{convert::TryFrom, fmt::Display};
 
 /// Capability bit values
@@ -8,7 +8,9 @@ use std::{convert::TryFrom, fmt::Display};
 /// To create a capability bitmap the enum values can be ORed with each other, e.g. for a WLAN router the
 /// capabilities might look like this:
 ///
-///     caps = Capability.WLAN_AP | Capability.ROUTER
+/// ```text
+/// caps = Capability.WLAN_AP | Capability.ROUTER
+/// ```
 #[derive(Debug, Clone)]
 pub enum SystemCapability {
     Other = 1,
@@ -61,18 +63,20 @@ impl TryFrom<u16> for SystemCapability {
 ///
 /// # TLV Format:
 ///
-///         0                   1                   2                   3                   4
-///         0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7
-///        +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
-///        |             |                 |            System             |            Enabled            |
-///        |      7      |      Length     |         Capabilities          |         Capabilities          |
-///        |             |                 |                               |                               |
-///        +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
-///                                        |                                                               |
-///                                        |             2 byte                           2 byte           |
-///                                        |                                                               |
-///                                        |                                                               |
-///                                        |<--------------------------  Value  -------------------------->|
+/// ```text
+///     0                   1                   2                   3                   4
+///     0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7
+///    +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+///    |             |                 |            System             |            Enabled            |
+///    |      7      |      Length     |         Capabilities          |         Capabilities          |
+///    |             |                 |                               |                               |
+///    +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+///                                    |                                                               |
+///                                    |             2 byte                           2 byte           |
+///                                    |                                                               |
+///                                    |                                                               |
+///                                    |<--------------------------  Value  -------------------------->|
+/// ```
 ///
 /// # Capabilities:
 ///
@@ -138,6 +142,17 @@ impl SystemCapabilitiesTLV {
         }
     }
 
+    /// Validating constructor
+    ///
+    /// Like [SystemCapabilitiesTLV::new], but returns an error instead of panicking if a capability is enabled
+    /// without being supported.
+    pub fn try_new(supported: u16, enabled: u16) -> Result<SystemCapabilitiesTLV, TlvError> {
+        if enabled & !supported != 0 {
+            return Err(TlvError::UnsupportedCapabilities { supported, enabled });
+        }
+        Ok(SystemCapabilitiesTLV::new(supported, enabled))
+    }
+
     /// Create a TLV instance from raw bytes.
     ///
     /// Panics if the provided TLV contains errors (e.g. has the wrong type).
@@ -189,7 +204,7 @@ impl SystemCapabilitiesTLV {
     /// Check if the system supports a given set of capabilities.
     ///
     /// Multiple capabilities should be ORed together.
-    fn supports(&self, capabilities: u16) -> bool {
+    pub fn supports(&self, capabilities: u16) -> bool {
         // TODO: Implement
 
         let sup_cap = ((self.value & 0xFFFF0000) >> 16)as u16;
@@ -202,7 +217,7 @@ impl SystemCapabilitiesTLV {
     /// Check if the system has a given capability enabled.
     ///
     /// Multiple capabilities should be ORed together.
-    fn enabled(&self, capabilities: u16) -> bool {
+    pub fn enabled(&self, capabilities: u16) -> bool {
         // TODO: Implement
         let enb_cap = (self.value & 0x0000FFFF)as u16;
         let res = capabilities & enb_cap;
@@ -216,6 +231,11 @@ impl SystemCapabilitiesTLV {
         4
     }
 
+    /// Check whether the TLV value is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Return the byte representation of the TLV.
     pub fn bytes(&self) -> Vec<u8> {
         // TODO: Implement
@@ -377,4 +397,16 @@ mod tests {
         let tlv = set_up();
         assert_eq!(format!("{}", tlv), "SystemCapabilitiesTLV(92, 84)")
     }
+
+    #[test]
+    fn test_try_new() {
+        assert!(SystemCapabilitiesTLV::try_new(0b10100, 0b10000).is_ok());
+        assert_eq!(
+            SystemCapabilitiesTLV::try_new(0b10100, 0b01000).unwrap_err(),
+            TlvError::UnsupportedCapabilities {
+                supported: 0b10100,
+                enabled: 0b01000
+            }
+        );
+    }
 }
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use crate::tlv::TlvType;
+use crate::tlv::{check_length, TlvError, TlvType};
 
 /// System Description TLV
 ///
@@ -11,15 +11,17 @@ use crate::tlv::TlvType;
 ///
 /// # TLV Format:
 ///
-///      0                   1                   2
-///      0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3
-///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
-///     |             |                 |                           |
-///     |      6      |      Length     |     System Description    |
-///     |             |                 |                           |
-///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
+/// ```text
+///  0                   1                   2
+///  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
+/// |             |                 |                           |
+/// |      6      |      Length     |     System Description    |
+/// |             |                 |                           |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
 ///
-///                                             0 - 255 byte
+///                                         0 - 255 byte
+/// ```
 #[derive(Debug, Clone)]
 pub struct SystemDescriptionTLV {
     /// The type of the TLV
@@ -46,6 +48,14 @@ impl SystemDescriptionTLV {
         }
     }
 
+    /// Validating constructor
+    ///
+    /// Like [SystemDescriptionTLV::new], but checks that the description is at most 255 octets long.
+    pub fn try_new(description: String) -> Result<SystemDescriptionTLV, TlvError> {
+        check_length(TlvType::SystemDescription, description.len(), 0, 255)?;
+        Ok(SystemDescriptionTLV::new(description))
+    }
+
     /// Create a TLV instance from raw bytes.
     ///
     /// Panics if the provided TLV contains errors (e.g. has the wrong type).
@@ -79,6 +89,11 @@ impl SystemDescriptionTLV {
         self.value.len()
     }
 
+    /// Check whether the TLV value is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Return the byte representation of the TLV.
     pub fn bytes(&self) -> Vec<u8> {
         // TODO: Implement
@@ -149,4 +164,18 @@ mod tests {
         let (tlv, _) = set_up();
         assert_eq!(format!("{}", tlv), "SystemDescriptionTLV(\"Unittest\")");
     }
+
+    #[test]
+    fn test_try_new() {
+        assert!(SystemDescriptionTLV::try_new("x".repeat(255)).is_ok());
+        assert_eq!(
+            SystemDescriptionTLV::try_new("x".repeat(300)).unwrap_err(),
+            TlvError::InvalidLength {
+                tlv_type: TlvType::SystemDescription,
+                length: 300,
+                min: 0,
+                max: 255
+            }
+        );
+    }
 }
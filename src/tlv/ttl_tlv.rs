@@ -1,5 +1,4 @@
 use crate::tlv::TlvType;
-use bytes::{Buf, BufMut};
 use std::fmt::Display;
 
 /// Time To Live TLV
@@ -12,13 +11,15 @@ use std::fmt::Display;
 ///
 /// # TLV Format:
 ///
-///      0                   1                   2                   3
-///      0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
-///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
-///     |             |                 |                               |
-///     |      3      |      Length     |               TTL             |
-///     |             |                 |                               |
-///     +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```text
+///  0                   1                   2                   3
+///  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// |             |                 |                               |
+/// |      3      |      Length     |               TTL             |
+/// |             |                 |                               |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
 #[derive(Debug, Clone)]
 pub struct TtlTLV {
     /// The type of the TLV
@@ -79,6 +80,11 @@ impl TtlTLV {
         2
     }
 
+    /// Check whether the TLV value is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Return the byte representation of the TLV.
     pub fn bytes(&self) -> Vec<u8> {
         // TODO: Implement
@@ -104,6 +110,7 @@ impl TtlTLV {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bytes::BufMut;
 
     fn set_up() -> (TtlTLV, u16) {
         let r = 36575;
@@ -0,0 +1,142 @@
+use std::fmt::Display;
+
+use crate::tlv::{check_length, TlvError, TlvType};
+
+/// XPDU Request TLV
+///
+/// A receiver which learned about XPDUs from a [Manifest TLV](crate::tlv::manifest_tlv::ManifestTLV) uses the XPDU
+/// Request TLV to ask the sender for the XPDUs it is missing.
+///
+/// XPDU requests are sent in an LLDPDU addressed to the agent offering the XPDUs. The requested XPDUs are sent back
+/// to the requesting agent.
+///
+/// # TLV Format:
+///
+/// ```text
+///  0               1               2               3
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
+/// |             |                 |     XPDU      |           |
+/// |     10      |      Length     |    Number     |    ...    |
+/// |             |                 |               |           |
+/// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-...-+-+-+-+
+///
+///                                 |<---- 1 - 255 byte ----->|
+/// ```
+#[derive(Debug, Clone)]
+pub struct XpduRequestTLV {
+    /// The type of the TLV
+    pub tlv_type: TlvType,
+    /// The numbers of the requested XPDUs
+    pub value: Vec<u8>,
+}
+
+impl Display for XpduRequestTLV {
+    /// Write a printable representation of the TLV object.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let numbers: Vec<String> = self.value.iter().map(|n| n.to_string()).collect();
+        write!(f, "XpduRequestTLV({})", numbers.join(", "))
+    }
+}
+
+impl XpduRequestTLV {
+    /// Constructor
+    pub fn new(numbers: Vec<u8>) -> XpduRequestTLV {
+        XpduRequestTLV {
+            tlv_type: TlvType::XpduRequest,
+            value: numbers,
+        }
+    }
+
+    /// Validating constructor
+    ///
+    /// Like [XpduRequestTLV::new], but checks that 1 - 255 XPDUs are requested.
+    pub fn try_new(numbers: Vec<u8>) -> Result<XpduRequestTLV, TlvError> {
+        check_length(TlvType::XpduRequest, numbers.len(), 1, 255)?;
+        Ok(XpduRequestTLV::new(numbers))
+    }
+
+    /// Create a TLV instance from raw bytes.
+    ///
+    /// Panics if the provided TLV contains errors (e.g. has the wrong type).
+    pub fn new_from_bytes(bytes: &[u8]) -> XpduRequestTLV {
+        let type_field = bytes[0] >> 1;
+        if type_field != TlvType::XpduRequest as u8 {
+            panic!("Wrong TLV Type for XpduRequest_Tlv");
+        }
+
+        let length = ((bytes[0] as usize & 1) << 8) | bytes[1] as usize;
+        assert_eq!(length, bytes[2..].len(), "XPDU Request TLV length mismatch");
+        assert!(
+            length > 0,
+            "XPDU Request TLV must request at least one XPDU"
+        );
+
+        XpduRequestTLV::new(bytes[2..].to_vec())
+    }
+
+    /// Return the length of the TLV value
+    pub fn len(&self) -> usize {
+        self.value.len()
+    }
+
+    /// Check whether the TLV value is empty
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    /// Return the byte representation of the TLV.
+    pub fn bytes(&self) -> Vec<u8> {
+        let length = self.len();
+
+        let mut result = vec![
+            ((self.tlv_type as u8) << 1) | ((length >> 8) as u8 & 1),
+            (length & 0xFF) as u8,
+        ];
+        result.extend_from_slice(&self.value);
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_type() {
+        let tlv = XpduRequestTLV::new(vec![1]);
+        assert_eq!(tlv.tlv_type as u8, 10);
+    }
+
+    #[test]
+    fn test_dump() {
+        let tlv = XpduRequestTLV::new(vec![1, 3]);
+        assert_eq!(tlv.bytes(), b"\x14\x02\x01\x03".to_vec());
+    }
+
+    #[test]
+    fn test_load() {
+        let tlv = XpduRequestTLV::new_from_bytes(b"\x14\x03\x01\x02\x05".as_ref());
+        assert_eq!(tlv.value, vec![1, 2, 5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_load_empty() {
+        XpduRequestTLV::new_from_bytes(b"\x14\x00".as_ref());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            format!("{}", XpduRequestTLV::new(vec![1, 3])),
+            "XpduRequestTLV(1, 3)"
+        );
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert!(XpduRequestTLV::try_new(vec![1]).is_ok());
+        assert!(XpduRequestTLV::try_new(vec![]).is_err());
+    }
+}
@@ -0,0 +1,566 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// Minimal TOML value
+///
+/// Used to read the vendor TLV layout files, as the crate does without third-party dependencies apart from `bytes`
+/// and `pnet`. Only parsing is supported, and only the subset of TOML needed for
+/// configuration files: tables, arrays of tables, dotted keys, basic and literal strings, integers, booleans,
+/// arrays and inline tables. Floats, dates and multi-line strings are rejected.
+///
+/// Table members keep their order in the document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Toml {
+    /// Basic or literal string
+    String(String),
+    /// Integer
+    Integer(i64),
+    /// Boolean
+    Bool(bool),
+    /// Array, including an array of tables
+    Array(Vec<Toml>),
+    /// Table or inline table, with its members in document order
+    Table(Vec<(String, Toml)>),
+}
+
+/// Error returned when parsing a TOML document fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TomlError {
+    /// Line of the error, starting at 1
+    pub line: usize,
+    /// Description of the problem
+    pub message: String,
+}
+
+impl Display for TomlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Toml {
+    /// Parse a TOML document into its root table.
+    pub(crate) fn parse(text: &str) -> Result<Toml, TomlError> {
+        Parser {
+            chars: text.chars().collect(),
+            position: 0,
+            line: 1,
+            definitions: HashMap::new(),
+        }
+        .document()
+    }
+
+    /// Get the member `name` of a table.
+    pub(crate) fn get(&self, name: &str) -> Option<&Toml> {
+        self.as_table()?
+            .iter()
+            .find(|(member, _)| member == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Get the members of a table.
+    pub(crate) fn as_table(&self) -> Option<&[(String, Toml)]> {
+        match self {
+            Toml::Table(members) => Some(members),
+            _ => None,
+        }
+    }
+}
+
+/// Get the table at `path` below `table`, creating missing tables.
+///
+/// An array of tables along the path resolves to its last element.
+fn table_at<'a>(
+    table: &'a mut Vec<(String, Toml)>,
+    path: &[String],
+) -> Result<&'a mut Vec<(String, Toml)>, String> {
+    let (key, rest) = match path.split_first() {
+        Some(split) => split,
+        None => return Ok(table),
+    };
+    if !table.iter().any(|(member, _)| member == key) {
+        table.push((key.clone(), Toml::Table(vec![])));
+    }
+    let value = &mut table
+        .iter_mut()
+        .find(|(member, _)| member == key)
+        .unwrap()
+        .1;
+    let members = match value {
+        Toml::Table(members) => members,
+        Toml::Array(values) => match values.last_mut() {
+            Some(Toml::Table(members)) => members,
+            _ => return Err(format!("{} is not an array of tables", key)),
+        },
+        _ => return Err(format!("{} is not a table", key)),
+    };
+    table_at(members, rest)
+}
+
+/// Insert `value` as `key` into `table`, rejecting duplicate keys.
+fn insert(table: &mut Vec<(String, Toml)>, key: &str, value: Toml) -> Result<(), String> {
+    if table.iter().any(|(member, _)| member == key) {
+        return Err(format!("duplicate key {}", key));
+    }
+    table.push((key.to_string(), value));
+    Ok(())
+}
+
+/// How the table or value at a path of the document was defined
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Definition {
+    /// Table header `[a]`
+    Header,
+    /// Array of tables header `[[a]]`
+    Array,
+    /// Table created by a dotted key, e.g. `a.b = 1`
+    Dotted,
+    /// Value of a key, including inline tables and arrays, which cannot be extended
+    Value,
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+    line: usize,
+    /// Definitions below the root table, or below the current element of an array of tables
+    definitions: HashMap<Vec<String>, Definition>,
+}
+
+impl Parser {
+    fn error<T>(&self, message: String) -> Result<T, TomlError> {
+        Err(TomlError {
+            line: self.line,
+            message,
+        })
+    }
+
+    /// Record the definition of the table at `path`, rejecting tables which are defined twice and values which are
+    /// extended.
+    fn define(&mut self, path: &[String], definition: Definition) -> Result<(), TomlError> {
+        for end in 1..path.len() {
+            if self.definitions.get(&path[..end]) == Some(&Definition::Value) {
+                return self.error(format!("{} cannot be extended", path[..end].join(".")));
+            }
+        }
+        match (self.definitions.get(path), definition) {
+            (None, _) | (Some(Definition::Dotted), Definition::Dotted) => {}
+            (Some(Definition::Array), Definition::Array) => {
+                // Tables below the new element are defined afresh
+                self.definitions.retain(|defined, _| {
+                    !(defined.len() > path.len() && defined.starts_with(path))
+                });
+            }
+            _ => return self.error(format!("{} is already defined", path.join("."))),
+        }
+        self.definitions.insert(path.to_vec(), definition);
+        Ok(())
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += 1;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), TomlError> {
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => self.error(format!("expected {:?}, found {:?}", expected, c)),
+            None => self.error(format!("expected {:?}, found end of file", expected)),
+        }
+    }
+
+    /// Skip spaces and tabs.
+    fn skip_spaces(&mut self) {
+        while let Some(' ') | Some('\t') = self.peek() {
+            self.next();
+        }
+    }
+
+    /// Skip whitespace, newlines and comments.
+    fn skip_blank(&mut self) {
+        loop {
+            match self.peek() {
+                Some(' ') | Some('\t') | Some('\r') | Some('\n') => {
+                    self.next();
+                }
+                Some('#') => self.skip_comment(),
+                _ => return,
+            }
+        }
+    }
+
+    fn skip_comment(&mut self) {
+        while !matches!(self.peek(), None | Some('\n')) {
+            self.next();
+        }
+    }
+
+    /// Expect the end of the line, allowing trailing whitespace and a comment.
+    fn end_of_line(&mut self) -> Result<(), TomlError> {
+        self.skip_spaces();
+        if self.peek() == Some('#') {
+            self.skip_comment();
+        }
+        if self.peek() == Some('\r') {
+            self.next();
+        }
+        match self.next() {
+            None | Some('\n') => Ok(()),
+            Some(c) => self.error(format!("expected end of line, found {:?}", c)),
+        }
+    }
+
+    fn document(&mut self) -> Result<Toml, TomlError> {
+        let mut root = vec![];
+        let mut current: Vec<String> = vec![];
+
+        loop {
+            self.skip_blank();
+            match self.peek() {
+                None => return Ok(Toml::Table(root)),
+                Some('[') => {
+                    self.next();
+                    let array = self.peek() == Some('[');
+                    if array {
+                        self.next();
+                    }
+                    self.skip_spaces();
+                    current = self.key_path()?;
+                    self.expect(']')?;
+                    if array {
+                        self.expect(']')?;
+                        self.define(&current, Definition::Array)?;
+                        let (key, parent) = current.split_last().unwrap();
+                        let line = self.line;
+                        let table = table_at(&mut root, parent).or_else(|err| self.error(err))?;
+                        match table.iter_mut().find(|(member, _)| member == key) {
+                            Some((_, Toml::Array(values))) => values.push(Toml::Table(vec![])),
+                            Some(_) => {
+                                return Err(TomlError {
+                                    line,
+                                    message: format!("{} is not an array of tables", key),
+                                })
+                            }
+                            None => {
+                                table.push((key.clone(), Toml::Array(vec![Toml::Table(vec![])])))
+                            }
+                        }
+                    } else {
+                        self.define(&current, Definition::Header)?;
+                        table_at(&mut root, &current).or_else(|err| self.error(err))?;
+                    }
+                    self.end_of_line()?;
+                }
+                Some(_) => {
+                    let mut path = current.clone();
+                    path.extend(self.key_path()?);
+                    self.expect('=')?;
+                    self.skip_spaces();
+                    let value = self.value()?;
+                    for end in current.len() + 1..path.len() {
+                        self.define(&path[..end], Definition::Dotted)?;
+                    }
+                    let (key, parent) = path.split_last().unwrap();
+                    let table = table_at(&mut root, parent).or_else(|err| self.error(err))?;
+                    insert(table, key, value).or_else(|err| self.error(err))?;
+                    self.definitions.insert(path, Definition::Value);
+                    self.end_of_line()?;
+                }
+            }
+        }
+    }
+
+    /// Parse a possibly dotted key, e.g. `tlv.field`, including the whitespace following it.
+    fn key_path(&mut self) -> Result<Vec<String>, TomlError> {
+        let mut path = vec![self.key()?];
+        self.skip_spaces();
+        while self.peek() == Some('.') {
+            self.next();
+            self.skip_spaces();
+            path.push(self.key()?);
+            self.skip_spaces();
+        }
+        Ok(path)
+    }
+
+    fn key(&mut self) -> Result<String, TomlError> {
+        match self.peek() {
+            Some('"') => self.basic_string(),
+            Some('\'') => self.literal_string(),
+            _ => {
+                let mut key = String::new();
+                while let Some(c) = self.peek() {
+                    if !(c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+                        break;
+                    }
+                    key.push(c);
+                    self.next();
+                }
+                if key.is_empty() {
+                    return self.error(format!("expected key, found {:?}", self.peek()));
+                }
+                Ok(key)
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<Toml, TomlError> {
+        match self.peek() {
+            Some('"') => self.basic_string().map(Toml::String),
+            Some('\'') => self.literal_string().map(Toml::String),
+            Some('[') => self.array(),
+            Some('{') => self.inline_table(),
+            Some(c) if c.is_ascii_alphanumeric() || c == '+' || c == '-' => self.scalar(),
+            Some(c) => self.error(format!("expected value, found {:?}", c)),
+            None => self.error(String::from("expected value, found end of file")),
+        }
+    }
+
+    fn basic_string(&mut self) -> Result<String, TomlError> {
+        self.expect('"')?;
+        let mut value = String::new();
+        loop {
+            match self.next() {
+                Some('"') => return Ok(value),
+                Some('\\') => {
+                    let escaped = match self.next() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => {
+                            let hex: String = (0..4).filter_map(|_| self.next()).collect();
+                            match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                                Some(c) => c,
+                                None => return self.error(format!("invalid escape \\u{}", hex)),
+                            }
+                        }
+                        c => return self.error(format!("invalid escape {:?}", c)),
+                    };
+                    value.push(escaped);
+                }
+                Some('\n') | None => return self.error(String::from("unterminated string")),
+                Some(c) => value.push(c),
+            }
+        }
+    }
+
+    fn literal_string(&mut self) -> Result<String, TomlError> {
+        self.expect('\'')?;
+        let mut value = String::new();
+        loop {
+            match self.next() {
+                Some('\'') => return Ok(value),
+                Some('\n') | None => return self.error(String::from("unterminated string")),
+                Some(c) => value.push(c),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Toml, TomlError> {
+        self.expect('[')?;
+        let mut values = vec![];
+        loop {
+            self.skip_blank();
+            if self.peek() == Some(']') {
+                self.next();
+                return Ok(Toml::Array(values));
+            }
+            values.push(self.value()?);
+            self.skip_blank();
+            match self.next() {
+                Some(',') => {}
+                Some(']') => return Ok(Toml::Array(values)),
+                c => return self.error(format!("expected ',' or ']', found {:?}", c)),
+            }
+        }
+    }
+
+    fn inline_table(&mut self) -> Result<Toml, TomlError> {
+        self.expect('{')?;
+        let mut members = vec![];
+        self.skip_spaces();
+        if self.peek() == Some('}') {
+            self.next();
+            return Ok(Toml::Table(members));
+        }
+        loop {
+            self.skip_spaces();
+            let path = self.key_path()?;
+            self.expect('=')?;
+            self.skip_spaces();
+            let value = self.value()?;
+            let (key, parent) = path.split_last().unwrap();
+            let table = table_at(&mut members, parent).or_else(|err| self.error(err))?;
+            insert(table, key, value).or_else(|err| self.error(err))?;
+            self.skip_spaces();
+            match self.next() {
+                Some(',') => {}
+                Some('}') => return Ok(Toml::Table(members)),
+                c => return self.error(format!("expected ',' or '}}', found {:?}", c)),
+            }
+        }
+    }
+
+    /// Parse a boolean or an integer, e.g. `true`, `-5`, `1_000` or `0x88CC`.
+    fn scalar(&mut self) -> Result<Toml, TomlError> {
+        let mut token = String::new();
+        while let Some(c) = self.peek() {
+            if !(c.is_ascii_alphanumeric() || c == '_' || c == '+' || c == '-' || c == '.') {
+                break;
+            }
+            token.push(c);
+            self.next();
+        }
+        match token.as_str() {
+            "true" => return Ok(Toml::Bool(true)),
+            "false" => return Ok(Toml::Bool(false)),
+            _ => {}
+        }
+
+        // Only decimal integers may carry a sign
+        let (sign, unsigned) = match token.strip_prefix('-') {
+            Some(unsigned) => (Some(-1), unsigned),
+            None => match token.strip_prefix('+') {
+                Some(unsigned) => (Some(1), unsigned),
+                None => (None, token.as_str()),
+            },
+        };
+        let (radix, digits) = match (sign, unsigned.get(..2)) {
+            (None, Some("0x")) => (16, &unsigned[2..]),
+            (None, Some("0o")) => (8, &unsigned[2..]),
+            (None, Some("0b")) => (2, &unsigned[2..]),
+            _ => (10, unsigned),
+        };
+        // Underscores are only allowed between digits
+        let valid = !digits.is_empty()
+            && digits.chars().all(|c| c == '_' || c.is_digit(radix))
+            && !digits.starts_with('_')
+            && !digits.ends_with('_')
+            && !digits.contains("__");
+        match i64::from_str_radix(&digits.replace('_', ""), radix) {
+            Ok(value) if valid => Ok(Toml::Integer(sign.unwrap_or(1) * value)),
+            _ => self.error(format!("unsupported value {:?}", token)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let toml = Toml::parse(
+            "# Example\n\
+             title = 'lldp' # comment\n\
+             [owner]\n\
+             name = \"A \\\"B\\\"\"\n\
+             id.number = 0x2A\n\
+             \n\
+             [[tlv]]\n\
+             flags = { 0 = \"up\", 7 = \"tagged\" }\n\
+             [[tlv.field]]\n\
+             sizes = [\n  1,\n  -2_000, # two\n]\n\
+             [[tlv]]\n\
+             enabled = true\n",
+        )
+        .unwrap();
+
+        let string = |value: &str| Toml::String(value.to_string());
+        assert_eq!(toml.get("title"), Some(&string("lldp")));
+        let owner = toml.get("owner").unwrap();
+        assert_eq!(owner.get("name"), Some(&string("A \"B\"")));
+        assert_eq!(
+            owner.get("id").and_then(|id| id.get("number")),
+            Some(&Toml::Integer(42))
+        );
+
+        let tlvs = match toml.get("tlv") {
+            Some(Toml::Array(tlvs)) => tlvs,
+            other => panic!("expected an array of tables, got {:?}", other),
+        };
+        assert_eq!(tlvs.len(), 2);
+        assert_eq!(
+            tlvs[0].get("flags").and_then(|flags| flags.get("7")),
+            Some(&Toml::String(String::from("tagged")))
+        );
+        let fields = match tlvs[0].get("field") {
+            Some(Toml::Array(fields)) => fields,
+            other => panic!("expected an array of tables, got {:?}", other),
+        };
+        assert_eq!(
+            fields[0].get("sizes"),
+            Some(&Toml::Array(vec![Toml::Integer(1), Toml::Integer(-2000)]))
+        );
+        assert_eq!(tlvs[1].get("enabled"), Some(&Toml::Bool(true)));
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = Toml::parse("a = 1\na = 2\n").unwrap_err();
+        assert_eq!(format!("{}", error), "line 2: duplicate key a");
+        assert_eq!(Toml::parse("a = 1.5").unwrap_err().line, 1);
+        assert!(Toml::parse("a = \"open\nb = 1").is_err());
+        assert!(Toml::parse("a = 1 b = 2").is_err());
+        assert!(Toml::parse("a = 1\n[a]\n").is_err());
+
+        // Integers
+        for value in [
+            "0x-5", "--5", "-+5", "+-5", "-0x5", "_1", "1_", "1__0", "0x_1", "0b12", "1e3", "-",
+        ] {
+            assert!(Toml::parse(&format!("a = {}", value)).is_err(), "{}", value);
+        }
+        assert_eq!(
+            Toml::parse("a = [+1_000, -5, 0o17, 0b1_0]")
+                .unwrap()
+                .get("a"),
+            Some(&Toml::Array(vec![
+                Toml::Integer(1000),
+                Toml::Integer(-5),
+                Toml::Integer(15),
+                Toml::Integer(2)
+            ]))
+        );
+
+        // Tables can be defined once
+        let error = Toml::parse("[a]\nb = 1\n[a]\nc = 2\n").unwrap_err();
+        assert_eq!(format!("{}", error), "line 3: a is already defined");
+        assert!(Toml::parse("[a.b]\n[a]\n[a.c]\n").is_ok());
+        assert!(Toml::parse("[a]\nb.c = 1\n[a.b]\n").is_err());
+        assert!(Toml::parse("[a]\nb.c = 1\n[a.b.d]\n").is_ok());
+        assert!(Toml::parse("[a.b]\n[a]\nb.c = 1\n").is_err());
+        assert!(Toml::parse("a.b = 1\na.c = 2\n").is_ok());
+        assert!(Toml::parse("a.b = 1\na = 2\n").is_err());
+        assert!(Toml::parse("a = 1\na.b = 2\n").is_err());
+
+        // Arrays of tables
+        assert!(Toml::parse("a = 1\n[[a]]\n").is_err());
+        assert!(Toml::parse("a = []\n[[a]]\n").is_err());
+        assert!(Toml::parse("[a]\n[[a]]\n").is_err());
+        assert!(Toml::parse("[[a]]\n[a]\n").is_err());
+        assert!(Toml::parse("[[a]]\n[a.b]\n[[a]]\n[a.b]\n").is_ok());
+        assert!(Toml::parse("[[a]]\n[a.b]\n[a.b]\n").is_err());
+
+        // Inline tables and arrays cannot be extended
+        assert!(Toml::parse("a = { b = 1 }\n[a]\n").is_err());
+        assert!(Toml::parse("a = { b = 1 }\n[a.c]\n").is_err());
+        assert!(Toml::parse("a = { b = 1 }\na.c = 2\n").is_err());
+        assert!(Toml::parse("a = [{ b = 1 }]\n[a.c]\n").is_err());
+
+        // Unterminated arrays and inline tables
+        assert!(Toml::parse("a = [1, 2\n").is_err());
+        assert!(Toml::parse("a = [").is_err());
+        assert!(Toml::parse("a = { b = 1").is_err());
+    }
+}